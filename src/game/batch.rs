@@ -0,0 +1,226 @@
+use crate::game::boxscore::{BoxScore, AverageTeamBoxScore};
+use crate::game::game::{Game, GameError};
+use crate::team::team::Team;
+
+use std::collections::BTreeMap;
+use std::thread;
+
+/// Get the number of available CPUs, or one if it cannot be determined
+pub(crate) fn get_num_threads() -> usize {
+    match thread::available_parallelism() {
+        Ok(num_threads) => num_threads.get(),
+        Err(_) => 1
+    }
+}
+
+/// Run a job for each index from zero to `num_jobs` across threads and
+/// return the outputs in index order, or the first error of the first
+/// thread to fail.  Each thread runs every `num_threads`-th job, so the
+/// outputs only depend on the index and not on the number of threads.
+pub(crate) fn parallel_map<T: Send, E: Send>(num_jobs: usize, num_threads: usize, job: impl Fn(usize) -> Result<T, E> + Sync) -> Result<Vec<T>, E> {
+    let num_threads: usize = num_threads.clamp(1, num_jobs.max(1));
+    let job = &job;
+    let thread_results: Vec<Result<Vec<T>, E>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..num_threads)
+            .map(|thread_index| scope.spawn(move || {
+                (thread_index..num_jobs)
+                    .step_by(num_threads)
+                    .map(job)
+                    .collect::<Result<Vec<T>, E>>()
+            }))
+            .collect();
+        handles.into_iter()
+            .map(|handle| handle.join().expect("Simulation thread panicked"))
+            .collect()
+    });
+
+    // Put the outputs back in index order
+    let mut thread_outputs: Vec<std::vec::IntoIter<T>> = Vec::with_capacity(num_threads);
+    for result in thread_results {
+        thread_outputs.push(result?.into_iter());
+    }
+    let mut outputs: Vec<T> = Vec::with_capacity(num_jobs);
+    for index in 0..num_jobs {
+        if let Some(output) = thread_outputs[index % num_threads].next() {
+            outputs.push(output);
+        }
+    }
+    Ok(outputs)
+}
+
+/// # BatchSimulator struct
+///
+/// A `BatchSimulator` simulates the same matchup many times in parallel.
+/// Game `i` of the batch is seeded with `seed + i`, so the results are the
+/// same whatever the number of threads.
+pub struct BatchSimulator {
+    pub home_team: Team,
+    pub away_team: Team,
+    pub num_games: usize,
+    pub seed: u64,
+    pub num_threads: usize
+}
+
+impl BatchSimulator {
+    /// Initialize a new batch simulator given the matchup, the number of
+    /// games, and the seed of the first game.  It uses one thread per
+    /// available CPU.
+    pub fn new(home_team: Team, away_team: Team, num_games: usize, seed: u64) -> BatchSimulator {
+        let num_threads: usize = get_num_threads();
+        BatchSimulator {
+            home_team,
+            away_team,
+            num_games,
+            seed,
+            num_threads
+        }
+    }
+
+    /// Simulate a single game of the batch, returning its box score
+    fn simulate_game(&self, index: usize) -> Result<BoxScore, GameError> {
+        let game_seed: u64 = self.seed.wrapping_add(index as u64);
+        let mut game: Game = Game::from_seed(self.home_team.clone(), self.away_team.clone(), game_seed);
+        game.simulate_seeded()?;
+        Ok(BoxScore::from_game(&game))
+    }
+
+    /// Simulate every game of the batch across threads and aggregate the
+    /// results
+    pub fn simulate(&self) -> Result<BatchResult, GameError> {
+        let box_scores: Vec<BoxScore> = parallel_map(self.num_games, self.num_threads, |index| self.simulate_game(index))?;
+        Ok(BatchResult::from_box_scores(&box_scores))
+    }
+}
+
+/// # BatchResult struct
+///
+/// A `BatchResult` aggregates the outcomes of a batch of simulated games.
+/// Margins are the home score minus the away score.
+pub struct BatchResult {
+    pub num_games: usize,
+    pub home_wins: usize,
+    pub away_wins: usize,
+    pub ties: usize,
+    pub score_distribution: BTreeMap<(usize, usize), usize>,
+    pub margins: Vec<isize>,
+    pub average_home_box_score: AverageTeamBoxScore,
+    pub average_away_box_score: AverageTeamBoxScore
+}
+
+impl BatchResult {
+    /// Aggregate the box scores of a batch of games
+    pub fn from_box_scores(box_scores: &[BoxScore]) -> BatchResult {
+        let mut result: BatchResult = BatchResult {
+            num_games: box_scores.len(),
+            home_wins: 0_usize,
+            away_wins: 0_usize,
+            ties: 0_usize,
+            score_distribution: BTreeMap::new(),
+            margins: Vec::with_capacity(box_scores.len()),
+            average_home_box_score: AverageTeamBoxScore::from_box_scores(box_scores.iter().map(|box_score| &box_score.home)),
+            average_away_box_score: AverageTeamBoxScore::from_box_scores(box_scores.iter().map(|box_score| &box_score.away))
+        };
+        for box_score in box_scores.iter() {
+            let home_points: usize = box_score.home.points;
+            let away_points: usize = box_score.away.points;
+            if home_points > away_points {
+                result.home_wins += 1;
+            } else if home_points < away_points {
+                result.away_wins += 1;
+            } else {
+                result.ties += 1;
+            }
+            *result.score_distribution.entry((home_points, away_points)).or_insert(0) += 1;
+            result.margins.push(home_points as isize - away_points as isize);
+        }
+        result.margins.sort();
+        result
+    }
+
+    /// Get the home team's win rate, counting ties as half a win
+    pub fn get_home_win_rate(&self) -> f64 {
+        if self.num_games == 0 {
+            return 0.5_f64;
+        }
+        (self.home_wins as f64 + 0.5_f64 * self.ties as f64) / self.num_games as f64
+    }
+
+    /// Get the away team's win rate, counting ties as half a win
+    pub fn get_away_win_rate(&self) -> f64 {
+        1_f64 - self.get_home_win_rate()
+    }
+
+    /// Get the average margin
+    pub fn get_average_margin(&self) -> f64 {
+        if self.margins.is_empty() {
+            return 0_f64;
+        }
+        self.margins.iter().sum::<isize>() as f64 / self.margins.len() as f64
+    }
+
+    /// Get a percentile of the margin, given as a fraction between 0 and 1,
+    /// using the nearest rank
+    pub fn get_margin_percentile(&self, percentile: f64) -> isize {
+        if self.margins.is_empty() {
+            return 0_isize;
+        }
+        let rank: usize = (percentile.clamp(0_f64, 1_f64) * (self.margins.len() - 1) as f64).round() as usize;
+        self.margins[rank]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_teams;
+
+    #[test]
+    fn test_parallel_map_keeps_index_order() {
+        for num_threads in [1_usize, 3, 8, 20] {
+            let outputs: Result<Vec<usize>, String> = parallel_map(10, num_threads, |index| Ok(index * index));
+            assert_eq!(outputs.unwrap(), (0..10).map(|index| index * index).collect::<Vec<usize>>());
+        }
+        let empty: Result<Vec<usize>, String> = parallel_map(0, 4, Ok);
+        assert!(empty.unwrap().is_empty());
+        let failed: Result<Vec<usize>, String> = parallel_map(10, 3, |index| match index {
+            7 => Err(String::from("job 7 failed")),
+            _ => Ok(index)
+        });
+        assert_eq!(failed.unwrap_err(), "job 7 failed");
+    }
+
+    #[test]
+    fn test_batch_results_do_not_depend_on_threads() {
+        let (home_team, away_team) = generate_test_teams(0);
+        let mut simulator: BatchSimulator = BatchSimulator::new(home_team, away_team, 12, 100);
+        simulator.num_threads = 1;
+        let single: BatchResult = simulator.simulate().unwrap();
+        simulator.num_threads = 5;
+        let multi: BatchResult = simulator.simulate().unwrap();
+        assert_eq!(single.num_games, 12);
+        assert_eq!(single.home_wins + single.away_wins + single.ties, 12);
+        assert_eq!(single.margins, multi.margins);
+        assert_eq!(single.score_distribution, multi.score_distribution);
+        assert_eq!(single.score_distribution.values().sum::<usize>(), 12);
+    }
+
+    #[test]
+    fn test_margin_statistics() {
+        let result: BatchResult = BatchResult {
+            num_games: 4,
+            home_wins: 2,
+            away_wins: 1,
+            ties: 1,
+            score_distribution: BTreeMap::new(),
+            margins: vec![-7, 0, 3, 14],
+            average_home_box_score: AverageTeamBoxScore::from_box_scores(std::iter::empty()),
+            average_away_box_score: AverageTeamBoxScore::from_box_scores(std::iter::empty())
+        };
+        assert_eq!(result.get_home_win_rate(), 0.625_f64);
+        assert_eq!(result.get_away_win_rate(), 0.375_f64);
+        assert_eq!(result.get_average_margin(), 2.5_f64);
+        assert_eq!(result.get_margin_percentile(0_f64), -7);
+        assert_eq!(result.get_margin_percentile(0.5_f64), 3);
+        assert_eq!(result.get_margin_percentile(2_f64), 14);
+    }
+}
@@ -0,0 +1,415 @@
+use crate::game::game::Game;
+use crate::game::injury::PlayerInjury;
+use crate::game::play::{PlayType, PlayResult};
+
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # TeamBoxScore struct
+///
+/// A `TeamBoxScore` totals a team's statistics over a single game.
+/// Passing yards are net of yards lost to sacks, and penalty yards are
+/// the yards the team was penalized on offense.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeamBoxScore {
+    pub points: usize,
+    pub passing_yards: isize,
+    pub rushing_yards: isize,
+    pub first_downs: usize,
+    pub turnovers: usize,
+    pub num_plays: usize,
+    pub time_of_possession: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub penalties: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub penalty_yards: isize
+}
+
+impl TeamBoxScore {
+    /// Tally a team's box score from a finished game given its abbreviation
+    pub fn from_game(game: &Game, abbreviation: &str, points: usize) -> TeamBoxScore {
+        let mut box_score: TeamBoxScore = TeamBoxScore {
+            points,
+            ..Default::default()
+        };
+        for play in game.plays.iter().filter(|play| play.offense == abbreviation) {
+            match play.play_type {
+                PlayType::Run => {
+                    box_score.rushing_yards += play.yards;
+                    box_score.num_plays += 1;
+                },
+                PlayType::Pass => {
+                    box_score.passing_yards += play.yards;
+                    box_score.num_plays += 1;
+                },
+                PlayType::Penalty => {
+                    box_score.penalties += 1;
+                    box_score.penalty_yards -= play.yards;
+                },
+                _ => {}
+            }
+            match play.result {
+                PlayResult::FirstDown => box_score.first_downs += 1,
+                PlayResult::Interception | PlayResult::Fumble => box_score.turnovers += 1,
+                _ => {}
+            }
+        }
+        for drive in game.drives.iter().filter(|drive| drive.offense == abbreviation) {
+            box_score.time_of_possession += drive.time_of_possession;
+        }
+        box_score
+    }
+
+    /// Get the total yards from scrimmage
+    pub fn get_total_yards(&self) -> isize {
+        self.passing_yards + self.rushing_yards
+    }
+
+    /// Add another box score's totals to this one
+    pub fn add(&mut self, other: &TeamBoxScore) {
+        self.points += other.points;
+        self.passing_yards += other.passing_yards;
+        self.rushing_yards += other.rushing_yards;
+        self.first_downs += other.first_downs;
+        self.turnovers += other.turnovers;
+        self.num_plays += other.num_plays;
+        self.time_of_possession += other.time_of_possession;
+        self.penalties += other.penalties;
+        self.penalty_yards += other.penalty_yards;
+    }
+}
+
+/// # BoxScore struct
+///
+/// A `BoxScore` holds both teams' statistics from a single game, and the
+/// injuries suffered during it
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoxScore {
+    pub home: TeamBoxScore,
+    pub away: TeamBoxScore,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub injuries: Vec<PlayerInjury>
+}
+
+impl BoxScore {
+    /// Tally the box score of a finished game
+    pub fn from_game(game: &Game) -> BoxScore {
+        BoxScore {
+            home: TeamBoxScore::from_game(game, &game.home_team.abbreviation, game.context.score.home_team_score),
+            away: TeamBoxScore::from_game(game, &game.away_team.abbreviation, game.context.score.away_team_score),
+            injuries: game.get_injuries().into_iter().cloned().collect()
+        }
+    }
+}
+
+/// # PlayerBoxScore struct
+///
+/// A `PlayerBoxScore` totals a player's statistics over one or more games.
+/// Players are identified by name and team abbreviation.  Snaps count the
+/// runs and passes a player was on the field for.  Passing yards do
+/// not count yards lost to sacks, and a pass which ends in a turnover on
+/// downs counts as complete if it gained yards.
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayerBoxScore {
+    pub name: String,
+    pub team: String,
+    pub games: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub snaps: usize,
+    pub pass_attempts: usize,
+    pub completions: usize,
+    pub passing_yards: isize,
+    pub passing_touchdowns: usize,
+    pub interceptions_thrown: usize,
+    pub times_sacked: usize,
+    pub rush_attempts: usize,
+    pub rushing_yards: isize,
+    pub rushing_touchdowns: usize,
+    pub fumbles_lost: usize,
+    pub targets: usize,
+    pub receptions: usize,
+    pub receiving_yards: isize,
+    pub receiving_touchdowns: usize,
+    pub tackles: usize,
+    pub sacks: usize,
+    pub interceptions: usize,
+    pub passes_defended: usize,
+    pub fumbles_recovered: usize,
+    pub field_goals_made: usize,
+    pub field_goals_attempted: usize,
+    pub extra_points_made: usize,
+    pub extra_points_attempted: usize,
+    pub punts: usize
+}
+
+impl PlayerBoxScore {
+    /// Initialize an empty box score for a player
+    pub fn new(name: &str, team: &str) -> PlayerBoxScore {
+        PlayerBoxScore {
+            name: String::from(name),
+            team: String::from(team),
+            ..Default::default()
+        }
+    }
+
+    /// Tally the box score of every player who played a snap or recorded a
+    /// statistic in a finished game
+    pub fn from_game(game: &Game) -> Vec<PlayerBoxScore> {
+        let mut box_scores: BTreeMap<(String, String), PlayerBoxScore> = BTreeMap::new();
+        for snap_count in game.snap_counts.iter() {
+            get_player_box_score(&mut box_scores, &snap_count.player, &snap_count.team).snaps += snap_count.snaps;
+        }
+        for play in game.plays.iter() {
+            let offense: &str = &play.offense;
+            let defense: &str = &play.defense;
+            let touchdown: bool = play.result == PlayResult::Touchdown;
+            match play.play_type {
+                PlayType::Run => {
+                    if let Some(carrier) = &play.ball_carrier {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, carrier, offense);
+                        box_score.rush_attempts += 1;
+                        box_score.rushing_yards += play.yards;
+                        box_score.rushing_touchdowns += touchdown as usize;
+                        box_score.fumbles_lost += (play.result == PlayResult::Fumble) as usize;
+                    }
+                    if let Some(defender) = &play.defender {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, defender, defense);
+                        box_score.tackles += 1;
+                        box_score.fumbles_recovered += (play.result == PlayResult::Fumble) as usize;
+                    }
+                },
+                PlayType::Pass => {
+                    let sacked: bool = play.result == PlayResult::Sack;
+                    let intercepted: bool = play.result == PlayResult::Interception;
+                    let complete: bool = !matches!(play.result, PlayResult::Sack | PlayResult::Incomplete | PlayResult::Interception);
+                    if let Some(passer) = &play.passer {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, passer, offense);
+                        if sacked {
+                            box_score.times_sacked += 1;
+                        } else {
+                            box_score.pass_attempts += 1;
+                            box_score.completions += complete as usize;
+                            box_score.interceptions_thrown += intercepted as usize;
+                            if complete {
+                                box_score.passing_yards += play.yards;
+                                box_score.passing_touchdowns += touchdown as usize;
+                            }
+                        }
+                    }
+                    if let Some(target) = &play.ball_carrier {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, target, offense);
+                        box_score.targets += 1;
+                        if complete {
+                            box_score.receptions += 1;
+                            box_score.receiving_yards += play.yards;
+                            box_score.receiving_touchdowns += touchdown as usize;
+                            box_score.fumbles_lost += (play.result == PlayResult::Fumble) as usize;
+                        }
+                    }
+                    if let Some(defender) = &play.defender {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, defender, defense);
+                        if sacked {
+                            box_score.sacks += 1;
+                        } else if intercepted {
+                            box_score.interceptions += 1;
+                        } else if complete {
+                            box_score.tackles += 1;
+                            box_score.fumbles_recovered += (play.result == PlayResult::Fumble) as usize;
+                        } else {
+                            box_score.passes_defended += 1;
+                        }
+                    }
+                },
+                PlayType::FieldGoal => {
+                    if let Some(kicker) = &play.kicker {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, kicker, offense);
+                        box_score.field_goals_attempted += 1;
+                        box_score.field_goals_made += (play.result == PlayResult::Good) as usize;
+                    }
+                },
+                PlayType::ExtraPoint => {
+                    if let Some(kicker) = &play.kicker {
+                        let box_score: &mut PlayerBoxScore = get_player_box_score(&mut box_scores, kicker, offense);
+                        box_score.extra_points_attempted += 1;
+                        box_score.extra_points_made += (play.result == PlayResult::Good) as usize;
+                    }
+                },
+                PlayType::Punt => {
+                    if let Some(punter) = &play.kicker {
+                        get_player_box_score(&mut box_scores, punter, offense).punts += 1;
+                    }
+                },
+                PlayType::Kickoff | PlayType::Penalty => {}
+            }
+        }
+        box_scores.into_values().collect()
+    }
+
+    /// Add another box score's totals to this one
+    pub fn add(&mut self, other: &PlayerBoxScore) {
+        self.games += other.games;
+        self.snaps += other.snaps;
+        self.pass_attempts += other.pass_attempts;
+        self.completions += other.completions;
+        self.passing_yards += other.passing_yards;
+        self.passing_touchdowns += other.passing_touchdowns;
+        self.interceptions_thrown += other.interceptions_thrown;
+        self.times_sacked += other.times_sacked;
+        self.rush_attempts += other.rush_attempts;
+        self.rushing_yards += other.rushing_yards;
+        self.rushing_touchdowns += other.rushing_touchdowns;
+        self.fumbles_lost += other.fumbles_lost;
+        self.targets += other.targets;
+        self.receptions += other.receptions;
+        self.receiving_yards += other.receiving_yards;
+        self.receiving_touchdowns += other.receiving_touchdowns;
+        self.tackles += other.tackles;
+        self.sacks += other.sacks;
+        self.interceptions += other.interceptions;
+        self.passes_defended += other.passes_defended;
+        self.fumbles_recovered += other.fumbles_recovered;
+        self.field_goals_made += other.field_goals_made;
+        self.field_goals_attempted += other.field_goals_attempted;
+        self.extra_points_made += other.extra_points_made;
+        self.extra_points_attempted += other.extra_points_attempted;
+        self.punts += other.punts;
+    }
+
+    /// Get the yards from scrimmage rushing and receiving
+    pub fn get_scrimmage_yards(&self) -> isize {
+        self.rushing_yards + self.receiving_yards
+    }
+
+    /// Get the touchdowns thrown, run and caught
+    pub fn get_total_touchdowns(&self) -> usize {
+        self.passing_touchdowns + self.rushing_touchdowns + self.receiving_touchdowns
+    }
+}
+
+impl fmt::Display for PlayerBoxScore {
+    /// Format a `PlayerBoxScore` as the categories the player recorded
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut stat_strs: Vec<String> = Vec::new();
+        if self.snaps > 0 {
+            stat_strs.push(format!("{} snaps", self.snaps));
+        }
+        if self.pass_attempts > 0 {
+            stat_strs.push(format!("{}/{} {} pass yds {} TD {} INT", self.completions, self.pass_attempts, self.passing_yards, self.passing_touchdowns, self.interceptions_thrown));
+        }
+        if self.rush_attempts > 0 {
+            stat_strs.push(format!("{} rush {} yds {} TD", self.rush_attempts, self.rushing_yards, self.rushing_touchdowns));
+        }
+        if self.targets > 0 {
+            stat_strs.push(format!("{} rec {} yds {} TD", self.receptions, self.receiving_yards, self.receiving_touchdowns));
+        }
+        if self.tackles + self.sacks + self.interceptions + self.passes_defended > 0 {
+            stat_strs.push(format!("{} tkl {} sck {} INT {} PD", self.tackles, self.sacks, self.interceptions, self.passes_defended));
+        }
+        if self.field_goals_attempted + self.extra_points_attempted > 0 {
+            stat_strs.push(format!("{}/{} FG {}/{} XP", self.field_goals_made, self.field_goals_attempted, self.extra_points_made, self.extra_points_attempted));
+        }
+        if self.punts > 0 {
+            stat_strs.push(format!("{} punts", self.punts));
+        }
+        let box_score_str = format!("{} ({}): {}", self.name, self.team, stat_strs.join(", "));
+        f.write_str(&box_score_str)
+    }
+}
+
+/// # AverageTeamBoxScore struct
+///
+/// An `AverageTeamBoxScore` averages a team's box scores over many games
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AverageTeamBoxScore {
+    pub points: f64,
+    pub passing_yards: f64,
+    pub rushing_yards: f64,
+    pub first_downs: f64,
+    pub turnovers: f64,
+    pub num_plays: f64,
+    pub time_of_possession: f64
+}
+
+impl AverageTeamBoxScore {
+    /// Average a team's box scores, which is all zeros for no games
+    pub fn from_box_scores<'a>(box_scores: impl Iterator<Item = &'a TeamBoxScore>) -> AverageTeamBoxScore {
+        let mut average: AverageTeamBoxScore = AverageTeamBoxScore::default();
+        let mut num_games: usize = 0_usize;
+        for box_score in box_scores {
+            average.points += box_score.points as f64;
+            average.passing_yards += box_score.passing_yards as f64;
+            average.rushing_yards += box_score.rushing_yards as f64;
+            average.first_downs += box_score.first_downs as f64;
+            average.turnovers += box_score.turnovers as f64;
+            average.num_plays += box_score.num_plays as f64;
+            average.time_of_possession += box_score.time_of_possession as f64;
+            num_games += 1;
+        }
+        let num_games: f64 = num_games.max(1) as f64;
+        average.points /= num_games;
+        average.passing_yards /= num_games;
+        average.rushing_yards /= num_games;
+        average.first_downs /= num_games;
+        average.turnovers /= num_games;
+        average.num_plays /= num_games;
+        average.time_of_possession /= num_games;
+        average
+    }
+
+    /// Get the average total yards from scrimmage
+    pub fn get_total_yards(&self) -> f64 {
+        self.passing_yards + self.rushing_yards
+    }
+}
+
+impl fmt::Display for TeamBoxScore {
+    /// Format a `TeamBoxScore` as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let box_score_str = format!(
+            "{} pts, {} yds ({} pass, {} rush), {} 1st downs, {} TO, {}-{} pen, {} plays, {}:{:02} TOP",
+            self.points,
+            self.get_total_yards(),
+            self.passing_yards,
+            self.rushing_yards,
+            self.first_downs,
+            self.turnovers,
+            self.penalties,
+            self.penalty_yards,
+            self.num_plays,
+            self.time_of_possession / 60,
+            self.time_of_possession % 60
+        );
+        f.write_str(&box_score_str)
+    }
+}
+
+impl fmt::Display for AverageTeamBoxScore {
+    /// Format an `AverageTeamBoxScore` as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let box_score_str = format!(
+            "{:.1} pts, {:.1} yds ({:.1} pass, {:.1} rush), {:.1} 1st downs, {:.2} TO, {:.1} plays, {:.0}s TOP",
+            self.points,
+            self.get_total_yards(),
+            self.passing_yards,
+            self.rushing_yards,
+            self.first_downs,
+            self.turnovers,
+            self.num_plays,
+            self.time_of_possession
+        );
+        f.write_str(&box_score_str)
+    }
+}
+
+/// Get a player's box score from a game's box scores, adding it if it is
+/// not there yet
+fn get_player_box_score<'a>(box_scores: &'a mut BTreeMap<(String, String), PlayerBoxScore>, name: &str, team: &str) -> &'a mut PlayerBoxScore {
+    box_scores.entry((String::from(team), String::from(name)))
+        .or_insert_with(|| PlayerBoxScore { games: 1, ..PlayerBoxScore::new(name, team) })
+}
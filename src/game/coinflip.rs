@@ -2,11 +2,14 @@ use crate::team::team::Team;
 
 use rand::Rng;
 use rand::distributions::{Distribution, Bernoulli};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # CoinFlipDecision enum
 ///
 /// A `CoinFlipDecision` represents the possible decisions that
 /// a team can make upon winning the opening coin flip.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CoinFlipDecision {
     KICK,
     RECEIVE
@@ -17,12 +20,20 @@ pub enum CoinFlipDecision {
 /// A `CoinFlip` represents a coin flip at the beginning of a
 /// football game to determine who receives the opening kickoff,
 /// and who receives the halftime kickoff.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CoinFlip {
     pub heads: bool,
     pub guess_heads: bool,
     pub decision: CoinFlipDecision
 }
 
+impl Default for CoinFlip {
+    /// A coin flip which has not been called yet
+    fn default() -> Self {
+        CoinFlip::new()
+    }
+}
+
 impl CoinFlip {
     /// Initialize a CoinFlip struct instance with default values
     pub fn new() -> CoinFlip {
@@ -38,6 +49,15 @@ impl CoinFlip {
         (self.heads && self.guess_heads) || !(self.heads || self.guess_heads)
     }
 
+    /// Check whether the away team kicks the opening kickoff, in which
+    /// case the home team kicks the halftime kickoff
+    pub fn away_team_kicks_opening(&self) -> bool {
+        match self.decision {
+            CoinFlipDecision::KICK => self.away_team_won(),
+            CoinFlipDecision::RECEIVE => !self.away_team_won()
+        }
+    }
+
     /// Allow the away team to make a guess on the result of the
     /// coin flip.  Flip the coin, and then get the winning team's
     /// decision on who should receive the opening kickoff, and who
@@ -2,11 +2,14 @@ use std::fmt;
 use rand::Rng;
 use rand::distributions::Distribution;
 use statrs::distribution::Beta;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # GameQuarter enum
 ///
 /// A `GameQuarter` represents a quarter of a football game
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameQuarter {
     Pregame,
     First,
@@ -21,12 +24,20 @@ pub enum GameQuarter {
 /// # GameClock struct
 ///
 /// A `GameClock` represents the score of a football game
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameClock {
     pub quarter: GameQuarter,
     pub game_clock_seconds: usize,
     pub play_clock_seconds: usize
 }
 
+impl Default for GameClock {
+    /// A pregame clock with a full first quarter to play
+    fn default() -> Self {
+        GameClock::new()
+    }
+}
+
 impl GameClock {
     /// Initialize a zeroed GameClock
     pub fn new() -> GameClock {
@@ -49,22 +60,46 @@ impl GameClock {
         self.quarter == GameQuarter::Postgame
     }
 
+    /// Return true if the current quarter has expired
+    pub fn is_quarter_over(&self) -> bool {
+        self.game_clock_seconds == 0
+    }
+
+    /// Advance to the next quarter and reset the game clock.  Halftime is
+    /// skipped straight to the third quarter, and the fourth quarter ends
+    /// the game.
+    pub fn end_quarter(&mut self) {
+        self.quarter = match self.quarter {
+            GameQuarter::Pregame => GameQuarter::First,
+            GameQuarter::First => GameQuarter::Second,
+            GameQuarter::Second => GameQuarter::Third,
+            GameQuarter::Halftime => GameQuarter::Third,
+            GameQuarter::Third => GameQuarter::Fourth,
+            GameQuarter::Fourth => GameQuarter::Postgame,
+            GameQuarter::Overtime => GameQuarter::Postgame,
+            GameQuarter::Postgame => GameQuarter::Postgame
+        };
+        self.game_clock_seconds = 900_usize;
+    }
+
+    /// Start a period of overtime given its length in seconds
+    pub fn start_overtime(&mut self, period_seconds: usize) {
+        self.quarter = GameQuarter::Overtime;
+        self.game_clock_seconds = period_seconds;
+    }
+
     /// Increment the game clock randomly given an expected number of seconds
     pub fn increment_game_clock(&mut self, expected_seconds: usize, mut rng: &mut impl Rng) {
-        // Initialize a variable tracking the expected seconds, max 50
-        let mean_seconds: usize = if expected_seconds > 30 { 30_usize } else { expected_seconds };
+        // Initialize a variable tracking the expected seconds, max 45
+        let mean_seconds: usize = expected_seconds.clamp(1_usize, 44_usize);
 
         // Map to a beta distribution centered at that mean & generate a play time
-        let dst_play_time: Beta = Beta::new(mean_seconds as f64 / 30_usize as f64, 5.0_f64).unwrap();
-        let play_time_seconds: usize = (dst_play_time.sample(&mut rng) * 30_f64) as usize;
-        println!("Play time: {}", play_time_seconds);
+        let mean: f64 = mean_seconds as f64 / 45_f64;
+        let dst_play_time: Beta = Beta::new(mean * 20_f64, (1_f64 - mean) * 20_f64).unwrap();
+        let play_time_seconds: usize = (dst_play_time.sample(&mut rng) * 45_f64).round() as usize;
 
         // Subtract the play time from the game clock
-        if play_time_seconds > self.game_clock_seconds {
-            self.game_clock_seconds = 0;
-        } else {
-            self.game_clock_seconds = self.game_clock_seconds - play_time_seconds;
-        }
+        self.game_clock_seconds = self.game_clock_seconds.saturating_sub(play_time_seconds);
     }
 
     /// Format the game clock as a string
@@ -79,6 +114,21 @@ impl GameClock {
         format!("{}:{}", minutes, seconds_str)
     }
 
+    /// Get the number of the current quarter, with overtime as the fifth
+    /// quarter and zero outside of play
+    pub fn get_quarter_number(&self) -> usize {
+        match self.quarter {
+            GameQuarter::Pregame => 0,
+            GameQuarter::First => 1,
+            GameQuarter::Second => 2,
+            GameQuarter::Halftime => 2,
+            GameQuarter::Third => 3,
+            GameQuarter::Fourth => 4,
+            GameQuarter::Overtime => 5,
+            GameQuarter::Postgame => 0
+        }
+    }
+
     /// Format the quarter as a string
     pub fn format_quarter(&self) -> String {
         let quarter_str = match self.quarter {
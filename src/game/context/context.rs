@@ -2,16 +2,27 @@ use crate::game::context::clock::GameClock;
 use crate::game::context::possession::GamePossession;
 use crate::game::context::score::GameScore;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 /// # GameContext struct
 ///
 /// A `GameContext` contains all necessary situational information about
 /// a football game to motivate playcalling
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameContext {
     pub clock: GameClock,
     pub possession: GamePossession,
     pub score: GameScore
 }
 
+impl Default for GameContext {
+    /// The pregame context of a scoreless game
+    fn default() -> Self {
+        GameContext::new()
+    }
+}
+
 impl GameContext {
     /// Initialize a new GameContext
     pub fn new() -> GameContext {
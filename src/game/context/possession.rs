@@ -1,9 +1,12 @@
 use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # GameDown enum
 ///
 /// A `GameDown` represents a down of a football game
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameDown {
     First,
     Second,
@@ -17,6 +20,7 @@ pub enum GameDown {
 ///
 /// A `GamePossession` represents the current possession on the
 /// field in a football game.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GamePossession {
     pub down: GameDown,
     pub first_down_line: isize,
@@ -25,13 +29,20 @@ pub struct GamePossession {
     pub direction_left: bool
 }
 
+impl Default for GamePossession {
+    /// The possession before the opening kickoff
+    fn default() -> Self {
+        GamePossession::new()
+    }
+}
+
 impl GamePossession {
     /// Initialize a zeroed GamePossession
     pub fn new() -> GamePossession {
         GamePossession {
             down: GameDown::Kickoff,
             first_down_line: 0_isize,
-            line_of_possession: 15_isize,
+            line_of_possession: -15_isize,
             possession_away: false,
             direction_left: false
         }
@@ -43,29 +54,59 @@ impl GamePossession {
         self.direction_left = !self.direction_left;
     }
 
-    /// Set the line of possession
+    /// Set the line of possession given a yard line and whether it is
+    /// in the possessing team's own territory
     pub fn set_line_of_possession(&mut self, yard_line: usize, own: bool) {
-        // Initialize a local mutable variable for the line of possession
-        let mut line_of_pos: isize = yard_line as isize;
-
-        // Ensure the yard line is valid
-        if line_of_pos > 50_isize {
-            line_of_pos = 50_isize;
-        }
+        // Ensure the yard line is valid, then measure it from midfield
+        let mut line_of_pos: isize = 50_isize - yard_line.min(50) as isize;
 
         // Negate the line of possession based on direction & side of field
         if own {
             if !self.direction_left {
-                line_of_pos = line_of_pos * -1_isize;
-            }
-        } else {
-            if self.direction_left {
-                line_of_pos = line_of_pos * -1_isize;
+                line_of_pos = -line_of_pos;
             }
+        } else if self.direction_left {
+            line_of_pos = -line_of_pos;
         }
         self.line_of_possession = line_of_pos;
     }
 
+    /// Place the line of possession a number of yards from the possessing
+    /// team's endzone, keeping it on the field
+    pub fn set_yards_to_endzone(&mut self, yards: isize) {
+        let yards: isize = yards.clamp(0_isize, 100_isize);
+        self.line_of_possession = match self.direction_left {
+            true => -50_isize + yards,
+            false => 50_isize - yards
+        };
+    }
+
+    /// Move the line of possession by a number of yards in the direction
+    /// of the possessing team, keeping it on the field
+    pub fn move_line_of_possession(&mut self, yards: isize) {
+        // Add the yards to the line of possession
+        if self.direction_left {
+            self.line_of_possession -= yards;
+        } else {
+            self.line_of_possession += yards;
+        }
+
+        // Max out the line of possession at += 50
+        self.line_of_possession = self.line_of_possession.clamp(-50_isize, 50_isize);
+    }
+
+    /// Check whether the line of possession is in the opponent's endzone
+    pub fn is_touchdown(&self) -> bool {
+        (self.direction_left && self.line_of_possession == -50) ||
+        (!self.direction_left && self.line_of_possession == 50)
+    }
+
+    /// Start a new set of downs from the current line of possession
+    pub fn start_series(&mut self) {
+        self.down = GameDown::First;
+        self.reset_first_down_line();
+    }
+
     /// Reset the first down line based on the line of possession
     pub fn reset_first_down_line(&mut self) {
         // If the line of possession is +/- 10 from a goal line
@@ -86,24 +127,10 @@ impl GamePossession {
     /// Increment the down after a play completes
     pub fn increment(&mut self, yards: isize) {
         // Add the yards to the line of possession
-        if self.direction_left {
-            self.line_of_possession -= yards;
-        } else {
-            self.line_of_possession += yards;
-        }
-
-        // Max out the line of possession at += 50
-        if self.line_of_possession > 50_isize {
-            self.line_of_possession = 50_isize;
-        } else if self.line_of_possession < -50_isize {
-            self.line_of_possession = -50_isize;
-        }
+        self.move_line_of_possession(yards);
 
         // Check if a touchdown was achieved
-        if self.direction_left && self.line_of_possession == -50 {
-            self.down = GameDown::PointAfter;
-            return;
-        } else if !self.direction_left && self.line_of_possession == 50 {
+        if self.is_touchdown() {
             self.down = GameDown::PointAfter;
             return;
         }
@@ -121,9 +148,8 @@ impl GamePossession {
 
         // Check if this was fourth down
         if self.down == GameDown::Fourth {
-            self.possession_away = !self.possession_away;
-            self.down = GameDown::First;
-            self.reset_first_down_line();
+            self.flip_possession();
+            self.start_series();
             return;
         }
 
@@ -151,6 +177,17 @@ impl GamePossession {
         String::from(down_str)
     }
 
+    /// Get the down as a number, or zero for kickoffs and point afters
+    pub fn get_down_number(&self) -> usize {
+        match self.down {
+            GameDown::First => 1,
+            GameDown::Second => 2,
+            GameDown::Third => 3,
+            GameDown::Fourth => 4,
+            GameDown::PointAfter | GameDown::Kickoff => 0
+        }
+    }
+
     /// Calculate the yards to the first down
     pub fn get_yards_to_first(&self) -> isize {
         (self.first_down_line - self.line_of_possession).abs()
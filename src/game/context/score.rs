@@ -1,13 +1,23 @@
 use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # GameScore struct
 ///
 /// A `GameScore` represents the score of a football game
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameScore {
     pub home_team_score: usize,
     pub away_team_score: usize
 }
 
+impl Default for GameScore {
+    /// A scoreless game
+    fn default() -> Self {
+        GameScore::new()
+    }
+}
+
 impl GameScore {
     /// Initialize a zeroed GameScore
     pub fn new() -> GameScore {
@@ -16,6 +26,17 @@ impl GameScore {
             away_team_score: 0_usize
         }
     }
+
+    /// Get the home team's share of the win, counting a tie as half
+    pub fn get_home_win_share(&self) -> f64 {
+        if self.home_team_score > self.away_team_score {
+            1_f64
+        } else if self.home_team_score < self.away_team_score {
+            0_f64
+        } else {
+            0.5_f64
+        }
+    }
 }
 
 impl fmt::Display for GameScore {
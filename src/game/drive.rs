@@ -0,0 +1,211 @@
+use crate::game::play::{Play, PlayType, PlayResult};
+
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # DriveResult enum
+///
+/// A `DriveResult` represents how a drive ended
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DriveResult {
+    Touchdown,
+    FieldGoal,
+    MissedFieldGoal,
+    Punt,
+    Turnover,
+    Downs,
+    EndOfHalf
+}
+
+impl DriveResult {
+    /// Get the result a play ends a drive with, if any
+    pub fn from_play(play: &Play) -> Option<DriveResult> {
+        match (play.play_type, play.result) {
+            (PlayType::Punt, _) => Some(DriveResult::Punt),
+            (PlayType::FieldGoal, PlayResult::Good) => Some(DriveResult::FieldGoal),
+            (PlayType::FieldGoal, _) => Some(DriveResult::MissedFieldGoal),
+            (_, PlayResult::Touchdown) => Some(DriveResult::Touchdown),
+            (_, PlayResult::Interception) => Some(DriveResult::Turnover),
+            (_, PlayResult::Fumble) => Some(DriveResult::Turnover),
+            _ if play.turnover_on_downs => Some(DriveResult::Downs),
+            _ => None
+        }
+    }
+
+    /// Format the drive result as a string
+    pub fn format_drive_result(&self) -> String {
+        let drive_result_str = match self {
+            DriveResult::Touchdown => "Touchdown",
+            DriveResult::FieldGoal => "Field Goal",
+            DriveResult::MissedFieldGoal => "Missed Field Goal",
+            DriveResult::Punt => "Punt",
+            DriveResult::Turnover => "Turnover",
+            DriveResult::Downs => "Downs",
+            DriveResult::EndOfHalf => "End of Half"
+        };
+        String::from(drive_result_str)
+    }
+}
+
+/// # Drive struct
+///
+/// A `Drive` represents consecutive plays from scrimmage by one offense.
+/// Field positions are measured in yards from the opponent's goal line,
+/// and a drive still in progress has no result.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Drive {
+    pub offense: String,
+    pub start_quarter: usize,
+    pub start_clock_seconds: usize,
+    pub start_yard_line: isize,
+    pub end_yard_line: isize,
+    pub num_plays: usize,
+    pub yards: isize,
+    pub time_of_possession: usize,
+    pub result: Option<DriveResult>
+}
+
+impl Drive {
+    /// Initialize a new drive starting with a play from scrimmage
+    pub fn new(play: &Play) -> Drive {
+        Drive {
+            offense: play.offense.clone(),
+            start_quarter: play.quarter,
+            start_clock_seconds: play.clock_seconds,
+            start_yard_line: play.yard_line,
+            end_yard_line: play.yard_line,
+            num_plays: 0_usize,
+            yards: 0_isize,
+            time_of_possession: 0_usize,
+            result: None
+        }
+    }
+
+    /// Check whether the drive has ended
+    pub fn is_over(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// Add a play from scrimmage to the drive given the seconds it took,
+    /// ending the drive if the play did.  Yards on punts and field goals
+    /// do not count towards the drive.
+    pub fn add_play(&mut self, play: &Play, seconds: usize) {
+        self.num_plays += 1;
+        self.time_of_possession += seconds;
+        match play.play_type {
+            PlayType::Punt | PlayType::FieldGoal => {
+                self.end_yard_line = play.yard_line;
+            },
+            _ => {
+                self.yards += play.yards;
+                self.end_yard_line = play.yard_line - play.yards;
+            }
+        }
+        self.result = DriveResult::from_play(play);
+    }
+
+    /// End the drive at the end of a half
+    pub fn end_half(&mut self) {
+        if !self.is_over() {
+            self.result = Some(DriveResult::EndOfHalf);
+        }
+    }
+
+    /// Format a field position as a yard line in own or opponent territory
+    fn format_yard_line(yard_line: isize) -> String {
+        if yard_line > 50 {
+            format!("own {}", 100 - yard_line)
+        } else if yard_line == 50 {
+            String::from("50")
+        } else {
+            format!("opp {}", yard_line)
+        }
+    }
+}
+
+impl fmt::Display for Drive {
+    /// Format a `Drive` as a line of a drive chart
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result_str: String = match self.result {
+            Some(result) => result.format_drive_result(),
+            None => String::from("In Progress")
+        };
+        let drive_str = format!(
+            "{} Q{} {}:{:02} | {} to {} | {} plays, {} yards, {}:{:02} | {}",
+            self.offense,
+            self.start_quarter,
+            self.start_clock_seconds / 60,
+            self.start_clock_seconds % 60,
+            Drive::format_yard_line(self.start_yard_line),
+            Drive::format_yard_line(self.end_yard_line),
+            self.num_plays,
+            self.yards,
+            self.time_of_possession / 60,
+            self.time_of_possession % 60,
+            result_str
+        );
+        f.write_str(&drive_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::context::context::GameContext;
+
+    /// Create a play from scrimmage with a yard line, yards and result
+    fn create_play(play_type: PlayType, yard_line: isize, yards: isize, result: PlayResult) -> Play {
+        let mut play: Play = Play::new(1, &GameContext::new(), "HOME", "AWAY", play_type);
+        play.quarter = 1;
+        play.yard_line = yard_line;
+        play.complete(yards, result, "");
+        play
+    }
+
+    #[test]
+    fn test_drive_ends_on_its_final_play() {
+        let mut drive: Drive = Drive::new(&create_play(PlayType::Run, 75, 5, PlayResult::Gain));
+        drive.add_play(&create_play(PlayType::Run, 75, 5, PlayResult::Gain), 30);
+        drive.add_play(&create_play(PlayType::Pass, 70, 15, PlayResult::FirstDown), 20);
+        assert!(!drive.is_over());
+        drive.add_play(&create_play(PlayType::Punt, 55, 40, PlayResult::FairCatch), 10);
+        assert_eq!(drive.result, Some(DriveResult::Punt));
+        assert_eq!(drive.num_plays, 3);
+        assert_eq!(drive.yards, 20);
+        assert_eq!(drive.end_yard_line, 55);
+        assert_eq!(drive.time_of_possession, 60);
+        assert_eq!(format!("{}", drive), "HOME Q1 15:00 | own 25 to own 45 | 3 plays, 20 yards, 1:00 | Punt");
+    }
+
+    #[test]
+    fn test_drive_results_from_plays() {
+        let mut turnover_on_downs: Play = create_play(PlayType::Pass, 30, 0, PlayResult::Incomplete);
+        turnover_on_downs.turnover_on_downs = true;
+        for (play, result) in [
+            (create_play(PlayType::FieldGoal, 20, 0, PlayResult::Good), Some(DriveResult::FieldGoal)),
+            (create_play(PlayType::FieldGoal, 40, 0, PlayResult::NoGood), Some(DriveResult::MissedFieldGoal)),
+            (create_play(PlayType::Run, 5, 5, PlayResult::Touchdown), Some(DriveResult::Touchdown)),
+            (create_play(PlayType::Pass, 50, 0, PlayResult::Interception), Some(DriveResult::Turnover)),
+            (create_play(PlayType::Run, 50, 2, PlayResult::Fumble), Some(DriveResult::Turnover)),
+            (turnover_on_downs, Some(DriveResult::Downs)),
+            (create_play(PlayType::Pass, 50, 0, PlayResult::Incomplete), None)
+        ] {
+            assert_eq!(DriveResult::from_play(&play), result);
+        }
+    }
+
+    #[test]
+    fn test_end_half_keeps_finished_result() {
+        let mut drive: Drive = Drive::new(&create_play(PlayType::Run, 40, 3, PlayResult::Gain));
+        drive.add_play(&create_play(PlayType::Run, 40, 3, PlayResult::Gain), 5);
+        drive.end_half();
+        assert_eq!(drive.result, Some(DriveResult::EndOfHalf));
+        let mut finished: Drive = Drive::new(&create_play(PlayType::Run, 5, 5, PlayResult::Touchdown));
+        finished.add_play(&create_play(PlayType::Run, 5, 5, PlayResult::Touchdown), 5);
+        finished.end_half();
+        assert_eq!(finished.result, Some(DriveResult::Touchdown));
+    }
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The temperature in degrees Fahrenheit below which the cold starts to
+/// affect the ball
+const COLD_TEMPERATURE: f64 = 50_f64;
+
+/// The temperature in degrees Fahrenheit under a dome
+const DOME_TEMPERATURE: f64 = 70_f64;
+
+/// The wind speed in miles per hour below which passes are unaffected
+const CALM_WIND_SPEED: f64 = 8_f64;
+
+/// # Precipitation enum
+///
+/// A `Precipitation` represents what is falling on the field
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Precipitation {
+    Clear,
+    Rain,
+    Snow
+}
+
+impl Precipitation {
+    /// Format the precipitation as a string
+    pub fn format_precipitation(&self) -> String {
+        let precipitation_str = match self {
+            Precipitation::Clear => "Clear",
+            Precipitation::Rain => "Rain",
+            Precipitation::Snow => "Snow"
+        };
+        String::from(precipitation_str)
+    }
+}
+
+/// # Surface enum
+///
+/// A `Surface` represents the playing surface of a field
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Surface {
+    Grass,
+    Turf
+}
+
+impl Surface {
+    /// Format the surface as a string
+    pub fn format_surface(&self) -> String {
+        let surface_str = match self {
+            Surface::Grass => "Grass",
+            Surface::Turf => "Turf"
+        };
+        String::from(surface_str)
+    }
+}
+
+/// # GameEnvironment struct
+///
+/// A `GameEnvironment` describes the conditions a game is played in.  The
+/// temperature is in degrees Fahrenheit, the wind speed in miles per hour
+/// and the altitude in feet.  The wind direction is the direction the wind
+/// blows towards in degrees, where 0 blows straight towards the endzone a
+/// team driving right is attacking, 180 towards the other endzone, and 90
+/// and 270 across the field.  Under a dome there is no wind or
+/// precipitation and the temperature is mild.
+///
+/// Kicks carry further with the wind, in thin air at altitude and in warm
+/// weather, and fall short into the wind, in the cold and through rain or
+/// snow.  Crosswinds and precipitation make kicks less accurate, strong
+/// winds, the cold and precipitation make passes less accurate, and the
+/// cold and precipitation make the ball harder to hold on to, especially
+/// on a muddy grass field.  The default environment has no effect on play.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameEnvironment {
+    pub temperature: f64,
+    pub wind_speed: f64,
+    pub wind_direction: f64,
+    pub precipitation: Precipitation,
+    pub altitude: f64,
+    pub surface: Surface,
+    pub dome: bool
+}
+
+impl Default for GameEnvironment {
+    /// Mild, calm and clear conditions on grass at sea level
+    fn default() -> Self {
+        GameEnvironment {
+            temperature: DOME_TEMPERATURE,
+            wind_speed: 0_f64,
+            wind_direction: 0_f64,
+            precipitation: Precipitation::Clear,
+            altitude: 0_f64,
+            surface: Surface::Grass,
+            dome: false
+        }
+    }
+}
+
+impl GameEnvironment {
+    /// Initialize a new outdoor environment
+    pub fn new(temperature: f64, wind_speed: f64, wind_direction: f64, precipitation: Precipitation, altitude: f64, surface: Surface) -> GameEnvironment {
+        GameEnvironment {
+            temperature,
+            wind_speed: wind_speed.max(0_f64),
+            wind_direction,
+            precipitation,
+            altitude,
+            surface,
+            dome: false
+        }
+    }
+
+    /// Initialize a new environment under a dome
+    pub fn dome(altitude: f64, surface: Surface) -> GameEnvironment {
+        GameEnvironment {
+            altitude,
+            surface,
+            dome: true,
+            ..Default::default()
+        }
+    }
+
+    /// Get the temperature the game is played in
+    pub fn get_temperature(&self) -> f64 {
+        match self.dome {
+            true => DOME_TEMPERATURE,
+            false => self.temperature
+        }
+    }
+
+    /// Get the wind speed on the field
+    pub fn get_wind_speed(&self) -> f64 {
+        match self.dome {
+            true => 0_f64,
+            false => self.wind_speed
+        }
+    }
+
+    /// Get the precipitation falling on the field
+    pub fn get_precipitation(&self) -> Precipitation {
+        match self.dome {
+            true => Precipitation::Clear,
+            false => self.precipitation
+        }
+    }
+
+    /// Get the wind at the back of a team driving in a direction, negative
+    /// for a headwind
+    pub fn get_tailwind(&self, direction_left: bool) -> f64 {
+        let tailwind: f64 = self.get_wind_speed() * self.wind_direction.to_radians().cos();
+        match direction_left {
+            true => -tailwind,
+            false => tailwind
+        }
+    }
+
+    /// Get the wind blowing across the field
+    pub fn get_crosswind(&self) -> f64 {
+        (self.get_wind_speed() * self.wind_direction.to_radians().sin()).abs()
+    }
+
+    /// Get the degrees below the temperature at which the cold matters
+    fn get_degrees_cold(&self) -> f64 {
+        (COLD_TEMPERATURE - self.get_temperature()).max(0_f64)
+    }
+
+    /// Get the factor applied to the distance of a kick by a team driving
+    /// in a direction
+    pub fn get_kick_distance_factor(&self, direction_left: bool) -> f64 {
+        let mut factor: f64 = 1_f64 + 0.006_f64 * self.get_tailwind(direction_left);
+        factor += 0.00002_f64 * self.altitude.max(0_f64);
+        factor -= 0.002_f64 * self.get_degrees_cold();
+        factor -= match self.get_precipitation() {
+            Precipitation::Clear => 0_f64,
+            Precipitation::Rain => 0.03_f64,
+            Precipitation::Snow => 0.06_f64
+        };
+        factor.clamp(0.6_f64, 1.4_f64)
+    }
+
+    /// Get the change in the probability that a kick is on target
+    pub fn get_kick_accuracy_modifier(&self) -> f64 {
+        let mut modifier: f64 = -0.006_f64 * self.get_crosswind();
+        modifier -= 0.001_f64 * self.get_degrees_cold();
+        modifier -= match self.get_precipitation() {
+            Precipitation::Clear => 0_f64,
+            Precipitation::Rain => 0.03_f64,
+            Precipitation::Snow => 0.05_f64
+        };
+        modifier
+    }
+
+    /// Get the change in the probability that a pass is completed, deep
+    /// passes being hit harder by the wind
+    pub fn get_pass_accuracy_modifier(&self, deep: bool) -> f64 {
+        let wind_factor: f64 = match deep {
+            true => 0.006_f64,
+            false => 0.003_f64
+        };
+        let mut modifier: f64 = -wind_factor * (self.get_wind_speed() - CALM_WIND_SPEED).max(0_f64);
+        modifier -= 0.001_f64 * self.get_degrees_cold();
+        modifier -= match self.get_precipitation() {
+            Precipitation::Clear => 0_f64,
+            Precipitation::Rain => 0.03_f64,
+            Precipitation::Snow => 0.04_f64
+        };
+        modifier
+    }
+
+    /// Get the change in the probability that a ball carrier fumbles
+    pub fn get_fumble_modifier(&self) -> f64 {
+        let mut modifier: f64 = 0.0002_f64 * self.get_degrees_cold();
+        modifier += match self.get_precipitation() {
+            Precipitation::Clear => 0_f64,
+            Precipitation::Rain => 0.006_f64,
+            Precipitation::Snow => 0.008_f64
+        };
+        if self.surface == Surface::Grass && self.get_precipitation() != Precipitation::Clear {
+            modifier += 0.002_f64;
+        }
+        modifier
+    }
+}
+
+impl fmt::Display for GameEnvironment {
+    /// Format a `GameEnvironment` as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let environment_str = match self.dome {
+            true => format!("Dome, {}, {:.0} ft", self.surface.format_surface(), self.altitude),
+            false => format!(
+                "{:.0}F, wind {:.0} mph at {:.0} degrees, {}, {}, {:.0} ft",
+                self.temperature,
+                self.wind_speed,
+                self.wind_direction,
+                self.precipitation.format_precipitation(),
+                self.surface.format_surface(),
+                self.altitude
+            )
+        };
+        f.write_str(&environment_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_environment_has_no_effect() {
+        let environment: GameEnvironment = GameEnvironment::default();
+        for direction_left in [false, true] {
+            assert_eq!(environment.get_kick_distance_factor(direction_left), 1_f64);
+        }
+        assert_eq!(environment.get_kick_accuracy_modifier(), 0_f64);
+        assert_eq!(environment.get_pass_accuracy_modifier(true), 0_f64);
+        assert_eq!(environment.get_fumble_modifier(), 0_f64);
+    }
+
+    #[test]
+    fn test_headwind_shortens_kicks() {
+        // A wind blowing towards the right endzone helps a team driving
+        // right and hurts a team driving left
+        let environment: GameEnvironment = GameEnvironment::new(70_f64, 20_f64, 0_f64, Precipitation::Clear, 0_f64, Surface::Grass);
+        assert!(environment.get_tailwind(true) < 0_f64);
+        assert!(environment.get_kick_distance_factor(true) < 1_f64);
+        assert!(environment.get_kick_distance_factor(false) > 1_f64);
+
+        // Turning the wind around swaps the headwind, and a crosswind only
+        // costs accuracy
+        let reversed: GameEnvironment = GameEnvironment { wind_direction: 180_f64, ..environment };
+        assert!((reversed.get_kick_distance_factor(false) - environment.get_kick_distance_factor(true)).abs() < 1e-9);
+        let crosswind: GameEnvironment = GameEnvironment { wind_direction: 90_f64, ..environment };
+        assert!((crosswind.get_kick_distance_factor(true) - 1_f64).abs() < 1e-9);
+        assert!(crosswind.get_kick_accuracy_modifier() < environment.get_kick_accuracy_modifier());
+    }
+
+    #[test]
+    fn test_dome_ignores_weather() {
+        let weather: GameEnvironment = GameEnvironment::new(10_f64, 30_f64, 0_f64, Precipitation::Snow, 0_f64, Surface::Grass);
+        let dome: GameEnvironment = GameEnvironment { dome: true, ..weather };
+        assert!(weather.get_kick_distance_factor(true) < 1_f64);
+        assert!(weather.get_fumble_modifier() > 0_f64);
+        for direction_left in [false, true] {
+            assert_eq!(dome.get_kick_distance_factor(direction_left), 1_f64);
+        }
+        assert_eq!(dome.get_kick_accuracy_modifier(), 0_f64);
+        assert_eq!(dome.get_pass_accuracy_modifier(true), 0_f64);
+        assert_eq!(dome.get_fumble_modifier(), 0_f64);
+
+        // Altitude still matters under a dome
+        assert!(GameEnvironment::dome(5000_f64, Surface::Turf).get_kick_distance_factor(true) > 1_f64);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::game::context::possession::{GameDown, GamePossession};
+use crate::game::game::Game;
+use crate::game::play::{Play, PlayType};
+use crate::game::winprob::solve_linear_system;
+
+use std::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The number of features in the expected points model
+pub const NUM_EXPECTED_POINTS_FEATURES: usize = 8;
+
+/// The expected points of a point after attempt
+pub const POINT_AFTER_EXPECTED_POINTS: f64 = 0.95_f64;
+
+/// The default coefficients of the expected points model, fit to 4000
+/// seeded games between teams from `TeamGenerator::new(6.0, 1.5)`
+const DEFAULT_COEFFICIENTS: [f64; NUM_EXPECTED_POINTS_FEATURES] = [
+    6.8570_f64,
+    -9.5424_f64,
+    2.7298_f64,
+    -0.4639_f64,
+    -1.1788_f64,
+    -2.0003_f64,
+    -0.8131_f64,
+    0.2517_f64
+];
+
+/// # ExpectedPointsModel struct
+///
+/// An `ExpectedPointsModel` is a linear regression giving the net points
+/// the offense can expect to score next in the half given the down, the
+/// distance to the first down, and the distance to the endzone
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExpectedPointsModel {
+    pub coefficients: [f64; NUM_EXPECTED_POINTS_FEATURES]
+}
+
+impl Default for ExpectedPointsModel {
+    /// Initialize the model with its default coefficients
+    fn default() -> Self {
+        ExpectedPointsModel::new(DEFAULT_COEFFICIENTS)
+    }
+}
+
+impl ExpectedPointsModel {
+    /// Initialize a new model given its coefficients
+    pub fn new(coefficients: [f64; NUM_EXPECTED_POINTS_FEATURES]) -> ExpectedPointsModel {
+        ExpectedPointsModel {
+            coefficients
+        }
+    }
+
+    /// Get the model features of a down, distance, and yard line.  Field
+    /// position enters as a quadratic, and long distances are costlier on
+    /// third and fourth down.
+    pub fn get_features(down: usize, distance: isize, yard_line: isize) -> [f64; NUM_EXPECTED_POINTS_FEATURES] {
+        let field_position: f64 = yard_line as f64 / 100_f64;
+        let distance: f64 = distance as f64 / 10_f64;
+        let late_down: f64 = if down >= 3 { 1_f64 } else { 0_f64 };
+        [
+            1_f64,
+            field_position,
+            field_position * field_position,
+            if down == 2 { 1_f64 } else { 0_f64 },
+            if down == 3 { 1_f64 } else { 0_f64 },
+            if down == 4 { 1_f64 } else { 0_f64 },
+            distance,
+            distance * late_down
+        ]
+    }
+
+    /// Get the expected points of a down, distance, and yard line
+    pub fn get_expected_points(&self, down: usize, distance: isize, yard_line: isize) -> f64 {
+        let features = ExpectedPointsModel::get_features(down, distance, yard_line);
+        let mut expected_points: f64 = 0_f64;
+        for (coefficient, feature) in self.coefficients.iter().zip(features.iter()) {
+            expected_points += coefficient * feature;
+        }
+        expected_points
+    }
+
+    /// Get the expected points of the team in possession.  A point after
+    /// attempt is worth its own expected points, and a kickoff is worth
+    /// the opposite of the receiving team's expected points after a
+    /// touchback.
+    pub fn get_possession_expected_points(&self, possession: &GamePossession) -> f64 {
+        let down: usize = match possession.down {
+            GameDown::PointAfter => return POINT_AFTER_EXPECTED_POINTS,
+            GameDown::Kickoff => return -self.get_expected_points(1, 10, 75),
+            GameDown::First => 1,
+            GameDown::Second => 2,
+            GameDown::Third => 3,
+            GameDown::Fourth => 4
+        };
+        self.get_expected_points(down, possession.get_yards_to_first(), possession.get_yards_to_endzone())
+    }
+
+    /// Get the situation before every play from scrimmage of a finished
+    /// game alongside the net points the offense scored next in the half
+    pub fn get_samples(game: &Game) -> Vec<([f64; NUM_EXPECTED_POINTS_FEATURES], f64)> {
+        let plays: &Vec<Play> = &game.plays;
+        let is_first_half = |play: &Play| play.quarter <= 2;
+        let mut samples: Vec<([f64; NUM_EXPECTED_POINTS_FEATURES], f64)> = Vec::new();
+        for (i, play) in plays.iter().enumerate() {
+            if !play.is_from_scrimmage() {
+                continue;
+            }
+
+            // Find the next play at which the score has changed, scored by
+            // the play before it, and skip ahead to the following kickoff
+            // so that point after attempts are included
+            let next_score = plays.iter().enumerate().skip(i + 1).find(|(_, next)| {
+                next.home_score != play.home_score || next.away_score != play.away_score
+            });
+            let (home_points, away_points) = match next_score {
+                Some((j, _)) if is_first_half(&plays[j - 1]) == is_first_half(play) => {
+                    let (home_score, away_score) = match plays.iter().skip(j).find(|next| next.play_type == PlayType::Kickoff) {
+                        Some(kickoff) => (kickoff.home_score, kickoff.away_score),
+                        None => (game.context.score.home_team_score, game.context.score.away_team_score)
+                    };
+                    (home_score as f64 - play.home_score as f64, away_score as f64 - play.away_score as f64)
+                },
+                _ => (0_f64, 0_f64)
+            };
+            let net_points: f64 = match play.offense == game.home_team.abbreviation {
+                true => home_points - away_points,
+                false => away_points - home_points
+            };
+            samples.push((ExpectedPointsModel::get_features(play.down, play.distance, play.yard_line), net_points));
+        }
+        samples
+    }
+
+    /// Fit a model to a batch of finished games using least squares
+    pub fn fit(games: &[Game]) -> ExpectedPointsModel {
+        const N: usize = NUM_EXPECTED_POINTS_FEATURES;
+        let mut xtx: [[f64; N]; N] = [[0_f64; N]; N];
+        let mut xty: [f64; N] = [0_f64; N];
+        for (i, row) in xtx.iter_mut().enumerate() {
+            row[i] = 1e-6_f64;
+        }
+        for game in games.iter() {
+            for (features, net_points) in ExpectedPointsModel::get_samples(game) {
+                for i in 0..N {
+                    xty[i] += features[i] * net_points;
+                    for j in 0..N {
+                        xtx[i][j] += features[i] * features[j];
+                    }
+                }
+            }
+        }
+        ExpectedPointsModel::new(solve_linear_system(xtx, xty))
+    }
+}
+
+/// # EpaSummary struct
+///
+/// An `EpaSummary` totals the expected points added over a group of plays
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EpaSummary {
+    pub num_plays: usize,
+    pub total_epa: f64
+}
+
+impl EpaSummary {
+    /// Add a play's expected points added to the summary
+    pub fn add(&mut self, epa: f64) {
+        self.num_plays += 1;
+        self.total_epa += epa;
+    }
+
+    /// Get the average expected points added per play
+    pub fn get_epa_per_play(&self) -> f64 {
+        if self.num_plays == 0 {
+            return 0_f64;
+        }
+        self.total_epa / self.num_plays as f64
+    }
+}
+
+/// Aggregate the expected points added of every play from scrimmage by
+/// the offense's team abbreviation
+pub fn aggregate_epa_by_team(games: &[Game]) -> BTreeMap<String, EpaSummary> {
+    let mut summaries: BTreeMap<String, EpaSummary> = BTreeMap::new();
+    for play in games.iter().flat_map(|game| game.plays.iter()) {
+        if let Some(epa) = play.epa {
+            summaries.entry(play.offense.clone()).or_default().add(epa);
+        }
+    }
+    summaries
+}
+
+/// Aggregate the expected points added of every run and pass by the
+/// offensive play called
+pub fn aggregate_epa_by_play_call(games: &[Game]) -> BTreeMap<String, EpaSummary> {
+    let mut summaries: BTreeMap<String, EpaSummary> = BTreeMap::new();
+    for play in games.iter().flat_map(|game| game.plays.iter()) {
+        if let (Some(epa), Some(play_call)) = (play.epa, play.play_call) {
+            summaries.entry(play_call.format_offensive_play()).or_default().add(epa);
+        }
+    }
+    summaries
+}
+
+/// Aggregate the expected points added of every run and pass by player,
+/// keyed by team abbreviation and player name.  Passes count towards both
+/// the passer and the target.
+pub fn aggregate_epa_by_player(games: &[Game]) -> BTreeMap<(String, String), EpaSummary> {
+    let mut summaries: BTreeMap<(String, String), EpaSummary> = BTreeMap::new();
+    for play in games.iter().flat_map(|game| game.plays.iter()) {
+        let epa: f64 = match play.epa {
+            Some(epa) => epa,
+            None => continue
+        };
+        for player in play.passer.iter().chain(play.ball_carrier.iter()) {
+            summaries.entry((play.offense.clone(), player.clone())).or_default().add(epa);
+        }
+    }
+    summaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::play::PlayResult;
+    use crate::team::generator::generate_test_teams;
+
+    #[test]
+    fn test_expected_points_follow_field_position_and_down() {
+        let model: ExpectedPointsModel = ExpectedPointsModel::default();
+        assert!(model.get_expected_points(1, 10, 20) > model.get_expected_points(1, 10, 50));
+        assert!(model.get_expected_points(1, 10, 50) > model.get_expected_points(1, 10, 90));
+        assert!(model.get_expected_points(1, 10, 50) > model.get_expected_points(3, 10, 50));
+        assert!(model.get_expected_points(3, 2, 50) > model.get_expected_points(3, 12, 50));
+    }
+
+    #[test]
+    fn test_possession_expected_points_of_kicks() {
+        let model: ExpectedPointsModel = ExpectedPointsModel::default();
+        let mut possession: GamePossession = GamePossession::new();
+        assert_eq!(model.get_possession_expected_points(&possession), -model.get_expected_points(1, 10, 75));
+        possession.down = GameDown::PointAfter;
+        assert_eq!(model.get_possession_expected_points(&possession), POINT_AFTER_EXPECTED_POINTS);
+    }
+
+    #[test]
+    fn test_epa_aggregates_match_play_totals() {
+        let (home_team, away_team) = generate_test_teams(0);
+        let mut game: Game = Game::from_seed(home_team, away_team, 0);
+        game.simulate_seeded().unwrap();
+        let games: Vec<Game> = vec![game];
+        let total_epa: f64 = games[0].plays.iter().filter_map(|play| play.epa).sum();
+        let num_epa_plays: usize = games[0].plays.iter().filter(|play| play.epa.is_some()).count();
+        let by_team: BTreeMap<String, EpaSummary> = aggregate_epa_by_team(&games);
+        assert_eq!(by_team.len(), 2);
+        assert_eq!(by_team.values().map(|summary| summary.num_plays).sum::<usize>(), num_epa_plays);
+        assert!((by_team.values().map(|summary| summary.total_epa).sum::<f64>() - total_epa).abs() < 1e-9);
+        let by_play_call: BTreeMap<String, EpaSummary> = aggregate_epa_by_play_call(&games);
+        let num_called: usize = games[0].plays.iter().filter(|play| play.epa.is_some() && play.play_call.is_some()).count();
+        assert_eq!(by_play_call.values().map(|summary| summary.num_plays).sum::<usize>(), num_called);
+        assert_eq!(EpaSummary::default().get_epa_per_play(), 0_f64);
+    }
+
+    #[test]
+    fn test_samples_credit_the_next_score_to_the_offense() {
+        let (home_team, away_team) = generate_test_teams(1);
+        let mut game: Game = Game::from_seed(home_team, away_team, 1);
+        game.simulate_seeded().unwrap();
+        let samples = ExpectedPointsModel::get_samples(&game);
+        assert_eq!(samples.len(), game.plays.iter().filter(|play| play.is_from_scrimmage()).count());
+
+        // A touchdown drive's scoring play is worth the touchdown and the
+        // point after to its offense
+        let touchdown: usize = game.plays.iter()
+            .filter(|play| play.is_from_scrimmage())
+            .position(|play| play.result == PlayResult::Touchdown)
+            .unwrap();
+        let (_, net_points) = samples[touchdown];
+        assert!(net_points == 6_f64 || net_points == 7_f64 || net_points == 8_f64);
+    }
+}
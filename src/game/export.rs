@@ -0,0 +1,79 @@
+use crate::game::play::{Play, PLAY_COLUMNS};
+
+use std::io;
+use std::io::Write;
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break
+fn format_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        String::from(field)
+    }
+}
+
+/// Write plays as CSV, starting with a header row of `PLAY_COLUMNS`
+pub fn write_plays_csv(plays: &[Play], writer: &mut impl Write) -> io::Result<()> {
+    writeln!(writer, "{}", PLAY_COLUMNS.join(","))?;
+    for play in plays.iter() {
+        let fields: Vec<String> = play.get_csv_fields()
+            .iter()
+            .map(|field| format_csv_field(field))
+            .collect();
+        writeln!(writer, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+/// Write plays as JSON Lines, one JSON object per play
+#[cfg(feature = "serde")]
+pub fn write_plays_jsonl(plays: &[Play], writer: &mut impl Write) -> io::Result<()> {
+    for play in plays.iter() {
+        serde_json::to_writer(&mut *writer, play)?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::context::context::GameContext;
+    use crate::game::play::{PlayType, PlayResult};
+
+    #[test]
+    fn test_csv_fields_are_quoted_when_needed() {
+        assert_eq!(format_csv_field("HOME 11 Shotgun"), "HOME 11 Shotgun");
+        assert_eq!(format_csv_field("Pass short, incomplete"), "\"Pass short, incomplete\"");
+        assert_eq!(format_csv_field("The \"Bus\""), "\"The \"\"Bus\"\"\"");
+    }
+
+    #[test]
+    fn test_csv_rows_match_header() {
+        let mut play: Play = Play::new(1, &GameContext::new(), "HOME", "AWAY", PlayType::Pass);
+        play.complete(-7, PlayResult::Sack, "QB sacked, for a loss of 7");
+        play.turnover_on_downs = true;
+        let mut buffer: Vec<u8> = Vec::new();
+        write_plays_csv(&[play.clone(), play], &mut buffer).unwrap();
+        let csv: String = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], PLAY_COLUMNS.join(","));
+        assert!(lines[1].contains(",Sack,true,"));
+        assert!(lines[1].contains(",\"QB sacked, for a loss of 7\","));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_jsonl_round_trips_plays() {
+        let mut play: Play = Play::new(1, &GameContext::new(), "HOME", "AWAY", PlayType::Run);
+        play.complete(3, PlayResult::Gain, "Run for 3");
+        let mut buffer: Vec<u8> = Vec::new();
+        write_plays_jsonl(&[play.clone(), play.clone()], &mut buffer).unwrap();
+        let jsonl: String = String::from_utf8(buffer).unwrap();
+        for line in jsonl.lines() {
+            assert_eq!(serde_json::from_str::<Play>(line).unwrap(), play);
+        }
+        assert_eq!(jsonl.lines().count(), 2);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::game::play::{PlayType, PlayResult};
+use crate::team::position::Position;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The share of a player's ratings lost when they are fully exhausted
+pub const FATIGUE_PENALTY: f64 = 0.2_f64;
+
+/// The share of a player's fatigue shed for each play spent on the
+/// sideline
+pub const SIDELINE_RECOVERY: f64 = 0.2_f64;
+
+/// Get how tiring a snap is for a player at a position.  Linemen on the
+/// defense and running backs take the most punishment, while quarterbacks
+/// and offensive linemen barely tire.
+pub fn get_fatigue_rate(position: Position) -> f64 {
+    match position {
+        Position::DefensiveTackle => 0.1_f64,
+        Position::DefensiveEnd => 0.08_f64,
+        Position::Runningback | Position::Returner => 0.09_f64,
+        Position::Fullback => 0.06_f64,
+        Position::Linebacker => 0.05_f64,
+        Position::WideReceiver | Position::TightEnd => 0.05_f64,
+        Position::Cornerback | Position::Safety => 0.04_f64,
+        Position::OffensiveLineman => 0.03_f64,
+        Position::Quarterback => 0.02_f64,
+        Position::Kicker | Position::Punter | Position::LongSnapper => 0.01_f64
+    }
+}
+
+/// Get the fatigue at which a player at a position rotates out for a
+/// fresher backup, if players at the position rotate at all
+pub fn get_rotation_fatigue(position: Position) -> Option<f64> {
+    match position {
+        Position::DefensiveTackle | Position::DefensiveEnd => Some(0.35_f64),
+        Position::Runningback | Position::Fullback | Position::Returner => Some(0.35_f64),
+        Position::WideReceiver | Position::TightEnd | Position::Linebacker => Some(0.5_f64),
+        Position::Cornerback | Position::Safety => Some(0.6_f64),
+        _ => None
+    }
+}
+
+/// Check whether a player is tired enough to rotate out for a backup
+pub fn is_rotating_out(position: Position, fatigue: f64) -> bool {
+    match get_rotation_fatigue(position) {
+        Some(rotation_fatigue) => fatigue >= rotation_fatigue,
+        None => false
+    }
+}
+
+/// Get how intense a play was, which scales the fatigue of every player on
+/// the field.  Runs are more physical than passes, and long plays mean
+/// more running for everyone.
+pub fn get_play_intensity(play_type: PlayType, result: PlayResult, yards: isize) -> f64 {
+    let base_intensity: f64 = match (play_type, result) {
+        (PlayType::Run, _) => 1_f64,
+        (PlayType::Pass, PlayResult::Sack) => 1_f64,
+        (PlayType::Pass, PlayResult::Incomplete) => 0.7_f64,
+        (PlayType::Pass, _) => 0.85_f64,
+        _ => 0.5_f64
+    };
+    base_intensity * (1_f64 + yards.max(0) as f64 / 30_f64)
+}
+
+/// Add a snap's fatigue to a player's current fatigue.  Each snap closes a
+/// share of the gap to full exhaustion.
+pub fn add_snap_fatigue(fatigue: f64, position: Position, intensity: f64) -> f64 {
+    let load: f64 = (get_fatigue_rate(position) * intensity).min(1_f64);
+    fatigue + (1_f64 - fatigue) * load
+}
+
+/// Recover a player's fatigue over a number of plays on the sideline
+pub fn recover_fatigue(fatigue: f64, num_plays: usize) -> f64 {
+    fatigue * (1_f64 - SIDELINE_RECOVERY).powi(num_plays as i32)
+}
+
+/// # SnapCount struct
+///
+/// A `SnapCount` counts the plays from scrimmage a player was on the field
+/// for during a game, identifying the player by name, position and team
+/// abbreviation
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SnapCount {
+    pub player: String,
+    pub team: String,
+    pub position: Position,
+    pub snaps: usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::attributes::{Attribute, PlayerAttributes};
+    use crate::team::player::Player;
+    use crate::team::players::select_players;
+
+    #[test]
+    fn test_snaps_tire_players_towards_exhaustion() {
+        let mut fatigue: f64 = 0_f64;
+        for _ in 0..200 {
+            let next: f64 = add_snap_fatigue(fatigue, Position::DefensiveTackle, 1_f64);
+            assert!(next > fatigue && next < 1_f64);
+            fatigue = next;
+        }
+
+        // Linemen on the defense tire faster than quarterbacks, and runs
+        // tire everyone more than incomplete passes
+        assert!(add_snap_fatigue(0_f64, Position::DefensiveTackle, 1_f64) > add_snap_fatigue(0_f64, Position::Quarterback, 1_f64));
+        assert!(get_play_intensity(PlayType::Run, PlayResult::Gain, 3) > get_play_intensity(PlayType::Pass, PlayResult::Incomplete, 0));
+        assert!(get_play_intensity(PlayType::Run, PlayResult::Gain, 30) > get_play_intensity(PlayType::Run, PlayResult::Gain, 3));
+    }
+
+    #[test]
+    fn test_sideline_recovery() {
+        assert_eq!(recover_fatigue(0.5_f64, 0), 0.5_f64);
+        assert!((recover_fatigue(0.5_f64, 1) - 0.4_f64).abs() < 1e-9);
+        assert!(recover_fatigue(0.5_f64, 2) < recover_fatigue(0.5_f64, 1));
+        assert_eq!(recover_fatigue(0_f64, 10), 0_f64);
+    }
+
+    #[test]
+    fn test_tired_players_play_below_ratings_and_rotate_out() {
+        let attributes: PlayerAttributes = PlayerAttributes::from_general(5, 5, 5, 5, 5, 5);
+        let starter: Player = Player {
+            fatigue: 1_f64,
+            ..Player::from_attributes("Starter", Position::DefensiveTackle, attributes.clone())
+        };
+        let backup: Player = Player::from_attributes("Backup", Position::DefensiveTackle, attributes);
+        let rating: f64 = backup.get_rating(Attribute::Tackling);
+        assert!((starter.get_rating(Attribute::Tackling) - rating * (1_f64 - FATIGUE_PENALTY)).abs() < 1e-9);
+
+        // A tired starter rests while a fresh backup can take their place,
+        // but still plays if the team needs both of them
+        let depth_chart: Vec<Player> = vec![starter, backup];
+        assert_eq!(select_players(&[&depth_chart], &[1], &[&[]])[0][0].name, "Backup");
+        assert_eq!(select_players(&[&depth_chart], &[2], &[&[]])[0].len(), 2);
+
+        // Quarterbacks never rotate out however tired they are
+        assert!(!is_rotating_out(Position::Quarterback, 1_f64));
+        assert!(is_rotating_out(Position::DefensiveTackle, 0.35_f64));
+    }
+}
@@ -1,31 +1,68 @@
+use crate::team::attributes::Attribute;
 use crate::team::player::Player;
+use crate::team::position::Position;
 use crate::team::team::Team;
+use crate::team::personnel::{OffensivePersonnel, OffensiveFormation, DefensivePersonnel};
+use crate::team::playcall::{OffensivePlayCall, OffensivePlay, DefensivePlayCall, FourthDownDecision, PlayCaller, PlayDepth};
+use crate::team::units::offense::offense::OffensiveLineup;
+use crate::team::units::defense::defense::DefensiveLineup;
+use crate::game::context::clock::GameQuarter;
 use crate::game::context::possession::GameDown;
 use crate::game::context::context::GameContext;
 use crate::game::log::GameLog;
 use crate::game::coinflip::{CoinFlip, CoinFlipDecision};
-use crate::game::play::Play;
+use crate::game::overtime::{Overtime, OvertimeRules};
+use crate::game::play::{Play, PlayType, PlayResult};
+use crate::game::drive::Drive;
+use crate::game::environment::GameEnvironment;
+use crate::game::epa::ExpectedPointsModel;
+use crate::game::fatigue::{SnapCount, get_play_intensity};
+use crate::game::homefield::HomeFieldAdvantage;
+use crate::game::injury::{Injury, PlayerInjury, get_injury_probability, is_contact};
+use crate::game::winprob::WinProbabilityModel;
 
 use std::fmt;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 use rand::distributions::{Distribution, Bernoulli};
+use rand_chacha::ChaCha8Rng;
 use statrs::distribution::Beta;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # Game struct
 ///
-/// The `Game` struct represents a football game
+/// The `Game` struct represents a football game, simulated play by play.
+/// The game owns copies of its teams, on which it tracks the injuries and
+/// fatigue of their players as the game goes on.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Game {
     pub home_team: Team,
     pub away_team: Team,
     pub context: GameContext,
     pub log: GameLog,
     pub coin_flip: CoinFlip,
-    pub plays: Vec<Play>
+    pub overtime: Overtime,
+    pub plays: Vec<Play>,
+    pub drives: Vec<Drive>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub snap_counts: Vec<SnapCount>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub environment: GameEnvironment,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub home_field: HomeFieldAdvantage,
+    pub win_probability_model: WinProbabilityModel,
+    pub expected_points_model: ExpectedPointsModel,
+    pub seed: Option<u64>,
+    rng: Option<ChaCha8Rng>
 }
 
 impl Game {
-    /// Initialize a new game given a home and away team
-    pub fn new(home_team: Team, away_team: Team) -> Game {
+    /// Initialize a new game given a home and away team, whose players
+    /// start the game fully rested
+    pub fn new(mut home_team: Team, mut away_team: Team) -> Game {
+        home_team.reset_fatigue();
+        away_team.reset_fatigue();
+
         // Initialize the Game struct
         Game {
             home_team: home_team,
@@ -33,10 +70,85 @@ impl Game {
             context: GameContext::new(),
             log: GameLog::new(),
             coin_flip: CoinFlip::new(),
-            plays: Vec::new()
+            overtime: Overtime::default(),
+            plays: Vec::new(),
+            drives: Vec::new(),
+            snap_counts: Vec::new(),
+            environment: GameEnvironment::default(),
+            home_field: HomeFieldAdvantage::default(),
+            win_probability_model: WinProbabilityModel::default(),
+            expected_points_model: ExpectedPointsModel::default(),
+            seed: None,
+            rng: None
         }
     }
 
+    /// Initialize a new seeded game given a home and away team.  For a
+    /// given seed and crate version, simulating the game with
+    /// `simulate_seeded` always produces the same play-by-play.
+    pub fn from_seed(home_team: Team, away_team: Team, seed: u64) -> Game {
+        let mut game: Game = Game::new(home_team, away_team);
+        game.seed = Some(seed);
+        game.rng = Some(ChaCha8Rng::seed_from_u64(seed));
+        game
+    }
+
+    /// Re-simulate a seeded game from its seed, returning a new game with
+    /// the same teams, conditions and models.  Simulation only modifies the
+    /// teams by marking the players injured during the game, who are healed
+    /// again before the replay, so the replayed game matches the original
+    /// play for play.
+    pub fn replay(&self) -> Result<Game, GameError> {
+        let seed: u64 = match self.seed {
+            Some(seed) => seed,
+            None => return Err(GameError::SeedError(String::from("Cannot replay game: Game was not seeded")))
+        };
+        let mut home_team: Team = self.home_team.clone();
+        let mut away_team: Team = self.away_team.clone();
+        for player_injury in self.get_injuries() {
+            home_team.set_injury(&player_injury.player, player_injury.position, None);
+            away_team.set_injury(&player_injury.player, player_injury.position, None);
+        }
+        let mut game: Game = Game::from_seed(home_team, away_team, seed);
+        game.set_overtime_rules(self.overtime.rules);
+        game.set_environment(self.environment);
+        game.set_home_field(self.home_field);
+        game.win_probability_model = self.win_probability_model.clone();
+        game.expected_points_model = self.expected_points_model.clone();
+        game.simulate_seeded()?;
+        Ok(game)
+    }
+
+    /// Get the injuries suffered during the game, in the order they happened
+    pub fn get_injuries(&self) -> Vec<&PlayerInjury> {
+        self.plays.iter().filter_map(|play| play.injury.as_ref()).collect()
+    }
+
+    /// Set the rules for overtime should the game be tied at the end of
+    /// regulation.  Games are played without overtime unless they are set.
+    pub fn set_overtime_rules(&mut self, rules: OvertimeRules) {
+        self.overtime = Overtime::new(rules);
+    }
+
+    /// Set the weather, field and altitude the game is played in, which
+    /// affect the kicking and passing games and how often the ball is
+    /// fumbled
+    pub fn set_environment(&mut self, environment: GameEnvironment) {
+        self.environment = environment;
+    }
+
+    /// Set the home team's advantage from playing at home: a small rating
+    /// boost, and crowd noise which the away offense struggles with
+    pub fn set_home_field(&mut self, home_field: HomeFieldAdvantage) {
+        self.home_field = home_field;
+    }
+
+    /// Set whether the game is played at a neutral site, where neither
+    /// team has a home field advantage
+    pub fn set_neutral_site(&mut self, neutral_site: bool) {
+        self.home_field.neutral_site = neutral_site;
+    }
+
     /// Log an event to the game log
     pub fn log(&mut self, message: &str) {
         self.log.log(&self.context, message);
@@ -76,7 +188,11 @@ impl Game {
                     }
                 }
             }
-        }
+        };
+
+        // Start the game now that the coin flip is settled, so that a game
+        // resumed before the opening kickoff does not flip again
+        self.context.clock.start_game();
     }
 
     /// Get the team currently with possession of the ball
@@ -95,13 +211,662 @@ impl Game {
         }
     }
 
+    /// Initialize a record of the next play from the current situation
+    fn new_play(&self, play_type: PlayType) -> Play {
+        Play::new(
+            self.plays.len(),
+            &self.context,
+            &self.get_team_in_possession().abbreviation,
+            &self.get_team_defending().abbreviation,
+            play_type
+        )
+    }
+
+    /// Get the home team's current win probability
+    pub fn get_home_win_probability(&self) -> f64 {
+        self.win_probability_model.get_home_win_probability(
+            &self.context,
+            self.home_team.get_overall(),
+            self.away_team.get_overall()
+        )
+    }
+
+    /// Calculate the expected points added by the latest play if it was
+    /// from scrimmage.  Points scored on the play count in full, and no
+    /// expected points carry over once the half is over.
+    fn get_expected_points_added(&self, half_over: bool) -> Option<f64> {
+        let play: &Play = self.plays.last()?;
+        if !play.is_from_scrimmage() {
+            return None;
+        }
+        let expected_points_before: f64 = self.expected_points_model.get_expected_points(play.down, play.distance, play.yard_line);
+
+        // Get the net points the offense scored on the play
+        let home_points: f64 = self.context.score.home_team_score as f64 - play.home_score as f64;
+        let away_points: f64 = self.context.score.away_team_score as f64 - play.away_score as f64;
+        let offense_is_home: bool = play.offense == self.home_team.abbreviation;
+        let mut expected_points_after: f64 = match offense_is_home {
+            true => home_points - away_points,
+            false => away_points - home_points
+        };
+
+        // Add the expected points of the next possession from the offense's
+        // perspective
+        if !half_over {
+            let possession_expected_points: f64 = self.expected_points_model.get_possession_expected_points(&self.context.possession);
+            if self.get_team_in_possession().abbreviation == play.offense {
+                expected_points_after += possession_expected_points;
+            } else {
+                expected_points_after -= possession_expected_points;
+            }
+        }
+        Some(expected_points_after - expected_points_before)
+    }
+
+    /// Add points to the score of the team currently with possession
+    fn score_points(&mut self, points: usize) {
+        match self.context.possession.possession_away {
+            true => { self.context.score.away_team_score += points; },
+            false => { self.context.score.home_team_score += points; }
+        };
+    }
+
+    /// Set up a kickoff from the kicking team's own 35 yard line
+    fn set_up_kickoff(&mut self) {
+        self.context.possession.down = GameDown::Kickoff;
+        self.context.possession.set_line_of_possession(35, true);
+    }
+
+    /// End the current quarter.  At the end of the first half the team
+    /// which received the opening kickoff kicks off to start the second.
+    /// Every player starts the second half fully rested.  A tie at the end
+    /// of regulation or of an overtime period goes to another period of
+    /// overtime if the overtime rules allow it.
+    fn end_quarter(&mut self, mut rng: &mut impl Rng) {
+        let end_of_half: bool = self.context.clock.quarter == GameQuarter::Second;
+        let end_of_game: bool = self.context.clock.quarter == GameQuarter::Fourth || self.context.clock.quarter == GameQuarter::Overtime;
+        let next_period: bool = end_of_game
+            && self.context.score.home_team_score == self.context.score.away_team_score
+            && self.overtime.rules.has_next_period(self.overtime.num_periods);
+        if end_of_half || (end_of_game && !self.overtime.has_started()) {
+            if let Some(drive) = self.drives.last_mut() {
+                drive.end_half();
+            }
+        }
+        self.log(&format!("End of the {} quarter", self.context.clock.format_quarter()));
+        self.context.clock.end_quarter();
+        if end_of_half {
+            self.home_team.reset_fatigue();
+            self.away_team.reset_fatigue();
+            self.context.possession.possession_away = !self.coin_flip.away_team_kicks_opening();
+            self.context.possession.direction_left = false;
+            self.set_up_kickoff();
+        }
+        if next_period {
+            self.start_overtime_period(&mut rng);
+        }
+        if self.context.clock.is_game_over() {
+            self.end_game();
+        }
+    }
+
+    /// Start a period of overtime.  The first period opens with a coin
+    /// flip, and the winner's choice kicks off.
+    fn start_overtime_period(&mut self, mut rng: &mut impl Rng) {
+        self.context.clock.start_overtime(self.overtime.rules.get_period_seconds());
+        if !self.overtime.has_started() {
+            let mut coin_flip: CoinFlip = CoinFlip::new();
+            coin_flip.simulate(&self.away_team, &self.home_team, &mut rng);
+            let away_team_kicks: bool = coin_flip.away_team_kicks_opening();
+            self.overtime.coin_flip = Some(coin_flip);
+            self.overtime.first_drive = self.drives.len();
+            self.context.possession.possession_away = away_team_kicks;
+            self.context.possession.direction_left = false;
+            self.set_up_kickoff();
+            let receiving_team: &str = match away_team_kicks {
+                true => &self.home_team.abbreviation,
+                false => &self.away_team.abbreviation
+            };
+            self.log(&format!("Overtime: {} will receive the kickoff", receiving_team));
+        }
+        self.overtime.num_periods += 1;
+    }
+
+    /// Check whether overtime has been decided.  The kicking team wins as
+    /// soon as it leads, and otherwise the game ends once the kicking team
+    /// has had a possession and the score is no longer tied.  A team
+    /// scoring a touchdown to draw level still attempts its PAT.
+    fn is_overtime_decided(&self) -> bool {
+        let away_team_kicks: bool = match self.overtime.away_team_kicks() {
+            Some(away_team_kicks) => away_team_kicks,
+            None => return false
+        };
+        let home_score: usize = self.context.score.home_team_score;
+        let away_score: usize = self.context.score.away_team_score;
+        if home_score == away_score {
+            return false;
+        }
+        let kicking_team_leads: bool = (away_score > home_score) == away_team_kicks;
+        let kicking_team: &str = match away_team_kicks {
+            true => &self.away_team.abbreviation,
+            false => &self.home_team.abbreviation
+        };
+        let kicking_team_possessed: bool = self.drives.iter()
+            .skip(self.overtime.first_drive)
+            .any(|drive| drive.offense == kicking_team && drive.is_over());
+        let possession_leads: bool = (away_score > home_score) == self.context.possession.possession_away;
+        let pat_owed: bool = self.context.possession.down == GameDown::PointAfter && !possession_leads;
+        kicking_team_leads || (kicking_team_possessed && !pat_owed)
+    }
+
+    /// End the game, logging the final score
+    fn end_game(&mut self) {
+        if let Some(drive) = self.drives.last_mut() {
+            drive.end_half();
+        }
+        self.context.clock.quarter = GameQuarter::Postgame;
+        self.log(&format!("Final: {} {} - {} {}", self.home_team.abbreviation, self.context.score.home_team_score, self.away_team.abbreviation, self.context.score.away_team_score));
+    }
+
+    /// Simulate whether a ball carrier fumbles when tackled and the defense
+    /// recovers, which is less likely for carriers who protect the ball and
+    /// likelier in the cold and wet
+    fn simulate_fumble(carrier: &Player, tackler: &Player, environment: &GameEnvironment, mut rng: &mut impl Rng) -> bool {
+        let mut p_fumble: f64 = 0.015_f64 - 0.002_f64 * (carrier.get_rating(Attribute::Carrying) - 5_f64) + 0.001_f64 * (tackler.get_rating(Attribute::Strength) - 5_f64);
+        p_fumble += environment.get_fumble_modifier();
+        let dst_fumble: Bernoulli = Bernoulli::new(p_fumble.clamp(0.002_f64, 0.04_f64)).unwrap();
+        let dst_recovered: Bernoulli = Bernoulli::new(0.5_f64).unwrap();
+        dst_fumble.sample(&mut rng) && dst_recovered.sample(&mut rng)
+    }
+
+    /// Simulate a run play given the lineups on the field
+    fn simulate_run(&self, offense: &OffensiveLineup, defense: &DefensiveLineup, inside: bool, def_call: &dyn DefensivePlayCall, play: &mut Play, mut rng: &mut impl Rng) -> Result<(isize, usize, PlayResult, String), GameError> {
+        let yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
+
+        // Get the ball carrier's name and running ability, outside runs
+        // lean on speed while inside runs lean on carrying
+        let carrier: &Player = offense.get_ball_carrier(inside, &mut rng).ok_or_else(|| self.get_lineup_error("ball carrier"))?;
+        play.ball_carrier = Some(carrier.name.clone());
+        let carrier_running: f64 = if inside {
+            carrier.get_rating_average(Attribute::Carrying, Attribute::Agility)
+        } else {
+            carrier.get_rating_average(Attribute::Speed, Attribute::Agility)
+        };
+
+        // Compare the blockers to the box, including the numbers advantage
+        let num_in_box: usize = defense.get_num_in_box(def_call.is_blitz(), def_call.is_all_out_blitz());
+        let numbers_diff: f64 = offense.get_num_run_blockers() as f64 - num_in_box as f64;
+        let home_edge: f64 = self.home_field.get_rating_edge(!self.context.possession.possession_away);
+        let blocking_diff: f64 = offense.get_run_blocking() - defense.get_run_defense() + (0.5_f64 * numbers_diff) + home_edge;
+
+        // Generate the yards gained at the line of scrimmage
+        let line_seed: f64 = (0.5_f64 + 0.05_f64 * blocking_diff).clamp(0.1_f64, 0.9_f64);
+        let spread: f64 = if inside { 12_f64 } else { 16_f64 };
+        let dst_line_yards: Beta = Beta::new(1.5_f64 + 3_f64 * line_seed, 4.5_f64 - 3_f64 * line_seed).unwrap();
+        let mut yards_gained: isize = ((dst_line_yards.sample(&mut rng) * spread) - (spread / 3_f64)).round() as isize;
+
+        // Determine whether the ball carrier breaks into the second level,
+        // which is likelier against a blitz or on outside runs
+        let tackler: &Player = defense.get_random_tackler(&mut rng).ok_or_else(|| self.get_lineup_error("defender"))?;
+        let mut p_break: f64 = 0.06_f64 + 0.01_f64 * (carrier_running - tackler.get_rating(Attribute::Tackling) + home_edge);
+        if !inside {
+            p_break += 0.03_f64;
+        }
+        if def_call.is_blitz() {
+            p_break += 0.03_f64;
+        }
+        let dst_break: Bernoulli = Bernoulli::new(p_break.clamp(0.01_f64, 0.4_f64)).unwrap();
+        if dst_break.sample(&mut rng) && yards_gained > 0 {
+            let dst_break_yards: Beta = Beta::new(1_f64, 4_f64).unwrap();
+            yards_gained += (dst_break_yards.sample(&mut rng) * 60_f64) as isize;
+        }
+        yards_gained = yards_gained.min(yards_to_endzone);
+
+        // Format the log message
+        let direction: &str = if inside { "inside" } else { "outside" };
+        let mut message: String = format!("{} runs {} for {} yards", carrier.name, direction, yards_gained);
+        if yards_gained == yards_to_endzone {
+            message += " for a touchdown!";
+            return Ok((yards_gained, 36_usize, PlayResult::Gain, message));
+        }
+        message += &format!(", brought down by {}", tackler.name);
+        play.defender = Some(tackler.name.clone());
+
+        // Determine whether the ball carrier fumbles and the defense recovers
+        if Game::simulate_fumble(carrier, tackler, &self.environment, &mut rng) {
+            message += &format!(", fumbled and recovered by {}", tackler.name);
+            return Ok((yards_gained, 36_usize, PlayResult::Fumble, message));
+        }
+        Ok((yards_gained, 36_usize, PlayResult::Gain, message))
+    }
+
+    /// Simulate a pass play given the lineups on the field
+    fn simulate_pass(&self, offense: &OffensiveLineup, defense: &DefensiveLineup, off_call: &dyn OffensivePlayCall, def_call: &dyn DefensivePlayCall, play: &mut Play, mut rng: &mut impl Rng) -> Result<(isize, usize, PlayResult, String), GameError> {
+        let yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
+
+        // Get the quarterback's name and throwing ability
+        let quarterback: &Player = offense.get_quarterback().ok_or_else(|| self.get_lineup_error("quarterback"))?;
+        play.passer = Some(quarterback.name.clone());
+        let blitz: bool = def_call.is_blitz();
+        let all_out_blitz: bool = def_call.is_all_out_blitz();
+
+        // Determine whether the quarterback is sacked, an away offense
+        // struggling to hear the snap count is sacked more often
+        let offense_home: bool = !self.context.possession.possession_away;
+        let home_edge: f64 = self.home_field.get_rating_edge(offense_home);
+        let communication_penalty: f64 = self.home_field.get_communication_penalty(offense_home);
+        let rush_diff: f64 = defense.get_pass_rush(blitz, all_out_blitz) - offense.get_pass_blocking(blitz) - home_edge;
+        let mut p_sack: f64 = 0.06_f64 + 0.012_f64 * rush_diff + communication_penalty;
+        if all_out_blitz {
+            p_sack += 0.05_f64;
+        } else if blitz {
+            p_sack += 0.02_f64;
+        }
+        let dst_sack: Bernoulli = Bernoulli::new(p_sack.clamp(0.01_f64, 0.3_f64)).unwrap();
+        if dst_sack.sample(&mut rng) {
+            let sacker: &Player = defense.get_random_tackler(&mut rng).ok_or_else(|| self.get_lineup_error("defender"))?;
+            play.defender = Some(sacker.name.clone());
+            let dst_sack_yards: Beta = Beta::new(2_f64, 3_f64).unwrap();
+            let sack_yards: isize = (dst_sack_yards.sample(&mut rng) * 12_f64) as isize + 1_isize;
+            let message: String = format!("{} is sacked by {} for a loss of {} yards", quarterback.name, sacker.name, sack_yards);
+            return Ok((-sack_yards, 36_usize, PlayResult::Sack, message));
+        }
+
+        // Choose a target and the defender covering them
+        let (target, target_group) = offense.get_random_target(&mut rng).ok_or_else(|| self.get_lineup_error("pass target"))?;
+        play.ball_carrier = Some(target.name.clone());
+        let defender: &Player = defense.get_coverage_defender(target_group, &mut rng).ok_or_else(|| self.get_lineup_error("coverage defender"))?;
+        play.defender = Some(defender.name.clone());
+        let defender_coverage: f64 = defender.get_rating_average(Attribute::Coverage, Attribute::Speed);
+        let target_separation: f64 = target.get_rating_average(Attribute::Catching, Attribute::RouteRunning);
+
+        // Determine the completion probability from the depth and matchup
+        let pass_depth: PlayDepth = off_call.get_pass_depth();
+        let (mut p_complete, depth_name) = match pass_depth {
+            PlayDepth::Short => (0.74_f64, "short"),
+            PlayDepth::Medium => (0.58_f64, "medium"),
+            PlayDepth::Long => (0.38_f64, "deep")
+        };
+        p_complete += 0.025_f64 * (quarterback.get_rating(Attribute::Accuracy) - 5_f64);
+        p_complete += 0.02_f64 * (target_separation - defender_coverage + home_edge);
+        p_complete -= communication_penalty;
+        if pass_depth == PlayDepth::Long {
+            p_complete += 0.015_f64 * (quarterback.get_rating(Attribute::ArmStrength) - 5_f64);
+        }
+        p_complete += self.environment.get_pass_accuracy_modifier(pass_depth == PlayDepth::Long);
+
+        // Adjust for the coverage call, zones covering the thrown depth are
+        // harder to complete against, and blitzes leave fewer in coverage
+        if def_call.is_zone_coverage() && !blitz {
+            if def_call.get_zone_depth() == pass_depth {
+                p_complete -= 0.08_f64;
+            } else {
+                p_complete += 0.04_f64;
+            }
+        }
+        if all_out_blitz {
+            p_complete += 0.08_f64;
+        } else if blitz {
+            p_complete += 0.04_f64;
+        }
+
+        // Adjust for the personnel, extra defensive backs tighten coverage
+        // and extra receivers stretch it
+        p_complete -= 0.02_f64 * (defense.get_num_defensive_backs() as f64 - 4_f64);
+        p_complete += 0.015_f64 * (offense.wide_receivers.len() as f64 - 2_f64);
+        let dst_complete: Bernoulli = Bernoulli::new(p_complete.clamp(0.1_f64, 0.9_f64)).unwrap();
+        if !dst_complete.sample(&mut rng) {
+            // Determine whether the incompletion is intercepted, deep passes
+            // and passes into tight coverage are picked off more often
+            let mut p_intercept: f64 = 0.08_f64 + 0.015_f64 * (defender_coverage - quarterback.get_rating(Attribute::Awareness));
+            let intercept_depth: isize = match pass_depth {
+                PlayDepth::Short => 5_isize,
+                PlayDepth::Medium => {
+                    p_intercept += 0.02_f64;
+                    14_isize
+                },
+                PlayDepth::Long => {
+                    p_intercept += 0.05_f64;
+                    28_isize
+                }
+            };
+            let dst_intercept: Bernoulli = Bernoulli::new(p_intercept.clamp(0.02_f64, 0.25_f64)).unwrap();
+            if dst_intercept.sample(&mut rng) {
+                let intercept_yards: isize = intercept_depth.min(yards_to_endzone - 1);
+                let message: String = format!("{} pass {} to {} is intercepted by {}", quarterback.name, depth_name, target.name, defender.name);
+                return Ok((intercept_yards, 6_usize, PlayResult::Interception, message));
+            }
+            let message: String = format!("{} pass {} to {} is incomplete, defended by {}", quarterback.name, depth_name, target.name, defender.name);
+            return Ok((0_isize, 6_usize, PlayResult::Incomplete, message));
+        }
+
+        // Generate the air yards and the yards after the catch
+        let (dst_air_yards, air_yards_min, air_yards_range) = match pass_depth {
+            PlayDepth::Short => (Beta::new(2_f64, 3_f64).unwrap(), -2_f64, 10_f64),
+            PlayDepth::Medium => (Beta::new(2_f64, 2_f64).unwrap(), 8_f64, 10_f64),
+            PlayDepth::Long => (Beta::new(1.5_f64, 3_f64).unwrap(), 18_f64, 32_f64)
+        };
+        let air_yards: f64 = air_yards_min + dst_air_yards.sample(&mut rng) * air_yards_range;
+        let dst_yac: Beta = Beta::new(1_f64 + target.get_rating_average(Attribute::Speed, Attribute::Agility) / 10_f64, 6_f64).unwrap();
+        let yac: f64 = dst_yac.sample(&mut rng) * 25_f64;
+        let yards_gained: isize = ((air_yards + yac).round() as isize).min(yards_to_endzone);
+
+        // Format the log message
+        let mut message: String = format!("{} pass {} to {} complete for {} yards", quarterback.name, depth_name, target.name, yards_gained);
+        if yards_gained == yards_to_endzone {
+            message += " for a touchdown!";
+            play.defender = None;
+            return Ok((yards_gained, 32_usize, PlayResult::Gain, message));
+        }
+        message += &format!(", brought down by {}", defender.name);
+
+        // Determine whether the receiver fumbles and the defense recovers
+        if Game::simulate_fumble(target, defender, &self.environment, &mut rng) {
+            message += &format!(", fumbled and recovered by {}", defender.name);
+            return Ok((yards_gained, 32_usize, PlayResult::Fumble, message));
+        }
+        Ok((yards_gained, 32_usize, PlayResult::Gain, message))
+    }
+
+    /// Get the error for a lineup missing a player the play needs
+    fn get_lineup_error(&self, role: &str) -> GameError {
+        GameError::LineupError(format!(
+            "Cannot simulate play: {} vs {} has no {} on the field",
+            self.get_team_in_possession().abbreviation,
+            self.get_team_defending().abbreviation,
+            role
+        ))
+    }
+
     /// Simulate an offensive play
     fn simulate_play(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
+        let is_home: bool = !self.context.possession.possession_away;
+
+        // The away offense may false start over the crowd noise
+        let p_false_start: f64 = self.home_field.get_false_start_probability(is_home);
+        let dst_false_start: Bernoulli = Bernoulli::new(p_false_start).unwrap();
+        if dst_false_start.sample(&mut rng) {
+            self.simulate_false_start();
+            return Ok(());
+        }
+
+        // On fourth down the coach first decides whether to kick
+        if self.context.possession.down == GameDown::Fourth {
+            let decision: FourthDownDecision = self.get_team_in_possession().coach.generate_fourth_down_decision(&self.context, is_home, &mut rng);
+            match decision {
+                FourthDownDecision::Punt => return self.simulate_punt(&mut rng),
+                FourthDownDecision::FieldGoal => return self.simulate_field_goal(&mut rng),
+                FourthDownDecision::GoForIt => {}
+            }
+        }
+        let yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
+        let yards_to_first: isize = self.context.possession.get_yards_to_first();
+        let (mut play, yards_gained, expected_seconds, play_result, message, off_players, def_players) = {
+            let offense: &Team = self.get_team_in_possession();
+            let defense: &Team = self.get_team_defending();
+
+            // Each coach chooses personnel, the defense responding to the offense
+            let off_personnel: OffensivePersonnel = offense.coach.generate_offensive_personnel(&self.context, is_home, &mut rng);
+            let def_personnel: DefensivePersonnel = defense.coach.generate_defensive_personnel(&self.context, !is_home, off_personnel, &mut rng);
+
+            // The offense lines up in a formation, then each coach calls a
+            // play for their personnel
+            let off_formation: OffensiveFormation = offense.coach.generate_offensive_formation(&self.context, is_home, off_personnel, &mut rng);
+            let off_call: Box<dyn OffensivePlayCall> = offense.coach.generate_offensive_playcall(&self.context, is_home, off_personnel, off_formation, &mut rng);
+            let def_call: Box<dyn DefensivePlayCall> = defense.coach.generate_defensive_playcall(&self.context, !is_home, def_personnel, &mut rng);
+
+            // Put the players on the field and simulate the play
+            let off_lineup: OffensiveLineup = offense.offense.get_lineup(off_personnel, off_formation);
+            let def_lineup: DefensiveLineup = defense.defense.get_lineup(def_personnel);
+            let mut play: Play = match off_call.is_run() {
+                true => self.new_play(PlayType::Run),
+                false => self.new_play(PlayType::Pass)
+            };
+            play.play_call = Some(OffensivePlay::new(off_call.is_run(), off_call.is_inside_run(), off_call.get_pass_depth()));
+            let (yards_gained, expected_seconds, play_result, play_message) = if off_call.is_run() {
+                self.simulate_run(&off_lineup, &def_lineup, off_call.is_inside_run(), def_call.as_ref(), &mut play, &mut rng)?
+            } else {
+                self.simulate_pass(&off_lineup, &def_lineup, off_call.as_ref(), def_call.as_ref(), &mut play, &mut rng)?
+            };
+            let message: String = format!(
+                "{} {} {} vs {}: {}",
+                offense.abbreviation,
+                off_personnel.format_personnel(),
+                off_formation.format_formation(),
+                def_personnel.format_personnel(),
+                play_message
+            );
+            let off_players: Vec<(String, Position)> = off_lineup.get_players().iter().map(|player| (player.name.clone(), player.position)).collect();
+            let def_players: Vec<(String, Position)> = def_lineup.get_players().iter().map(|player| (player.name.clone(), player.position)).collect();
+            (play, yards_gained, expected_seconds, play_result, message, off_players, def_players)
+        };
+
+        // Determine the result of the play from the yards gained, flagging
+        // a fourth down play short of the line to gain as a turnover on
+        // downs without discarding its result
+        let is_turnover: bool = play_result == PlayResult::Interception || play_result == PlayResult::Fumble;
+        let play_result: PlayResult = if is_turnover {
+            play_result
+        } else if yards_gained >= yards_to_endzone {
+            PlayResult::Touchdown
+        } else if yards_gained >= yards_to_first {
+            PlayResult::FirstDown
+        } else {
+            play_result
+        };
+        play.turnover_on_downs = !is_turnover && yards_gained < yards_to_first && self.context.possession.down == GameDown::Fourth;
+
+        // Log and record the play, then adjust the game context accordingly
+        self.log(&message);
+        let play_yards: isize = match play_result {
+            PlayResult::Interception => 0_isize,
+            _ => yards_gained
+        };
+        play.complete(play_yards, play_result, &message);
+        let intensity: f64 = get_play_intensity(play.play_type, play_result, play_yards);
+        let (offense, defense) = (play.offense.clone(), play.defense.clone());
+        self.plays.push(play);
+        self.add_snaps(&offense, &off_players, intensity);
+        self.add_snaps(&defense, &def_players, intensity);
+        self.context.clock.increment_game_clock(expected_seconds, &mut rng);
+        if is_turnover {
+            // The defense takes over at the spot, scoring if the ball was
+            // lost in the offense's own endzone
+            self.context.possession.move_line_of_possession(yards_gained);
+            self.context.possession.flip_possession();
+            self.context.possession.start_series();
+            if self.context.possession.is_touchdown() {
+                self.context.possession.down = GameDown::PointAfter;
+                self.score_points(6);
+            }
+            return Ok(());
+        }
+        self.context.possession.increment(yards_gained);
+        if self.context.possession.down == GameDown::PointAfter {
+            self.score_points(6);
+        }
+        Ok(())
+    }
+
+    /// Simulate a false start, moving the offense back five yards, or half
+    /// the distance to their own goal line, and replaying the down
+    fn simulate_false_start(&mut self) {
+        let mut play: Play = self.new_play(PlayType::Penalty);
+        let yards_to_goal: isize = 100_isize - self.context.possession.get_yards_to_endzone();
+        let penalty_yards: isize = 5_isize.min(yards_to_goal / 2_isize);
+        let message: String = format!("False start on {}, {} yard penalty", self.get_team_in_possession().abbreviation, penalty_yards);
+        self.log(&message);
+        play.complete(-penalty_yards, PlayResult::Penalty, &message);
+        self.plays.push(play);
+        self.context.possession.move_line_of_possession(-penalty_yards);
+    }
+
+    /// Count a snap for each of a team's players on the field for a play,
+    /// tiring them with its intensity while the rest of the team recovers.
+    /// Tired players play below their ratings, and rotate out for fresher
+    /// backups at positions which rotate.
+    fn add_snaps(&mut self, abbreviation: &str, players: &[(String, Position)], intensity: f64) {
+        let team: &mut Team = match abbreviation == self.home_team.abbreviation {
+            true => &mut self.home_team,
+            false => &mut self.away_team
+        };
+        team.update_fatigue(players, intensity);
+        for (name, position) in players.iter() {
+            match self.snap_counts.iter_mut().find(|snap_count| snap_count.player == *name && snap_count.position == *position && snap_count.team == abbreviation) {
+                Some(snap_count) => snap_count.snaps += 1,
+                None => self.snap_counts.push(SnapCount {
+                    player: name.clone(),
+                    team: String::from(abbreviation),
+                    position: *position,
+                    snaps: 1
+                })
+            }
+        }
+    }
+
+    /// Simulate a punt
+    fn simulate_punt(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
+        // Get the punter's name, kicking power, and kicking accuracy
+        let mut play: Play = self.new_play(PlayType::Punt);
+        let punter: &Player = self.get_team_in_possession().special_teams.get_punter();
+        play.kicker = Some(punter.name.clone());
+        let punter_power: f64 = punter.get_rating(Attribute::KickPower);
+        let punter_accuracy: f64 = punter.get_rating(Attribute::KickAccuracy);
+        let mut message: String = String::new();
+
+        // Generate the length of the punt
+        let yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
+        let dst_punt_length: Beta = Beta::new(1_f64 + punter_power / 2_f64, 3_f64).unwrap();
+        let distance_factor: f64 = self.environment.get_kick_distance_factor(self.context.possession.direction_left);
+        let punt_length: isize = ((30_f64 + dst_punt_length.sample(&mut rng) * 30_f64) * distance_factor).round() as isize;
+        message += &format!("{} punts {} yards", punter.name, punt_length.min(yards_to_endzone));
+
+        // Determine whether a touchback occurred
+        self.context.possession.flip_possession();
+        if punt_length >= yards_to_endzone {
+            message += " for a touchback";
+            self.context.possession.set_yards_to_endzone(80);
+            self.context.possession.start_series();
+            self.context.clock.increment_game_clock(6, &mut rng);
+            self.log(&message);
+            play.complete(0, PlayResult::Touchback, &message);
+            self.plays.push(play);
+            return Ok(());
+        }
+        self.context.possession.set_yards_to_endzone(100 - (yards_to_endzone - punt_length));
+
+        // Determine whether a fair catch occurred, more accurate punts with
+        // more hang time are fair caught more often
+        let returner: &Player = self.get_team_in_possession().special_teams.get_punt_returner();
+        play.ball_carrier = Some(returner.name.clone());
+        let p_fair_catch: f64 = (0.3_f64 + 0.04_f64 * (punter_accuracy - 5_f64)).clamp(0.05_f64, 0.7_f64);
+        let dst_fair_catch: Bernoulli = Bernoulli::new(p_fair_catch).unwrap();
+        if dst_fair_catch.sample(&mut rng) {
+            message += &format!(", {} calls for a fair catch", returner.name);
+            self.context.possession.start_series();
+            self.context.clock.increment_game_clock(8, &mut rng);
+            self.log(&message);
+            play.complete(0, PlayResult::FairCatch, &message);
+            self.plays.push(play);
+            return Ok(());
+        }
+
+        // Generate the return yards given the returner's running ability
+        let returner_running: f64 = returner.get_rating_average(Attribute::Speed, Attribute::Agility);
+        let return_yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
+        let dst_return: Beta = Beta::new(1_f64 + returner_running / 5_f64, 6_f64).unwrap();
+        let return_yards: isize = ((dst_return.sample(&mut rng) * 30_f64).round() as isize - 2_isize).min(return_yards_to_endzone);
+        message += &format!(", {} returns it {} yards", returner.name, return_yards);
+
+        // Adjust the game context accordingly
+        self.context.clock.increment_game_clock(10, &mut rng);
+        self.context.possession.move_line_of_possession(return_yards);
+        let punt_result: PlayResult = if self.context.possession.is_touchdown() {
+            message += " for a touchdown!";
+            self.context.possession.down = GameDown::PointAfter;
+            self.score_points(6);
+            PlayResult::Touchdown
+        } else {
+            self.context.possession.start_series();
+            PlayResult::Return
+        };
+        self.log(&message);
+        play.complete(return_yards, punt_result, &message);
+        self.plays.push(play);
+        Ok(())
+    }
+
+    /// Simulate a field goal attempt
+    fn simulate_field_goal(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
+        // Get the kicker's name, kicking power, and kicking accuracy
+        let mut play: Play = self.new_play(PlayType::FieldGoal);
+        let kicker: &Player = self.get_team_in_possession().special_teams.get_kicker();
+        play.kicker = Some(kicker.name.clone());
+        let kicker_power: f64 = kicker.get_rating(Attribute::KickPower);
+        let kicker_accuracy: f64 = kicker.get_rating(Attribute::KickAccuracy);
+        let kicker_name: String = kicker.name.clone();
+
+        // Determine whether the field goal is good, long kicks beyond the
+        // kicker's range rarely are.  The conditions stretch or shrink the
+        // kicker's range and can push the kick off target.
+        let yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
+        let kick_distance: isize = yards_to_endzone + 17;
+        let effective_distance: f64 = kick_distance as f64 / self.environment.get_kick_distance_factor(self.context.possession.direction_left);
+        let mut p_good: f64 = 0.99_f64 - 0.0003_f64 * (effective_distance - 18_f64).powi(2);
+        p_good += 0.02_f64 * (kicker_accuracy - 5_f64);
+        p_good += self.environment.get_kick_accuracy_modifier();
+        if effective_distance > 50_f64 + 0.8_f64 * kicker_power {
+            p_good *= 0.3_f64;
+        }
+        let dst_good: Bernoulli = Bernoulli::new(p_good.clamp(0.01_f64, 0.99_f64)).unwrap();
+        self.context.clock.increment_game_clock(5, &mut rng);
+        if dst_good.sample(&mut rng) {
+            let message: String = format!("{}'s {} yard field goal is good", kicker_name, kick_distance);
+            self.log(&message);
+            play.complete(0, PlayResult::Good, &message);
+            self.plays.push(play);
+            self.score_points(3);
+            self.set_up_kickoff();
+            return Ok(());
+        }
+
+        // On a miss the defense takes over at the spot of the kick
+        let message: String = format!("{}'s {} yard field goal is no good", kicker_name, kick_distance);
+        self.log(&message);
+        play.complete(0, PlayResult::NoGood, &message);
+        self.plays.push(play);
+        self.context.possession.flip_possession();
+        self.context.possession.set_yards_to_endzone((100 - (yards_to_endzone + 7)).min(80));
+        self.context.possession.start_series();
         Ok(())
     }
 
     /// Simulate a PAT
     fn simulate_pat(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
+        // Get the kicker's name and kicking accuracy
+        let kicker: &Player = self.get_team_in_possession().special_teams.get_kicker();
+        let kicker_kicking: f64 = kicker.get_rating(Attribute::KickAccuracy);
+        let kicker_name: String = kicker.name.clone();
+        let mut play: Play = self.new_play(PlayType::ExtraPoint);
+        play.kicker = Some(kicker_name.clone());
+
+        // Determine whether the extra point is good
+        let p_good: f64 = (0.9_f64 + 0.01_f64 * (kicker_kicking - 5_f64) + self.environment.get_kick_accuracy_modifier()).clamp(0.5_f64, 0.99_f64);
+        let dst_good: Bernoulli = Bernoulli::new(p_good).unwrap();
+        if dst_good.sample(&mut rng) {
+            let message: String = format!("{}'s extra point is good", kicker_name);
+            self.log(&message);
+            play.complete(0, PlayResult::Good, &message);
+            self.score_points(1);
+        } else {
+            let message: String = format!("{}'s extra point is no good", kicker_name);
+            self.log(&message);
+            play.complete(0, PlayResult::NoGood, &message);
+        }
+        self.plays.push(play);
+
+        // The scoring team kicks off next
+        self.set_up_kickoff();
         Ok(())
     }
 
@@ -110,15 +875,16 @@ impl Game {
         // Initialize a log message
         let mut message: String = String::new();
 
-        // Get the kicker's name and kicking ability
+        // Get the kicker's name and kicking power
         let kicker: &Player = self.get_team_in_possession().special_teams.get_kicker();
-        let kicker_kicking: usize = kicker.kicking;
+        let kicker_kicking: f64 = kicker.get_rating(Attribute::KickPower);
         let kicker_name: &str = &kicker.name;
 
         // Generate the length of the kicker's kickoff
-        let dst_kick_length: Beta = Beta::new(kicker_kicking as f64 / 10_f64, 5.0_f64).unwrap();
+        let dst_kick_length: Beta = Beta::new(kicker_kicking / 10_f64, 5.0_f64).unwrap();
         let kick_length_seed: f64 = dst_kick_length.sample(&mut rng);
-        let kick_length: usize = ((kick_length_seed * 50_f64) + 30_f64) as usize;
+        let distance_factor: f64 = self.environment.get_kick_distance_factor(self.context.possession.direction_left);
+        let kick_length: usize = (((kick_length_seed * 50_f64) + 30_f64) * distance_factor) as usize;
         message += &format!("{} kicks {} yards", kicker_name, kick_length);
 
         // Determine whether a touchback occurred
@@ -128,6 +894,7 @@ impl Game {
             // Adjust the game context accordingly
             self.context.possession.flip_possession();
             self.context.possession.set_line_of_possession(25, true);
+            self.context.possession.start_series();
             self.context.clock.increment_game_clock(5, &mut rng);
 
             // Log the play and return
@@ -143,8 +910,8 @@ impl Game {
         // Initialize a log message component
         let mut message: String = String::new();
 
-        // Get the kick returner's name
-        let returner: &Player = self.get_team_in_possession().special_teams.get_kick_returner();
+        // Get the kick returner's name, the receiving team is still defending
+        let returner: &Player = self.get_team_defending().special_teams.get_kick_returner();
         let returner_name: &str = &returner.name;
         
         // Determine whether a fair catch occurred
@@ -152,37 +919,41 @@ impl Game {
         if kick_length > 55 {
             let dst_fair_catch: Bernoulli = Bernoulli::new(0.5).unwrap();
             is_fair_catch = dst_fair_catch.sample(&mut rng);
-            message += &format!(" {} calls for a fair catch", returner_name)
+            if is_fair_catch {
+                message += &format!(", {} calls for a fair catch", returner_name);
+            }
         }
         return (is_fair_catch, message);
     }
 
-    fn simulate_kickoff_return(&mut self, mut rng: &mut impl Rng) -> (bool, isize, usize, String) {
+    fn simulate_kickoff_return(&mut self, play: &mut Play, mut rng: &mut impl Rng) -> (bool, isize, usize, String) {
         // Initialize a log message component
         let mut message: String = String::new();
 
         // Get the kick returner's name, catching, and running ability
         let returner: &Player = self.get_team_in_possession().special_teams.get_kick_returner();
-        let returner_running: usize = returner.running;
+        let returner_running: f64 = returner.get_rating_average(Attribute::Speed, Attribute::Agility);
         let returner_name: &str = &returner.name;
+        play.ball_carrier = Some(returner.name.clone());
 
         // Add initial log message value
         message += &format!(", {} fields the kick at the {}", returner_name, self.context.possession.get_yard_line());
 
         // Get the blocker and defender overall
-        let blocker_blocking: usize = self.get_team_in_possession().special_teams.get_kickoff_blockers_blocking();
-        let defender_blocking: usize = self.get_team_defending().special_teams.get_kickoff_defenders_blocking();
+        let blocker_blocking: f64 = self.get_team_in_possession().special_teams.get_kickoff_blockers_blocking();
+        let defender_blocking: f64 = self.get_team_defending().special_teams.get_kickoff_defenders_blocking();
 
         // Initialize a distribution for whether the blockers are penetrated
-        let blocking_diff: isize = blocker_blocking as isize - defender_blocking as isize;
-        let block_penetrated_seed: f64 = (blocking_diff + 9_isize) as f64 / 18_f64;
+        let blocking_diff: f64 = defender_blocking - blocker_blocking;
+        let block_penetrated_seed: f64 = ((blocking_diff + 9_f64) / 18_f64).clamp(0.05_f64, 0.95_f64);
         let dst_block_penetrated: Bernoulli = Bernoulli::new(block_penetrated_seed).unwrap();
 
         // Loop until the player is tackled or scores
+        let yards_to_endzone: isize = self.context.possession.get_yards_to_endzone();
         let mut player_is_tackled: bool = false;
         let mut total_yards_gained: isize = 0_isize;
         let mut iterations: usize = 0_usize;
-        while !player_is_tackled {
+        while !player_is_tackled && total_yards_gained < yards_to_endzone {
             // Determine whether the block was penetrated
             let block_penetrated: bool = dst_block_penetrated.sample(&mut rng);
 
@@ -190,47 +961,51 @@ impl Game {
             if block_penetrated {
                 // Get a random defender who penetrated the block
                 let blocker: &Player = self.get_team_defending().special_teams.get_random_kickoff_defender(&mut rng);
-                let blocker_running: usize = blocker.running;
-                let blocker_tackling: usize = blocker.tackling;
+                let blocker_running: f64 = blocker.get_rating(Attribute::Speed);
+                let blocker_tackling: f64 = blocker.get_rating(Attribute::Tackling);
                 let blocker_name: &str = &blocker.name;
 
                 // Get the yards gained or lost while the defender penetrated the block
-                let running_diff: isize = returner_running as isize - blocker_running as isize;
-                let yards_gained_seed: f64 = (running_diff + 9_isize) as f64 / 18_f64;
+                let running_diff: f64 = returner_running - blocker_running;
+                let yards_gained_seed: f64 = ((running_diff + 9_f64) / 18_f64).max(0.05_f64);
                 let dst_yards_gained: Beta = Beta::new(yards_gained_seed, 5.0_f64).unwrap();
                 let yards_gained: isize = ((dst_yards_gained.sample(&mut rng) * 10_f64) - 2_f64) as isize;
                 total_yards_gained += yards_gained;
 
                 // Determine whether the tackle was made or not
-                let running_tackling_diff: isize = returner_running as isize - blocker_tackling as isize;
-                let tackle_made_seed: f64 = (running_tackling_diff + 9_isize) as f64 / 18_f64;
+                let running_tackling_diff: f64 = blocker_tackling - returner_running;
+                let tackle_made_seed: f64 = ((running_tackling_diff + 9_f64) / 18_f64).clamp(0.05_f64, 0.95_f64);
                 let dst_tackle_made: Bernoulli = Bernoulli::new(tackle_made_seed).unwrap();
                 let tackle_made: bool = dst_tackle_made.sample(&mut rng);
                 if tackle_made {
                     player_is_tackled = true;
                     message += &format!(", is brought down by {}", blocker_name);
+                    play.defender = Some(blocker.name.clone());
                 } else {
                     message += &format!(", breaks {}'s tackle", blocker_name);
                 }
             } else {
                 // Get the yards gained while the returner ran freely
-                let dst_yards_gained: Beta = Beta::new(returner_running as f64 / 10_f64, 5.0_f64).unwrap();
+                let dst_yards_gained: Beta = Beta::new((returner_running / 10_f64).max(0.05_f64), 5.0_f64).unwrap();
                 let yards_gained: isize = (dst_yards_gained.sample(&mut rng) * 10_f64) as isize;
                 total_yards_gained += yards_gained;
             }
             iterations += 1_usize;
         }
-        return (player_is_tackled, total_yards_gained, iterations, message);
+        return (player_is_tackled, total_yards_gained.min(yards_to_endzone), iterations, message);
     }
 
     /// Simulate a kickoff
     fn simulate_kickoff(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
         // If the opening kickoff, then jump to the first quarter
         self.context.clock.start_game();
+        let mut play: Play = self.new_play(PlayType::Kickoff);
 
         // Simulate the kick and get whether a touchback occurred, the kick length, and log message
         let (touchback, kick_length, mut message) = self.simulate_kickoff_kick(&mut rng);
         if touchback {
+            play.complete(0, PlayResult::Touchback, &message);
+            self.plays.push(play);
             return Ok(());
         }
 
@@ -242,27 +1017,24 @@ impl Game {
             // Adjust the game context accordingly
             self.context.possession.flip_possession();
             self.context.possession.set_line_of_possession(25, true);
+            self.context.possession.start_series();
             self.context.clock.increment_game_clock(5, &mut rng);
 
-            // Log the play and return
+            // Log and record the play and return
             self.log(&message);
-            self.context.possession.down = GameDown::First;
+            play.complete(0, PlayResult::FairCatch, &message);
+            self.plays.push(play);
             return Ok(());
         }
 
-        // Flip the possession & set the new line of possession
+        // Flip the possession & set the new line of possession where the
+        // kick landed, measured from the receiving team's own goal line
+        let receiving_yard_line: usize = (self.context.possession.get_yards_to_endzone() - kick_length as isize).max(1) as usize;
         self.context.possession.flip_possession();
-        let new_lop = {
-            if self.context.possession.direction_left {
-                (self.context.possession.line_of_possession - kick_length as isize).abs() as usize
-            } else {
-                (self.context.possession.line_of_possession + kick_length as isize).abs() as usize
-            }
-        };
-        self.context.possession.set_line_of_possession(new_lop, true);
+        self.context.possession.set_line_of_possession(receiving_yard_line, true);
 
         // Simulate the kickoff return & append to the message
-        let (tackle_made, yards_gained, tackles_broken, return_message) = self.simulate_kickoff_return(&mut rng);
+        let (tackle_made, yards_gained, tackles_broken, return_message) = self.simulate_kickoff_return(&mut play, &mut rng);
         message += &return_message;
 
         // Increment the game clock by a few seconds for each broken tackle
@@ -272,12 +1044,116 @@ impl Game {
             message += &format!(" for a touchdown!");
         }
         
-        // Log the play and increment the down
+        // Log and record the play and start the receiving team's series
         self.log(&message);
-        self.context.possession.increment(yards_gained);
+        let kickoff_result: PlayResult = match tackle_made {
+            true => PlayResult::Return,
+            false => PlayResult::Touchdown
+        };
+        play.complete(yards_gained, kickoff_result, &message);
+        self.plays.push(play);
+        self.context.possession.move_line_of_possession(yards_gained);
+        if self.context.possession.is_touchdown() {
+            self.context.possession.down = GameDown::PointAfter;
+            self.score_points(6);
+        } else {
+            self.context.possession.start_series();
+        }
         Ok(())
     }
 
+    /// Simulate whether a player involved in the latest play is injured.
+    /// The ball carrier, the defender who brought them down or covered
+    /// them, a sacked passer and the kicker may each be hurt, with hits
+    /// being far more dangerous.  At most one player is injured per play,
+    /// and they sit out the rest of the game.
+    fn simulate_injury(&mut self, mut rng: &mut impl Rng) {
+        let play: &Play = match self.plays.last() {
+            Some(play) => play,
+            None => return
+        };
+
+        // Get the players involved in the play, their team and whether
+        // they were hit.  Returners are on the receiving team.
+        let contact: bool = is_contact(play.play_type, play.result, play.defender.is_some());
+        let (carrier_team, defender_team) = match play.play_type {
+            PlayType::Kickoff | PlayType::Punt => (&play.defense, &play.offense),
+            _ => (&play.offense, &play.defense)
+        };
+        let sacked: bool = play.play_type == PlayType::Pass && play.ball_carrier.is_none() && play.defender.is_some();
+        let mut involved: Vec<(String, String, bool)> = Vec::new();
+        if let Some(passer) = &play.passer {
+            involved.push((passer.clone(), play.offense.clone(), sacked));
+        }
+        if let Some(carrier) = &play.ball_carrier {
+            involved.push((carrier.clone(), carrier_team.clone(), contact));
+        }
+        if let Some(defender) = &play.defender {
+            involved.push((defender.clone(), defender_team.clone(), contact));
+        }
+        if let Some(kicker) = &play.kicker {
+            involved.push((kicker.clone(), play.offense.clone(), false));
+        }
+
+        // Determine whether any of them is injured
+        let play_type: PlayType = play.play_type;
+        for (name, abbreviation, hit) in involved {
+            let dst_injury: Bernoulli = Bernoulli::new(get_injury_probability(play_type, hit)).unwrap();
+            if !dst_injury.sample(&mut rng) {
+                continue;
+            }
+            let team: &mut Team = match abbreviation == self.home_team.abbreviation {
+                true => &mut self.home_team,
+                false => &mut self.away_team
+            };
+            let position = match team.get_roster().iter().find(|player| player.name == name && !player.is_injured()) {
+                Some(player) => player.position,
+                None => continue
+            };
+            let injury: Injury = Injury::generate(&mut rng);
+            team.set_injury(&name, position, Some(injury));
+            let player_injury: PlayerInjury = PlayerInjury {
+                player: name,
+                team: abbreviation,
+                position,
+                injury
+            };
+            self.log(&format!("Injury: {}", player_injury));
+            if let Some(play) = self.plays.last_mut() {
+                play.injury = Some(player_injury);
+            }
+            return;
+        }
+    }
+
+    /// Add the latest play to the current drive, starting a new drive if
+    /// the offense has changed or the previous drive has ended
+    fn update_drives(&mut self, seconds: usize) {
+        let play: &Play = match self.plays.last() {
+            Some(play) => play,
+            None => return
+        };
+        if !play.is_from_scrimmage() {
+            return;
+        }
+        let is_new_drive: bool = match self.drives.last() {
+            Some(drive) => drive.is_over() || drive.offense != play.offense,
+            None => true
+        };
+        if is_new_drive {
+            self.drives.push(Drive::new(play));
+        }
+        if let Some(drive) = self.drives.last_mut() {
+            drive.add_play(play, seconds);
+        }
+    }
+
+    /// Format the drive chart, one drive per line
+    pub fn format_drive_chart(&self) -> String {
+        let drive_strs: Vec<String> = self.drives.iter().map(|drive| format!("{}", drive)).collect();
+        drive_strs.join("\n")
+    }
+
     /// Simulate the next play
     pub fn simulate_next_play(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
         // Check if the game is over, if so then error
@@ -286,24 +1162,106 @@ impl Game {
         }
 
         // Simulate the next play based on the down
+        let num_plays: usize = self.plays.len();
+        let clock_seconds: usize = self.context.clock.game_clock_seconds;
+        let quarter: usize = self.context.clock.get_quarter_number();
         match self.context.possession.down {
             GameDown::Kickoff => {
-                self.simulate_kickoff(&mut rng)
+                self.simulate_kickoff(&mut rng)?;
             },
             GameDown::PointAfter => {
-                self.simulate_pat(&mut rng)
+                self.simulate_pat(&mut rng)?;
             },
             _ => {
-                self.simulate_play(&mut rng)
+                self.simulate_play(&mut rng)?;
+            }
+        };
+        if self.plays.len() > num_plays {
+            self.simulate_injury(&mut rng);
+            if let Some(PlayType::Kickoff | PlayType::Punt | PlayType::FieldGoal | PlayType::ExtraPoint) = self.plays.last().map(|play| play.play_type) {
+                self.home_team.recover_fatigue(1);
+                self.away_team.recover_fatigue(1);
+            }
+        }
+
+        // Add the play to the current drive if it was from scrimmage
+        if self.plays.len() > num_plays {
+            let seconds: usize = clock_seconds.saturating_sub(self.context.clock.game_clock_seconds);
+            self.update_drives(seconds);
+        }
+
+        // End the quarter if time has expired, unless a PAT is still owed
+        if self.context.clock.is_quarter_over() && self.context.possession.down != GameDown::PointAfter {
+            self.end_quarter(&mut rng);
+        }
+
+        // End the game as soon as overtime is decided
+        if self.context.clock.quarter == GameQuarter::Overtime && self.is_overtime_decided() {
+            self.end_game();
+        }
+
+        // Evaluate the home team's win probability and the expected points
+        // added after the play
+        if self.plays.len() > num_plays {
+            let new_quarter: usize = self.context.clock.get_quarter_number();
+            let half_over: bool = (quarter == 2 && new_quarter == 3) || (quarter == 4 && new_quarter == 5) || self.context.clock.is_game_over();
+            let home_win_probability: f64 = self.get_home_win_probability();
+            let epa: Option<f64> = self.get_expected_points_added(half_over);
+            if let Some(play) = self.plays.last_mut() {
+                play.home_win_probability = home_win_probability;
+                play.epa = epa;
             }
         }
+        Ok(())
+    }
+
+    /// Simulate the game through to the end, starting with the opening
+    /// coin flip if the game has not yet started
+    pub fn simulate(&mut self, mut rng: &mut impl Rng) -> Result<(), GameError> {
+        if self.context.clock.quarter == GameQuarter::Pregame {
+            self.simulate_opening_coin_fip(&mut rng);
+        }
+        while !self.context.clock.is_game_over() {
+            self.simulate_next_play(&mut rng)?;
+        }
+        Ok(())
+    }
+
+    /// Take the seeded game's own random number generator
+    fn take_rng(&mut self) -> Result<ChaCha8Rng, GameError> {
+        match self.rng.take() {
+            Some(rng) => Ok(rng),
+            None => Err(GameError::SeedError(String::from("Cannot simulate with seed: Game was not seeded")))
+        }
+    }
+
+    /// Simulate the next play of a seeded game using its own generator
+    pub fn simulate_next_play_seeded(&mut self) -> Result<(), GameError> {
+        let mut rng: ChaCha8Rng = self.take_rng()?;
+        if self.context.clock.quarter == GameQuarter::Pregame {
+            self.simulate_opening_coin_fip(&mut rng);
+        }
+        let result = self.simulate_next_play(&mut rng);
+        self.rng = Some(rng);
+        result
+    }
+
+    /// Simulate a seeded game through to the end using its own generator
+    pub fn simulate_seeded(&mut self) -> Result<(), GameError> {
+        let mut rng: ChaCha8Rng = self.take_rng()?;
+        let result = self.simulate(&mut rng);
+        self.rng = Some(rng);
+        result
     }
 }
 
 impl fmt::Display for Game {
     /// Format a `Game` as a string
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let team_str = format!("{} {}\n{} {}", self.home_team, self.context.score.home_team_score, self.away_team, self.context.score.away_team_score);
+        let mut team_str = format!("{} {}\n{} {}", self.home_team, self.context.score.home_team_score, self.away_team, self.context.score.away_team_score);
+        if let Some(seed) = self.seed {
+            team_str += &format!("\nSeed: {}", seed);
+        }
         f.write_str(&team_str)
     }
 }
@@ -315,13 +1273,164 @@ impl fmt::Display for Game {
 #[derive(Debug)]
 pub enum GameError {
     GameOverError(String),
+    SeedError(String),
+    LineupError(String)
 }
 
 impl fmt::Display for GameError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let description = match self {
-            GameError::GameOverError(err) => format!("Game Over Error: {}", err)
+            GameError::GameOverError(err) => format!("Game Over Error: {}", err),
+            GameError::SeedError(err) => format!("Seed Error: {}", err),
+            GameError::LineupError(err) => format!("Lineup Error: {}", err)
         };
         f.write_str(&description)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::boxscore::{BoxScore, PlayerBoxScore};
+    use crate::game::drive::DriveResult;
+    use crate::game::environment::{Precipitation, Surface};
+    use crate::team::generator::generate_test_teams;
+    use crate::team::playcall::DefensivePlay;
+
+    #[test]
+    fn test_seeded_games_are_reproducible() {
+        let (home_team, away_team) = generate_test_teams(0);
+        let mut game: Game = Game::from_seed(home_team.clone(), away_team.clone(), 42);
+        let mut other: Game = Game::from_seed(home_team, away_team, 42);
+        game.simulate_seeded().unwrap();
+        other.simulate_seeded().unwrap();
+        assert_eq!(game.plays, other.plays);
+        assert_eq!(game.log.log, other.log.log);
+    }
+
+    #[test]
+    fn test_replay_matches_original_with_fitted_models() {
+        // Fit the models to a handful of games
+        let (home_team, away_team) = generate_test_teams(1);
+        let mut games: Vec<Game> = Vec::new();
+        for seed in 0..20_u64 {
+            let mut game: Game = Game::from_seed(home_team.clone(), away_team.clone(), seed);
+            game.simulate_seeded().unwrap();
+            games.push(game);
+        }
+        let win_probability_model: WinProbabilityModel = WinProbabilityModel::fit(&games);
+        let expected_points_model: ExpectedPointsModel = ExpectedPointsModel::fit(&games);
+
+        // Play a game with the fitted models and unusual settings, then replay it
+        for seed in 100..105_u64 {
+            let mut game: Game = Game::from_seed(home_team.clone(), away_team.clone(), seed);
+            game.win_probability_model = win_probability_model.clone();
+            game.expected_points_model = expected_points_model.clone();
+            game.set_overtime_rules(OvertimeRules::Playoff);
+            game.set_environment(GameEnvironment::new(25_f64, 15_f64, 45_f64, Precipitation::Snow, 0_f64, Surface::Grass));
+            game.set_home_field(HomeFieldAdvantage::new(1_f64, 0.5_f64));
+            game.simulate_seeded().unwrap();
+            let replay: Game = game.replay().unwrap();
+            assert_eq!(replay.plays, game.plays);
+            assert_eq!(replay.log.log, game.log.log);
+        }
+    }
+
+    #[test]
+    fn test_false_starts_are_recorded_as_plays() {
+        let (home_team, away_team) = generate_test_teams(2);
+        let mut num_false_starts: usize = 0;
+        for seed in 0..5_u64 {
+            let mut game: Game = Game::from_seed(home_team.clone(), away_team.clone(), seed);
+            game.set_home_field(HomeFieldAdvantage::new(1_f64, 0_f64));
+            game.simulate_seeded().unwrap();
+
+            // Only the away offense false starts, losing at most five yards
+            let false_starts: Vec<&Play> = game.plays.iter().filter(|play| play.play_type == PlayType::Penalty).collect();
+            for play in false_starts.iter() {
+                assert_eq!(play.offense, "AWAY");
+                assert_eq!(play.result, PlayResult::Penalty);
+                assert!(play.yards < 0 && play.yards >= -5);
+            }
+            let box_score: BoxScore = BoxScore::from_game(&game);
+            assert_eq!(box_score.away.penalties, false_starts.len());
+            assert_eq!(box_score.away.penalty_yards, -false_starts.iter().map(|play| play.yards).sum::<isize>());
+            assert_eq!(box_score.home.penalties, 0);
+
+            // Every play from scrimmage, penalties included, is part of a drive
+            let num_scrimmage_plays: usize = game.plays.iter().filter(|play| play.is_from_scrimmage()).count();
+            assert_eq!(game.drives.iter().map(|drive| drive.num_plays).sum::<usize>(), num_scrimmage_plays);
+            num_false_starts += false_starts.len();
+        }
+        assert!(num_false_starts > 0);
+    }
+
+    /// Get the average yards gained on a run for an offensive and
+    /// defensive lineup
+    fn get_average_run_yards(game: &Game, off_personnel: OffensivePersonnel, formation: OffensiveFormation, def_personnel: DefensivePersonnel) -> f64 {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(7);
+        let offense: OffensiveLineup = game.home_team.offense.get_lineup(off_personnel, formation);
+        let defense: DefensiveLineup = game.away_team.defense.get_lineup(def_personnel);
+        let def_call: DefensivePlay = DefensivePlay::new(false, false, true, PlayDepth::Medium);
+        let num_runs: usize = 4000;
+        let mut total_yards: isize = 0;
+        for _ in 0..num_runs {
+            let mut play: Play = Play::new(0, &game.context, "HOME", "AWAY", PlayType::Run);
+            let (yards, _, _, _) = game.simulate_run(&offense, &defense, true, &def_call, &mut play, &mut rng).unwrap();
+            total_yards += yards;
+        }
+        total_yards as f64 / num_runs as f64
+    }
+
+    #[test]
+    fn test_personnel_and_formation_change_run_outcomes() {
+        let (home_team, away_team) = generate_test_teams(3);
+        let game: Game = Game::new(home_team, away_team);
+
+        // A heavy box stops the run better than a light one
+        let vs_dime: f64 = get_average_run_yards(&game, OffensivePersonnel::TwentyOne, OffensiveFormation::IFormation, DefensivePersonnel::Dime);
+        let vs_goal_line: f64 = get_average_run_yards(&game, OffensivePersonnel::TwentyOne, OffensiveFormation::IFormation, DefensivePersonnel::GoalLine);
+        assert!(vs_dime > vs_goal_line);
+
+        // Running from under center beats running from the shotgun
+        let from_shotgun: f64 = get_average_run_yards(&game, OffensivePersonnel::TwentyOne, OffensiveFormation::Shotgun, DefensivePersonnel::Base);
+        let from_i_formation: f64 = get_average_run_yards(&game, OffensivePersonnel::TwentyOne, OffensiveFormation::IFormation, DefensivePersonnel::Base);
+        assert!(from_i_formation > from_shotgun);
+    }
+
+    #[test]
+    fn test_missing_quarterback_is_a_lineup_error() {
+        let (mut home_team, away_team) = generate_test_teams(4);
+        home_team.offense.quarterbacks.clear();
+        let mut game: Game = Game::from_seed(home_team, away_team, 0);
+        match game.simulate_seeded() {
+            Err(GameError::LineupError(err)) => assert!(err.contains("quarterback")),
+            other => panic!("Expected a lineup error, got {:?}", other.err())
+        }
+    }
+
+    #[test]
+    fn test_turnover_on_downs_keeps_play_result() {
+        let (home_team, away_team) = generate_test_teams(5);
+        let mut kept_results: Vec<PlayResult> = Vec::new();
+        for seed in 0..40_u64 {
+            let mut game: Game = Game::from_seed(home_team.clone(), away_team.clone(), seed);
+            game.simulate_seeded().unwrap();
+            for play in game.plays.iter().filter(|play| play.turnover_on_downs) {
+                assert_eq!(play.down, 4);
+                assert!(play.yards < play.distance);
+                assert!(!matches!(play.result, PlayResult::FirstDown | PlayResult::Touchdown | PlayResult::Interception | PlayResult::Fumble));
+                kept_results.push(play.result);
+            }
+
+            // Every sack counts against the passer, fourth down or not
+            let num_sacks: usize = game.plays.iter().filter(|play| play.result == PlayResult::Sack).count();
+            let box_scores: Vec<PlayerBoxScore> = PlayerBoxScore::from_game(&game);
+            assert_eq!(box_scores.iter().map(|box_score| box_score.times_sacked).sum::<usize>(), num_sacks);
+            let num_downs: usize = game.drives.iter().filter(|drive| drive.result == Some(DriveResult::Downs)).count();
+            assert_eq!(num_downs, game.plays.iter().filter(|play| play.turnover_on_downs).count());
+        }
+        assert!(kept_results.contains(&PlayResult::Gain));
+        assert!(kept_results.contains(&PlayResult::Incomplete));
+    }
+}
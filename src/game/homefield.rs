@@ -0,0 +1,141 @@
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The probability of a false start by the away offense at full crowd noise
+const MAX_FALSE_START_PROBABILITY: f64 = 0.04_f64;
+
+/// The change in the away offense's sack and completion probabilities
+/// from struggling to communicate at full crowd noise
+const MAX_COMMUNICATION_PENALTY: f64 = 0.03_f64;
+
+/// # HomeFieldAdvantage struct
+///
+/// A `HomeFieldAdvantage` describes how much the home team benefits from
+/// playing at home.  `crowd_noise` ranges from 0 for a silent stadium to 1
+/// for the loudest, and the noise draws false starts from the away offense
+/// and makes it harder for them to communicate at the line, leading to
+/// more sacks and fewer completions.  `rating_boost` is added to the
+/// ratings of the home team's players on every play from scrimmage.  At a
+/// neutral site neither team has any advantage.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HomeFieldAdvantage {
+    pub crowd_noise: f64,
+    pub rating_boost: f64,
+    pub neutral_site: bool
+}
+
+impl Default for HomeFieldAdvantage {
+    /// A typical home crowd and a small rating boost
+    fn default() -> Self {
+        HomeFieldAdvantage {
+            crowd_noise: 0.5_f64,
+            rating_boost: 0.25_f64,
+            neutral_site: false
+        }
+    }
+}
+
+impl HomeFieldAdvantage {
+    /// Initialize a new home field advantage given the crowd noise and the
+    /// home team's rating boost
+    pub fn new(crowd_noise: f64, rating_boost: f64) -> HomeFieldAdvantage {
+        HomeFieldAdvantage {
+            crowd_noise: crowd_noise.clamp(0_f64, 1_f64),
+            rating_boost: rating_boost.max(0_f64),
+            neutral_site: false
+        }
+    }
+
+    /// Initialize a home field advantage for a game at a neutral site
+    pub fn neutral() -> HomeFieldAdvantage {
+        HomeFieldAdvantage {
+            neutral_site: true,
+            ..Default::default()
+        }
+    }
+
+    /// Get the crowd noise the away team plays through, clamped to its
+    /// range since the field can be set directly
+    pub fn get_crowd_noise(&self) -> f64 {
+        match self.neutral_site {
+            true => 0_f64,
+            false => self.crowd_noise.clamp(0_f64, 1_f64)
+        }
+    }
+
+    /// Get the probability that the offense false starts before a snap
+    pub fn get_false_start_probability(&self, offense_home: bool) -> f64 {
+        match offense_home {
+            true => 0_f64,
+            false => MAX_FALSE_START_PROBABILITY * self.get_crowd_noise()
+        }
+    }
+
+    /// Get how much likelier the offense is to be sacked, and how much
+    /// less likely to complete a pass, from struggling to communicate
+    pub fn get_communication_penalty(&self, offense_home: bool) -> f64 {
+        match offense_home {
+            true => 0_f64,
+            false => MAX_COMMUNICATION_PENALTY * self.get_crowd_noise()
+        }
+    }
+
+    /// Get the ratings edge of the offense over the defense, positive when
+    /// the home team has the ball.  A negative rating boost counts as none.
+    pub fn get_rating_edge(&self, offense_home: bool) -> f64 {
+        if self.neutral_site {
+            return 0_f64;
+        }
+        let rating_boost: f64 = self.rating_boost.max(0_f64);
+        match offense_home {
+            true => rating_boost,
+            false => -rating_boost
+        }
+    }
+}
+
+impl fmt::Display for HomeFieldAdvantage {
+    /// Format a `HomeFieldAdvantage` as its crowd noise and rating boost
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let home_field_str = match self.neutral_site {
+            true => String::from("Neutral site"),
+            false => format!("Crowd noise {:.2}, rating boost {:.2}", self.crowd_noise, self.rating_boost)
+        };
+        f.write_str(&home_field_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::team::generator::generate_test_teams;
+
+    #[test]
+    fn test_out_of_range_fields_are_clamped_on_use() {
+        let home_field: HomeFieldAdvantage = HomeFieldAdvantage {
+            crowd_noise: -1_f64,
+            rating_boost: -0.5_f64,
+            neutral_site: false
+        };
+        assert_eq!(home_field.get_crowd_noise(), 0_f64);
+        assert_eq!(home_field.get_false_start_probability(false), 0_f64);
+        assert_eq!(home_field.get_rating_edge(true), 0_f64);
+        let loud: HomeFieldAdvantage = HomeFieldAdvantage { crowd_noise: 3_f64, ..Default::default() };
+        assert_eq!(loud.get_false_start_probability(false), MAX_FALSE_START_PROBABILITY);
+    }
+
+    #[test]
+    fn test_game_with_negative_crowd_noise_simulates() {
+        let (home_team, away_team) = generate_test_teams(0);
+        let mut game: Game = Game::from_seed(home_team, away_team, 0);
+        game.set_home_field(HomeFieldAdvantage {
+            crowd_noise: -1_f64,
+            rating_boost: -1_f64,
+            neutral_site: false
+        });
+        assert!(game.simulate_seeded().is_ok());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::game::play::{PlayType, PlayResult};
+use crate::team::position::Position;
+
+use std::fmt;
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # InjurySeverity enum
+///
+/// An `InjurySeverity` represents how serious an injury is, which sets how
+/// many games the injured player is expected to miss
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InjurySeverity {
+    Minor,
+    Moderate,
+    Severe,
+    SeasonEnding
+}
+
+impl InjurySeverity {
+    /// All injury severities, least severe first
+    pub const ALL: [InjurySeverity; 4] = [
+        InjurySeverity::Minor,
+        InjurySeverity::Moderate,
+        InjurySeverity::Severe,
+        InjurySeverity::SeasonEnding
+    ];
+
+    /// Get the relative frequency of injuries of this severity
+    pub fn get_frequency(&self) -> f64 {
+        match self {
+            InjurySeverity::Minor => 0.55_f64,
+            InjurySeverity::Moderate => 0.3_f64,
+            InjurySeverity::Severe => 0.12_f64,
+            InjurySeverity::SeasonEnding => 0.03_f64
+        }
+    }
+
+    /// Get the least and most games missed after the game of the injury
+    pub fn get_games_out_range(&self) -> (usize, usize) {
+        match self {
+            InjurySeverity::Minor => (0, 1),
+            InjurySeverity::Moderate => (1, 3),
+            InjurySeverity::Severe => (4, 8),
+            InjurySeverity::SeasonEnding => (17, 20)
+        }
+    }
+
+    /// Format the injury severity as a string
+    pub fn format_severity(&self) -> String {
+        let severity_str = match self {
+            InjurySeverity::Minor => "Minor",
+            InjurySeverity::Moderate => "Moderate",
+            InjurySeverity::Severe => "Severe",
+            InjurySeverity::SeasonEnding => "SeasonEnding"
+        };
+        String::from(severity_str)
+    }
+}
+
+/// # Injury struct
+///
+/// An `Injury` keeps a player out for the rest of the game in which it
+/// happened, and then for `games_out` more games.  `games_remaining` counts
+/// down as the player's team plays those games.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Injury {
+    pub severity: InjurySeverity,
+    pub games_out: usize,
+    pub games_remaining: usize
+}
+
+impl Injury {
+    /// Initialize a new injury given its severity and the games it is
+    /// expected to keep the player out
+    pub fn new(severity: InjurySeverity, games_out: usize) -> Injury {
+        Injury {
+            severity,
+            games_out,
+            games_remaining: games_out
+        }
+    }
+
+    /// Generate a random injury, minor injuries being the most common
+    pub fn generate(mut rng: &mut impl Rng) -> Injury {
+        let weights: Vec<f64> = InjurySeverity::ALL.iter().map(|severity| severity.get_frequency()).collect();
+        let dst_severity: WeightedIndex<f64> = WeightedIndex::new(&weights).unwrap();
+        let severity: InjurySeverity = InjurySeverity::ALL[dst_severity.sample(&mut rng)];
+        let (min_games_out, max_games_out) = severity.get_games_out_range();
+        Injury::new(severity, rng.gen_range(min_games_out..=max_games_out))
+    }
+
+    /// Count a game missed towards the player's recovery
+    pub fn recover_game(&mut self) {
+        self.games_remaining = self.games_remaining.saturating_sub(1);
+    }
+
+    /// Check whether the player has recovered from the injury
+    pub fn is_healed(&self) -> bool {
+        self.games_remaining == 0
+    }
+}
+
+impl fmt::Display for Injury {
+    /// Format an `Injury` as its severity and expected games out
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let injury_str = match self.games_remaining {
+            0 => format!("{} injury, out for the game", self.severity.format_severity()),
+            1 => format!("{} injury, out 1 more game", self.severity.format_severity()),
+            games => format!("{} injury, out {} more games", self.severity.format_severity(), games)
+        };
+        f.write_str(&injury_str)
+    }
+}
+
+/// # PlayerInjury struct
+///
+/// A `PlayerInjury` records an injury suffered by a player during a game,
+/// identifying the player by name, position and team abbreviation
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayerInjury {
+    pub player: String,
+    pub team: String,
+    pub position: Position,
+    pub injury: Injury
+}
+
+impl fmt::Display for PlayerInjury {
+    /// Format a `PlayerInjury` as the player and their injury
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let injury_str = format!("{} ({}, {}): {}", self.player, self.team, self.position, self.injury);
+        f.write_str(&injury_str)
+    }
+}
+
+/// Get the probability that a player involved in a play is injured given
+/// the type of play and whether they were hit.  Kick returns, with players
+/// colliding at full speed, are the most dangerous, and players who were
+/// not hit are rarely injured.
+pub fn get_injury_probability(play_type: PlayType, contact: bool) -> f64 {
+    match (play_type, contact) {
+        (PlayType::Kickoff, true) => 0.02_f64,
+        (PlayType::Punt, true) => 0.015_f64,
+        (PlayType::Run, true) | (PlayType::Pass, true) => 0.01_f64,
+        (PlayType::FieldGoal, true) | (PlayType::ExtraPoint, true) => 0.005_f64,
+        (PlayType::Run, false) | (PlayType::Pass, false) => 0.002_f64,
+        (PlayType::Penalty, _) | (_, false) => 0.001_f64
+    }
+}
+
+/// Check whether the players involved in a play were hit, that is whether
+/// the ball carrier was tackled or the passer sacked
+pub fn is_contact(play_type: PlayType, result: PlayResult, has_defender: bool) -> bool {
+    match play_type {
+        PlayType::Run => true,
+        PlayType::Pass => match result {
+            PlayResult::Incomplete | PlayResult::Interception => false,
+            _ => has_defender
+        },
+        PlayType::Kickoff | PlayType::Punt => result == PlayResult::Return || result == PlayResult::Touchdown,
+        PlayType::FieldGoal | PlayType::ExtraPoint | PlayType::Penalty => false
+    }
+}
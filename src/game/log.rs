@@ -1,13 +1,24 @@
 use crate::game::context::context::GameContext;
 
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 /// # GameLog struct
 ///
 /// A `GameLog` stores the log of events which occurred
 /// throughout the football game.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameLog {
     pub log: Vec<String>
 }
 
+impl Default for GameLog {
+    /// An empty game log
+    fn default() -> Self {
+        GameLog::new()
+    }
+}
+
 impl GameLog {
     /// Initialize an empty GameLog
     pub fn new() -> GameLog {
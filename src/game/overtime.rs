@@ -0,0 +1,77 @@
+use crate::game::coinflip::CoinFlip;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # OvertimeRules enum
+///
+/// `OvertimeRules` decide what happens when a game is tied at the end of
+/// regulation.  Without overtime the game ends in a tie.  Otherwise
+/// overtime opens with a coin flip and a kickoff, and both teams get a
+/// possession before the next score wins, unless the kicking team scores
+/// first.  Regular season overtime is a single ten minute period which may
+/// still end in a tie, while playoff overtime continues in fifteen minute
+/// periods until there is a winner.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OvertimeRules {
+    #[default]
+    None,
+    RegularSeason,
+    Playoff
+}
+
+impl OvertimeRules {
+    /// Get the length of an overtime period in seconds
+    pub fn get_period_seconds(&self) -> usize {
+        match self {
+            OvertimeRules::None => 0_usize,
+            OvertimeRules::RegularSeason => 600_usize,
+            OvertimeRules::Playoff => 900_usize
+        }
+    }
+
+    /// Check whether another overtime period is played after a number of
+    /// periods have ended in a tie
+    pub fn has_next_period(&self, num_periods: usize) -> bool {
+        match self {
+            OvertimeRules::None => false,
+            OvertimeRules::RegularSeason => num_periods == 0,
+            OvertimeRules::Playoff => true
+        }
+    }
+}
+
+/// # Overtime struct
+///
+/// An `Overtime` holds a game's overtime rules and the progress of its
+/// overtime: the coin flip deciding who kicks off, the index of the first
+/// drive of overtime, and the number of periods started
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Overtime {
+    pub rules: OvertimeRules,
+    pub coin_flip: Option<CoinFlip>,
+    pub first_drive: usize,
+    pub num_periods: usize
+}
+
+impl Overtime {
+    /// Initialize a new overtime given its rules
+    pub fn new(rules: OvertimeRules) -> Overtime {
+        Overtime {
+            rules,
+            ..Default::default()
+        }
+    }
+
+    /// Check whether the game has gone to overtime
+    pub fn has_started(&self) -> bool {
+        self.num_periods > 0
+    }
+
+    /// Check whether the away team kicked off to start overtime
+    pub fn away_team_kicks(&self) -> Option<bool> {
+        self.coin_flip.as_ref().map(|coin_flip| coin_flip.away_team_kicks_opening())
+    }
+}
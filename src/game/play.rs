@@ -1,9 +1,246 @@
 use crate::game::context::context::GameContext;
+use crate::game::injury::PlayerInjury;
+use crate::team::playcall::OffensivePlay;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The columns of a play-by-play CSV export, in order
+pub const PLAY_COLUMNS: [&str; 23] = [
+    "play_id",
+    "quarter",
+    "clock_seconds",
+    "down",
+    "distance",
+    "yard_line",
+    "offense",
+    "defense",
+    "play_type",
+    "play_call",
+    "passer",
+    "ball_carrier",
+    "defender",
+    "kicker",
+    "yards",
+    "result",
+    "turnover_on_downs",
+    "home_score",
+    "away_score",
+    "description",
+    "home_win_probability",
+    "epa",
+    "injury"
+];
+
+/// # PlayType enum
+///
+/// A `PlayType` represents the kind of play that was run
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayType {
+    Kickoff,
+    Run,
+    Pass,
+    Punt,
+    FieldGoal,
+    ExtraPoint,
+    Penalty
+}
+
+impl PlayType {
+    /// Format the play type as a string
+    pub fn format_play_type(&self) -> String {
+        let play_type_str = match self {
+            PlayType::Kickoff => "Kickoff",
+            PlayType::Run => "Run",
+            PlayType::Pass => "Pass",
+            PlayType::Punt => "Punt",
+            PlayType::FieldGoal => "FieldGoal",
+            PlayType::ExtraPoint => "ExtraPoint",
+            PlayType::Penalty => "Penalty"
+        };
+        String::from(play_type_str)
+    }
+}
+
+/// # PlayResult enum
+///
+/// A `PlayResult` represents the outcome of a play
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayResult {
+    Gain,
+    FirstDown,
+    Touchdown,
+    Incomplete,
+    Sack,
+    Interception,
+    Fumble,
+    Touchback,
+    FairCatch,
+    Return,
+    Good,
+    NoGood,
+    Penalty
+}
+
+impl PlayResult {
+    /// Format the play result as a string
+    pub fn format_play_result(&self) -> String {
+        let play_result_str = match self {
+            PlayResult::Gain => "Gain",
+            PlayResult::FirstDown => "FirstDown",
+            PlayResult::Touchdown => "Touchdown",
+            PlayResult::Incomplete => "Incomplete",
+            PlayResult::Sack => "Sack",
+            PlayResult::Interception => "Interception",
+            PlayResult::Fumble => "Fumble",
+            PlayResult::Touchback => "Touchback",
+            PlayResult::FairCatch => "FairCatch",
+            PlayResult::Return => "Return",
+            PlayResult::Good => "Good",
+            PlayResult::NoGood => "NoGood",
+            PlayResult::Penalty => "Penalty"
+        };
+        String::from(play_result_str)
+    }
+}
 
 /// # Play struct
 ///
-/// A `Play` represents a play in a game of football
+/// A `Play` represents a play in a game of football.  The situation is
+/// recorded as it stood before the snap.  The down and distance are zero
+/// for kickoffs and extra points, and the yard line is the distance in
+/// yards from the opponent's goal line.  The home team's win probability
+/// is evaluated after the play, and the expected points added are only
+/// evaluated for plays from scrimmage.  The defender is the player who made
+/// the tackle, sack or interception, or who defended an incomplete pass,
+/// and the kicker is the punter or kicker on punts, field goals and extra
+/// points.  On kickoffs and punts the ball carrier is the returner.  A
+/// penalty before the snap is recorded as a play of its own, with the
+/// yards it cost the offense.  Any player injured on the play is recorded
+/// alongside their injury.  A fourth down play short of the line to gain
+/// keeps its own result and is flagged as a turnover on downs.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Play {
-    context: GameContext,
-    message: String
-}
\ No newline at end of file
+    pub play_id: usize,
+    pub quarter: usize,
+    pub clock_seconds: usize,
+    pub down: usize,
+    pub distance: isize,
+    pub yard_line: isize,
+    pub offense: String,
+    pub defense: String,
+    pub play_type: PlayType,
+    pub play_call: Option<OffensivePlay>,
+    pub passer: Option<String>,
+    pub ball_carrier: Option<String>,
+    pub defender: Option<String>,
+    pub kicker: Option<String>,
+    pub yards: isize,
+    pub result: PlayResult,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub turnover_on_downs: bool,
+    pub home_score: usize,
+    pub away_score: usize,
+    pub home_win_probability: f64,
+    pub epa: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub injury: Option<PlayerInjury>,
+    pub description: String
+}
+
+impl Play {
+    /// Initialize a new play from the situation before the snap
+    pub fn new(play_id: usize, context: &GameContext, offense: &str, defense: &str, play_type: PlayType) -> Play {
+        let down: usize = context.possession.get_down_number();
+        let distance: isize = match down {
+            0 => 0,
+            _ => context.possession.get_yards_to_first()
+        };
+        Play {
+            play_id,
+            quarter: context.clock.get_quarter_number(),
+            clock_seconds: context.clock.game_clock_seconds,
+            down,
+            distance,
+            yard_line: context.possession.get_yards_to_endzone(),
+            offense: String::from(offense),
+            defense: String::from(defense),
+            play_type,
+            play_call: None,
+            passer: None,
+            ball_carrier: None,
+            defender: None,
+            kicker: None,
+            yards: 0_isize,
+            result: PlayResult::Gain,
+            turnover_on_downs: false,
+            home_score: context.score.home_team_score,
+            away_score: context.score.away_team_score,
+            home_win_probability: 0.5_f64,
+            epa: None,
+            injury: None,
+            description: String::new()
+        }
+    }
+
+    /// Check whether the play was from scrimmage
+    pub fn is_from_scrimmage(&self) -> bool {
+        self.play_type != PlayType::Kickoff && self.play_type != PlayType::ExtraPoint
+    }
+
+    /// Record the outcome of the play
+    pub fn complete(&mut self, yards: isize, result: PlayResult, description: &str) {
+        self.yards = yards;
+        self.result = result;
+        self.description = String::from(description);
+    }
+
+    /// Get the play's fields as CSV values, in the order of `PLAY_COLUMNS`
+    pub fn get_csv_fields(&self) -> Vec<String> {
+        vec![
+            self.play_id.to_string(),
+            self.quarter.to_string(),
+            self.clock_seconds.to_string(),
+            self.down.to_string(),
+            self.distance.to_string(),
+            self.yard_line.to_string(),
+            self.offense.clone(),
+            self.defense.clone(),
+            self.play_type.format_play_type(),
+            self.play_call.map(|play_call| play_call.format_offensive_play()).unwrap_or_default(),
+            self.passer.clone().unwrap_or_default(),
+            self.ball_carrier.clone().unwrap_or_default(),
+            self.defender.clone().unwrap_or_default(),
+            self.kicker.clone().unwrap_or_default(),
+            self.yards.to_string(),
+            self.result.format_play_result(),
+            self.turnover_on_downs.to_string(),
+            self.home_score.to_string(),
+            self.away_score.to_string(),
+            self.description.clone(),
+            format!("{:.4}", self.home_win_probability),
+            self.epa.map(|epa| format!("{:.4}", epa)).unwrap_or_default(),
+            self.injury.as_ref().map(|injury| injury.to_string()).unwrap_or_default()
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::context::context::GameContext;
+
+    #[test]
+    fn test_play_columns_header() {
+        // Columns are only ever appended so existing exports stay readable
+        assert_eq!(
+            PLAY_COLUMNS.join(","),
+            "play_id,quarter,clock_seconds,down,distance,yard_line,offense,defense,play_type,play_call,passer,ball_carrier,defender,kicker,yards,result,turnover_on_downs,home_score,away_score,description,home_win_probability,epa,injury"
+        );
+        let play: Play = Play::new(1, &GameContext::new(), "HOME", "AWAY", PlayType::Kickoff);
+        assert_eq!(play.get_csv_fields().len(), PLAY_COLUMNS.len());
+    }
+}
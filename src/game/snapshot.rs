@@ -0,0 +1,137 @@
+#[cfg(feature = "serde")]
+use crate::game::game::Game;
+
+use std::fmt;
+#[cfg(feature = "serde")]
+use std::fs;
+#[cfg(feature = "serde")]
+use std::path::Path;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+
+/// # GameSnapshot struct
+///
+/// A `GameSnapshot` holds the full state of a game in progress together
+/// with the state of the random number generator driving it.  Resuming a
+/// snapshot and continuing the simulation with its generator produces the
+/// same outcome as if the game had never been paused.  Any serializable
+/// generator works, such as `rand_chacha::ChaCha8Rng`, whose state is
+/// serializable when the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+pub struct GameSnapshot<R> {
+    pub game: Game,
+    pub rng: R
+}
+
+#[cfg(feature = "serde")]
+impl<R: Serialize + DeserializeOwned> GameSnapshot<R> {
+    /// Initialize a new snapshot from a game and its generator
+    pub fn new(game: Game, rng: R) -> GameSnapshot<R> {
+        GameSnapshot {
+            game,
+            rng
+        }
+    }
+
+    /// Serialize the snapshot to a JSON string.  Floating point values are
+    /// written so that they read back exactly, which resuming relies on.
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        match serde_json::to_string(self) {
+            Ok(contents) => Ok(contents),
+            Err(err) => Err(SnapshotError::ParseError(err.to_string()))
+        }
+    }
+
+    /// Deserialize a snapshot from a JSON string
+    pub fn from_json(contents: &str) -> Result<GameSnapshot<R>, SnapshotError> {
+        match serde_json::from_str(contents) {
+            Ok(snapshot) => Ok(snapshot),
+            Err(err) => Err(SnapshotError::ParseError(err.to_string()))
+        }
+    }
+
+    /// Save the snapshot to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let path: &Path = path.as_ref();
+        let contents: String = self.to_json()?;
+        match fs::write(path, contents) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(SnapshotError::IoError(format!("{}: {}", path.display(), err)))
+        }
+    }
+
+    /// Load a snapshot from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<GameSnapshot<R>, SnapshotError> {
+        let path: &Path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(contents) => GameSnapshot::from_json(&contents),
+            Err(err) => Err(SnapshotError::IoError(format!("{}: {}", path.display(), err)))
+        }
+    }
+}
+
+/// # SnapshotError enum
+///
+/// The `SnapshotError` enum represents the errors that can be generated
+/// while saving or loading a game snapshot
+#[derive(Debug)]
+pub enum SnapshotError {
+    IoError(String),
+    ParseError(String)
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            SnapshotError::IoError(err) => format!("Snapshot IO Error: {}", err),
+            SnapshotError::ParseError(err) => format!("Snapshot Parse Error: {}", err)
+        };
+        f.write_str(&description)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_teams;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Start a game between two generated teams and play its first plays
+    fn start_game(seed: u64, num_plays: usize) -> (Game, ChaCha8Rng) {
+        let (home_team, away_team) = generate_test_teams(seed);
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut game: Game = Game::new(home_team, away_team);
+        game.simulate_opening_coin_fip(&mut rng);
+        for _ in 0..num_plays {
+            game.simulate_next_play(&mut rng).unwrap();
+        }
+        (game, rng)
+    }
+
+    #[test]
+    fn test_resumed_snapshot_matches_uninterrupted_game() {
+        for seed in 0..10_u64 {
+            for num_plays in [20_usize, 60, 100] {
+                let (mut uninterrupted, mut rng) = start_game(seed, 0);
+                uninterrupted.simulate(&mut rng).unwrap();
+
+                let (game, rng) = start_game(seed, num_plays);
+                let json: String = GameSnapshot::new(game, rng).to_json().unwrap();
+                let snapshot: GameSnapshot<ChaCha8Rng> = GameSnapshot::from_json(&json).unwrap();
+                assert_eq!(snapshot.to_json().unwrap(), json);
+                let GameSnapshot { game: mut resumed, rng: mut resumed_rng } = snapshot;
+                resumed.simulate(&mut resumed_rng).unwrap();
+
+                assert_eq!(resumed.log.log, uninterrupted.log.log);
+                assert_eq!(
+                    serde_json::to_string(&resumed.plays).unwrap(),
+                    serde_json::to_string(&uninterrupted.plays).unwrap()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,352 @@
+use crate::game::context::context::GameContext;
+use crate::game::game::Game;
+use crate::game::play::{Play, PlayType};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The number of features in the win probability model
+pub const NUM_WIN_PROBABILITY_FEATURES: usize = 8;
+
+/// The default coefficients of the win probability model, fit to 4000
+/// seeded games between teams from `TeamGenerator::new(6.0, 1.5)`
+const DEFAULT_COEFFICIENTS: [f64; NUM_WIN_PROBABILITY_FEATURES] = [
+    0.3616_f64,
+    0.0307_f64,
+    2.0615_f64,
+    0.3904_f64,
+    0.7414_f64,
+    -0.2399_f64,
+    0.0195_f64,
+    1.2974_f64
+];
+
+/// Calculate the seconds remaining in regulation given a quarter number
+/// and the seconds left on the game clock
+pub fn get_seconds_remaining(quarter: usize, clock_seconds: usize) -> usize {
+    match quarter {
+        1..=4 => (4 - quarter) * 900 + clock_seconds,
+        _ => clock_seconds
+    }
+}
+
+/// # GameSituation struct
+///
+/// A `GameSituation` holds the facts about a moment in a game which the
+/// win probability model draws on, from the home team's perspective
+#[derive(Clone, Copy, Debug)]
+pub struct GameSituation {
+    pub home_score_diff: isize,
+    pub seconds_remaining: usize,
+    pub home_possession: bool,
+    pub yard_line: isize,
+    pub down: usize,
+    pub yards_to_first: isize,
+    pub rating_diff: f64
+}
+
+impl GameSituation {
+    /// Initialize a situation from the current game context
+    pub fn from_context(context: &GameContext, home_overall: f64, away_overall: f64) -> GameSituation {
+        GameSituation {
+            home_score_diff: context.score.home_team_score as isize - context.score.away_team_score as isize,
+            seconds_remaining: get_seconds_remaining(context.clock.get_quarter_number(), context.clock.game_clock_seconds),
+            home_possession: !context.possession.possession_away,
+            yard_line: context.possession.get_yards_to_endzone(),
+            down: context.possession.get_down_number(),
+            yards_to_first: match context.possession.get_down_number() {
+                0 => 0,
+                _ => context.possession.get_yards_to_first()
+            },
+            rating_diff: home_overall - away_overall
+        }
+    }
+
+    /// Initialize a situation from the moment before a play was snapped
+    pub fn from_play(play: &Play, home_abbreviation: &str, home_overall: f64, away_overall: f64) -> GameSituation {
+        GameSituation {
+            home_score_diff: play.home_score as isize - play.away_score as isize,
+            seconds_remaining: get_seconds_remaining(play.quarter, play.clock_seconds),
+            home_possession: play.offense == home_abbreviation,
+            yard_line: play.yard_line,
+            down: play.down,
+            yards_to_first: play.distance,
+            rating_diff: home_overall - away_overall
+        }
+    }
+
+    /// Get the model features of the situation.  The score difference
+    /// matters more as time runs out, while the rating difference matters
+    /// less.  Possession, field position, down and distance are signed
+    /// towards the home team, and the down and distance are zero on
+    /// kickoffs and point afters.
+    pub fn get_features(&self) -> [f64; NUM_WIN_PROBABILITY_FEATURES] {
+        let time_fraction: f64 = self.seconds_remaining as f64 / 3600_f64;
+        let score_diff: f64 = self.home_score_diff as f64 / 14_f64;
+        let possession: f64 = if self.home_possession { 1_f64 } else { -1_f64 };
+        let down: f64 = self.down.saturating_sub(1) as f64 / 3_f64;
+        let distance: f64 = self.yards_to_first.clamp(0, 20) as f64 / 10_f64;
+        [
+            1_f64,
+            score_diff,
+            score_diff / (time_fraction + 0.01_f64).sqrt(),
+            possession,
+            possession * (50_f64 - self.yard_line as f64) / 50_f64,
+            possession * down,
+            possession * distance,
+            self.rating_diff * time_fraction.sqrt()
+        ]
+    }
+}
+
+/// # CalibrationBin struct
+///
+/// A `CalibrationBin` compares the average predicted win probability of a
+/// group of situations to the fraction of those situations actually won
+#[derive(Clone, Copy, Debug)]
+pub struct CalibrationBin {
+    pub predicted: f64,
+    pub observed: f64,
+    pub count: usize
+}
+
+/// # WinProbabilityModel struct
+///
+/// A `WinProbabilityModel` is a logistic regression giving the home
+/// team's probability of winning from a game situation.  Ties count as
+/// half a win.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WinProbabilityModel {
+    pub coefficients: [f64; NUM_WIN_PROBABILITY_FEATURES]
+}
+
+impl Default for WinProbabilityModel {
+    /// Initialize the model with its default coefficients
+    fn default() -> Self {
+        WinProbabilityModel::new(DEFAULT_COEFFICIENTS)
+    }
+}
+
+impl WinProbabilityModel {
+    /// Initialize a new model given its coefficients
+    pub fn new(coefficients: [f64; NUM_WIN_PROBABILITY_FEATURES]) -> WinProbabilityModel {
+        WinProbabilityModel {
+            coefficients
+        }
+    }
+
+    /// Get the home team's win probability in a situation
+    pub fn get_probability(&self, situation: &GameSituation) -> f64 {
+        let features = situation.get_features();
+        let mut logit: f64 = 0_f64;
+        for (coefficient, feature) in self.coefficients.iter().zip(features.iter()) {
+            logit += coefficient * feature;
+        }
+        1_f64 / (1_f64 + (-logit).exp())
+    }
+
+    /// Get the home team's win probability given the game context and the
+    /// overall ratings of both teams.  Once the game is over the result
+    /// is certain.
+    pub fn get_home_win_probability(&self, context: &GameContext, home_overall: f64, away_overall: f64) -> f64 {
+        if context.clock.is_game_over() {
+            return context.score.get_home_win_share();
+        }
+        let situation: GameSituation = GameSituation::from_context(context, home_overall, away_overall);
+        self.get_probability(&situation)
+    }
+
+    /// Get the situation before every play of a finished game alongside
+    /// the home team's share of the win.  Point after attempts are skipped
+    /// since their situation is dominated by the touchdown before them.
+    pub fn get_samples(game: &Game) -> Vec<(GameSituation, f64)> {
+        let home_overall: f64 = game.home_team.get_overall();
+        let away_overall: f64 = game.away_team.get_overall();
+        let home_win_share: f64 = game.context.score.get_home_win_share();
+        game.plays.iter()
+            .filter(|play| play.play_type != PlayType::ExtraPoint)
+            .map(|play| (GameSituation::from_play(play, &game.home_team.abbreviation, home_overall, away_overall), home_win_share))
+            .collect()
+    }
+
+    /// Fit a model to a batch of finished games using Newton's method
+    pub fn fit(games: &[Game]) -> WinProbabilityModel {
+        let samples: Vec<(GameSituation, f64)> = games.iter().flat_map(WinProbabilityModel::get_samples).collect();
+        WinProbabilityModel::fit_samples(&samples)
+    }
+
+    /// Fit a model to situations and their outcomes using Newton's method
+    pub fn fit_samples(samples: &[(GameSituation, f64)]) -> WinProbabilityModel {
+        const N: usize = NUM_WIN_PROBABILITY_FEATURES;
+        let mut model: WinProbabilityModel = WinProbabilityModel::new([0_f64; N]);
+        for _ in 0..25 {
+            // Accumulate the gradient and hessian of the log likelihood,
+            // with a small ridge penalty to keep the hessian invertible
+            let mut gradient: [f64; N] = [0_f64; N];
+            let mut hessian: [[f64; N]; N] = [[0_f64; N]; N];
+            for (i, row) in hessian.iter_mut().enumerate() {
+                row[i] = 1e-6_f64;
+            }
+            for (situation, outcome) in samples.iter() {
+                let features = situation.get_features();
+                let p: f64 = model.get_probability(situation);
+                let weight: f64 = p * (1_f64 - p);
+                for i in 0..N {
+                    gradient[i] += (outcome - p) * features[i];
+                    for j in 0..N {
+                        hessian[i][j] += weight * features[i] * features[j];
+                    }
+                }
+            }
+
+            // Take a Newton step
+            let step: [f64; N] = solve_linear_system(hessian, gradient);
+            let mut step_size: f64 = 0_f64;
+            for (coefficient, delta) in model.coefficients.iter_mut().zip(step.iter()) {
+                *coefficient += delta;
+                step_size += delta.abs();
+            }
+            if step_size < 1e-9_f64 {
+                break;
+            }
+        }
+        model
+    }
+
+    /// Compare the model's predictions to the outcomes of a batch of
+    /// finished games, grouping situations into equal-width probability
+    /// bins.  Empty bins are omitted.
+    pub fn get_calibration(&self, games: &[Game], num_bins: usize) -> Vec<CalibrationBin> {
+        let num_bins: usize = num_bins.max(1);
+        let mut sum_predicted: Vec<f64> = vec![0_f64; num_bins];
+        let mut sum_observed: Vec<f64> = vec![0_f64; num_bins];
+        let mut counts: Vec<usize> = vec![0_usize; num_bins];
+        for game in games.iter() {
+            for (situation, outcome) in WinProbabilityModel::get_samples(game) {
+                let p: f64 = self.get_probability(&situation);
+                let bin: usize = ((p * num_bins as f64) as usize).min(num_bins - 1);
+                sum_predicted[bin] += p;
+                sum_observed[bin] += outcome;
+                counts[bin] += 1;
+            }
+        }
+        (0..num_bins)
+            .filter(|bin| counts[*bin] > 0)
+            .map(|bin| CalibrationBin {
+                predicted: sum_predicted[bin] / counts[bin] as f64,
+                observed: sum_observed[bin] / counts[bin] as f64,
+                count: counts[bin]
+            })
+            .collect()
+    }
+}
+
+/// Solve a small linear system by Gaussian elimination with partial
+/// pivoting
+pub(crate) fn solve_linear_system<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> [f64; N] {
+    for col in 0..N {
+        // Swap in the row with the largest pivot
+        let mut pivot: usize = col;
+        for row in (col + 1)..N {
+            if a[row][col].abs() > a[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        // Eliminate the column from the rows below
+        for row in (col + 1)..N {
+            let factor: f64 = a[row][col] / a[col][col];
+            let pivot_row: [f64; N] = a[col];
+            for (value, pivot_value) in a[row].iter_mut().zip(pivot_row.iter()).skip(col) {
+                *value -= factor * pivot_value;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    // Back substitute
+    let mut x: [f64; N] = [0_f64; N];
+    for row in (0..N).rev() {
+        let mut sum: f64 = b[row];
+        for k in (row + 1)..N {
+            sum -= a[row][k] * x[k];
+        }
+        x[row] = sum / a[row][row];
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create a situation with the home team in possession of a tied game
+    fn create_situation(down: usize, yards_to_first: isize) -> GameSituation {
+        GameSituation {
+            home_score_diff: 0,
+            seconds_remaining: 600,
+            home_possession: true,
+            yard_line: 50,
+            down,
+            yards_to_first,
+            rating_diff: 0_f64
+        }
+    }
+
+    #[test]
+    fn test_later_down_and_longer_distance_lower_win_probability() {
+        let model: WinProbabilityModel = WinProbabilityModel::default();
+        let first_and_ten: f64 = model.get_probability(&create_situation(1, 10));
+        let fourth_and_fifteen: f64 = model.get_probability(&create_situation(4, 15));
+        assert!(first_and_ten > fourth_and_fifteen);
+
+        // The same situation with the away team in possession mirrors it
+        let mut away_first_and_ten: GameSituation = create_situation(1, 10);
+        away_first_and_ten.home_possession = false;
+        let mut away_fourth_and_fifteen: GameSituation = create_situation(4, 15);
+        away_fourth_and_fifteen.home_possession = false;
+        assert!(model.get_probability(&away_first_and_ten) < model.get_probability(&away_fourth_and_fifteen));
+    }
+
+    #[test]
+    fn test_seconds_remaining_counts_down_regulation() {
+        assert_eq!(get_seconds_remaining(1, 900), 3600);
+        assert_eq!(get_seconds_remaining(3, 120), 1020);
+        assert_eq!(get_seconds_remaining(5, 300), 300);
+    }
+
+    #[test]
+    fn test_fit_learns_score_difference() {
+        // The home team wins whenever it leads and loses whenever it trails
+        let mut samples: Vec<(GameSituation, f64)> = Vec::new();
+        for diff in -14..=14_isize {
+            let mut situation: GameSituation = create_situation(1, 10);
+            situation.home_score_diff = diff;
+            let outcome: f64 = match diff {
+                d if d > 0 => 1_f64,
+                0 => 0.5_f64,
+                _ => 0_f64
+            };
+            samples.push((situation, outcome));
+            samples.push((situation, (outcome + 0.5_f64) / 2_f64));
+        }
+        let model: WinProbabilityModel = WinProbabilityModel::fit_samples(&samples);
+        let mut ahead: GameSituation = create_situation(1, 10);
+        ahead.home_score_diff = 7;
+        let mut behind: GameSituation = create_situation(1, 10);
+        behind.home_score_diff = -7;
+        assert!(model.get_probability(&ahead) > 0.7_f64);
+        assert!(model.get_probability(&behind) < 0.3_f64);
+    }
+
+    #[test]
+    fn test_solve_linear_system() {
+        let x: [f64; 3] = solve_linear_system([[0_f64, 2_f64, 1_f64], [1_f64, 1_f64, 0_f64], [2_f64, 0_f64, 3_f64]], [5_f64, 3_f64, 11_f64]);
+        for (value, expected) in x.iter().zip([1.75_f64, 1.25_f64, 2.5_f64].iter()) {
+            assert!((value - expected).abs() < 1e-9, "{:?}", x);
+        }
+    }
+}
@@ -0,0 +1,453 @@
+use crate::league::league::{League, LeagueError};
+use crate::league::playoffs::Playoffs;
+use crate::league::standings::Standings;
+use crate::team::attributes::{Attribute, PlayerAttributes};
+use crate::team::contract::Contract;
+use crate::team::generator::{PlayerGenerator, ROSTER_SIZES};
+use crate::team::player::Player;
+use crate::team::position::Position;
+use crate::team::progression::get_projected_overall;
+use crate::team::team::Team;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use rand::Rng;
+use rand::distributions::Distribution;
+use statrs::distribution::Normal;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The number of starters at each position
+const NUM_STARTERS: [(Position, usize); 15] = [
+    (Position::Quarterback, 1),
+    (Position::Runningback, 1),
+    (Position::Fullback, 1),
+    (Position::WideReceiver, 3),
+    (Position::TightEnd, 1),
+    (Position::OffensiveLineman, 5),
+    (Position::DefensiveEnd, 2),
+    (Position::DefensiveTackle, 2),
+    (Position::Linebacker, 3),
+    (Position::Cornerback, 3),
+    (Position::Safety, 2),
+    (Position::Kicker, 1),
+    (Position::Punter, 1),
+    (Position::LongSnapper, 1),
+    (Position::Returner, 1)
+];
+
+/// How many rating points of need a coach with perfect intelligence is
+/// willing to trade for a better prospect
+const NEED_WEIGHT: f64 = 3_f64;
+
+/// The standard deviation of a coach's misjudgment of a prospect's
+/// scouted overall, scaled down as the coach's intelligence goes up
+const MISJUDGMENT_SPREAD: f64 = 1.5_f64;
+
+/// Get a team's need at each position from its depth charts, neediest
+/// first.  A position's need grows as its starters get weaker, with empty
+/// starting spots counting as zero overall, and with every open spot on
+/// the position's depth chart.
+pub fn get_team_needs(team: &Team) -> Vec<(Position, f64)> {
+    let roster: Vec<Player> = team.get_roster();
+    let mut needs: Vec<(Position, f64)> = Vec::with_capacity(ROSTER_SIZES.len());
+    for (position, roster_size) in ROSTER_SIZES.iter() {
+        let num_starters: usize = NUM_STARTERS.iter()
+            .find(|(starter_position, _)| starter_position == position)
+            .map(|(_, num_starters)| *num_starters)
+            .unwrap_or(1);
+        let mut overalls: Vec<f64> = roster.iter()
+            .filter(|player| player.position == *position)
+            .map(|player| player.get_overall())
+            .collect();
+        overalls.sort_by(|a, b| b.total_cmp(a));
+        let num_open_spots: usize = roster_size.saturating_sub(overalls.len());
+        overalls.resize(num_starters.max(overalls.len()), 0_f64);
+        let starter_overall: f64 = overalls.iter().take(num_starters).sum::<f64>() / num_starters as f64;
+        let need: f64 = (10_f64 - starter_overall) / 10_f64 + 0.5_f64 * num_open_spots as f64;
+        needs.push((*position, need));
+    }
+    needs.sort_by(|a, b| b.1.total_cmp(&a.1));
+    needs
+}
+
+/// # Prospect struct
+///
+/// A `Prospect` is a draft-eligible player.  Teams only see the scouted
+/// attributes, which are the player's true attributes plus scouting error,
+/// until the prospect is drafted.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Prospect {
+    pub player: Player,
+    pub scouted_attributes: PlayerAttributes
+}
+
+impl Prospect {
+    /// Scout a player, adding normally distributed error with the given
+    /// standard deviation to each of their attributes
+    pub fn scout(player: Player, scouting_error: f64, mut rng: &mut impl Rng) -> Prospect {
+        let dst_error: Normal = Normal::new(0_f64, scouting_error.max(f64::MIN_POSITIVE)).unwrap();
+        let mut scouted_attributes: PlayerAttributes = player.attributes.clone();
+        for attribute in Attribute::ALL.iter() {
+            let rating: &mut usize = scouted_attributes.get_mut(*attribute);
+            *rating = (*rating as f64 + dst_error.sample(&mut rng)).round().clamp(0_f64, 10_f64) as usize;
+        }
+        Prospect {
+            player,
+            scouted_attributes
+        }
+    }
+
+    /// Get the prospect's scouted overall at their position
+    pub fn get_scouted_overall(&self) -> f64 {
+        self.player.position.get_overall(&self.scouted_attributes)
+    }
+
+    /// Get the prospect's scouted overall projected to next season
+    pub fn get_projected_overall(&self) -> f64 {
+        let mut scouted_player: Player = self.player.clone();
+        scouted_player.attributes = self.scouted_attributes.clone();
+        get_projected_overall(&scouted_player)
+    }
+}
+
+impl fmt::Display for Prospect {
+    /// Format a `Prospect` as their scouted rating
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prospect_str = format!(
+            "{} ({}, age {}, {:.2} Scouted)",
+            self.player.name,
+            self.player.position,
+            self.player.age,
+            self.get_scouted_overall()
+        );
+        f.write_str(&prospect_str)
+    }
+}
+
+/// # DraftPick struct
+///
+/// A `DraftPick` is a numbered selection in the draft.  Picks can be
+/// traded before they are made, so the team making the pick may differ
+/// from the team originally given it.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DraftPick {
+    pub number: usize,
+    pub round: usize,
+    pub original_team: String,
+    pub team: String,
+    pub player: Option<Player>
+}
+
+impl fmt::Display for DraftPick {
+    /// Format a `DraftPick` as its number, team and selection
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut pick_str: String = format!("Round {} Pick {}: {}", self.round, self.number, self.team);
+        if self.team != self.original_team {
+            pick_str += &format!(" (from {})", self.original_team);
+        }
+        if let Some(player) = &self.player {
+            pick_str += &format!(" - {} ({}, {:.2} Overall)", player.name, player.position, player.get_overall());
+        }
+        f.write_str(&pick_str)
+    }
+}
+
+/// # Draft struct
+///
+/// A `Draft` lets teams take turns selecting players from a class of
+/// prospects, with every team picking once a round in the draft order.
+/// Picks can be made manually, or by the AI, which picks the prospect
+/// with the best projected overall plus a bonus for the team's need at
+/// their position.  A coach's `intelligence` decides how well they judge
+/// prospects and how much weight they give to the team's needs.  Drafted
+/// players join their team's roster straight away on a rookie contract.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Draft {
+    pub picks: Vec<DraftPick>,
+    pub prospects: Vec<Prospect>,
+    pub num_picks_made: usize
+}
+
+impl Draft {
+    /// Initialize a new draft given the draft order, the number of rounds
+    /// and the class of prospects
+    pub fn new(order: &[String], num_rounds: usize, prospects: Vec<Prospect>) -> Draft {
+        let mut picks: Vec<DraftPick> = Vec::with_capacity(order.len() * num_rounds);
+        for round in 1..=num_rounds {
+            for team in order.iter() {
+                picks.push(DraftPick {
+                    number: picks.len() + 1,
+                    round,
+                    original_team: team.clone(),
+                    team: team.clone(),
+                    player: None
+                });
+            }
+        }
+        Draft {
+            picks,
+            prospects,
+            num_picks_made: 0_usize
+        }
+    }
+
+    /// Get the draft order from the standings, worst team first.  If the
+    /// playoffs are given, playoff teams pick after every other team in
+    /// order of the round they reached, with the champion picking last.
+    pub fn get_draft_order(league: &League, standings: &Standings, playoffs: Option<&Playoffs>, rng: &mut impl Rng) -> Vec<String> {
+        let teams: Vec<&str> = league.teams.iter().map(|team| team.abbreviation.as_str()).collect();
+        let mut order: Vec<String> = standings.rank_teams(league, &teams, rng);
+        order.reverse();
+        if let Some(playoffs) = playoffs {
+            order.sort_by_key(|team| match playoffs.get_round_reached(team) {
+                Some(_) if *team == playoffs.champion => playoffs.get_num_rounds() + 2,
+                Some(round) => round + 1,
+                None => 0
+            });
+        }
+        order
+    }
+
+    /// Generate a class of prospects.  Prospects are rookies spread across
+    /// positions in proportion to a full roster, scouted with the given
+    /// scouting error.  Every prospect is given a name which is not taken,
+    /// such as by a player already in the league, or by another prospect.
+    pub fn generate_prospects(num_prospects: usize, generator: &PlayerGenerator, scouting_error: f64, taken: &BTreeSet<String>, mut rng: &mut impl Rng) -> Vec<Prospect> {
+        let roster_size: usize = ROSTER_SIZES.iter().map(|(_, count)| count).sum();
+        let mut names: BTreeSet<String> = taken.clone();
+        let mut prospects: Vec<Prospect> = Vec::with_capacity(num_prospects);
+        for index in 0..num_prospects {
+            // Cycle through the positions of a full roster in turn
+            let mut slot: usize = index % roster_size;
+            let mut position: Position = ROSTER_SIZES[0].0;
+            for (roster_position, count) in ROSTER_SIZES.iter() {
+                if slot < *count {
+                    position = *roster_position;
+                    break;
+                }
+                slot -= count;
+            }
+            let player: Player = generator.generate_unique_rookie(position, &names, &mut rng);
+            names.insert(player.name.clone());
+            prospects.push(Prospect::scout(player, scouting_error, &mut rng));
+        }
+        prospects.sort_by(|a, b| b.get_scouted_overall().total_cmp(&a.get_scouted_overall()));
+        prospects
+    }
+
+    /// Check whether every pick has been made
+    pub fn is_over(&self) -> bool {
+        self.num_picks_made >= self.picks.len()
+    }
+
+    /// Get the pick currently on the clock
+    pub fn get_current_pick(&self) -> Option<&DraftPick> {
+        self.picks.get(self.num_picks_made)
+    }
+
+    /// Get a team's picks, made or not
+    pub fn get_team_picks(&self, abbreviation: &str) -> Vec<&DraftPick> {
+        self.picks.iter().filter(|pick| pick.team == abbreviation).collect()
+    }
+
+    /// Trade picks between two teams given the overall numbers of the
+    /// picks each team gives up.  Every pick must belong to the team giving
+    /// it up and must not have been made yet.
+    pub fn trade_picks(&mut self, team_a: &str, picks_a: &[usize], team_b: &str, picks_b: &[usize]) -> Result<(), LeagueError> {
+        for (team, pick_numbers) in [(team_a, picks_a), (team_b, picks_b)] {
+            for pick_number in pick_numbers.iter() {
+                let pick: &DraftPick = match self.picks.get(pick_number.wrapping_sub(1)) {
+                    Some(pick) => pick,
+                    None => return Err(LeagueError::DraftError(format!("Cannot trade pick {}: No such pick", pick_number)))
+                };
+                if pick.team != team {
+                    return Err(LeagueError::DraftError(format!("Cannot trade pick {}: Pick belongs to {}, not {}", pick_number, pick.team, team)));
+                }
+                if pick.player.is_some() {
+                    return Err(LeagueError::DraftError(format!("Cannot trade pick {}: Pick has already been made", pick_number)));
+                }
+            }
+        }
+        for pick_number in picks_a.iter() {
+            self.picks[pick_number - 1].team = String::from(team_b);
+        }
+        for pick_number in picks_b.iter() {
+            self.picks[pick_number - 1].team = String::from(team_a);
+        }
+        Ok(())
+    }
+
+    /// Make the current pick manually given the index of the chosen
+    /// prospect, signing the player to a rookie contract on the picking
+    /// team's roster
+    pub fn make_pick(&mut self, league: &mut League, prospect_index: usize) -> Result<&DraftPick, LeagueError> {
+        if self.is_over() {
+            return Err(LeagueError::DraftError(String::from("Cannot make pick: Draft is over")));
+        }
+        if prospect_index >= self.prospects.len() {
+            return Err(LeagueError::DraftError(format!("Cannot make pick: No prospect at index {}", prospect_index)));
+        }
+        let pick_index: usize = self.num_picks_made;
+        let team: &mut Team = match league.get_team_mut(&self.picks[pick_index].team) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(self.picks[pick_index].team.clone()))
+        };
+        let mut prospect: Prospect = self.prospects.remove(prospect_index);
+        prospect.player.contract = Some(Contract::rookie(self.picks[pick_index].number, self.picks.len()));
+        team.sign_player(prospect.player.clone());
+        self.picks[pick_index].player = Some(prospect.player);
+        self.num_picks_made += 1;
+        Ok(&self.picks[pick_index])
+    }
+
+    /// Choose the prospect the AI would take for a team
+    pub fn get_ai_choice(&self, team: &Team, mut rng: &mut impl Rng) -> Option<usize> {
+        let intelligence: f64 = team.coach.intelligence.min(10) as f64 / 10_f64;
+        let needs: Vec<(Position, f64)> = get_team_needs(team);
+        let dst_misjudgment: Normal = Normal::new(0_f64, (MISJUDGMENT_SPREAD * (1_f64 - intelligence)).max(f64::MIN_POSITIVE)).unwrap();
+        let mut best_choice: Option<(usize, f64)> = None;
+        for (index, prospect) in self.prospects.iter().enumerate() {
+            let need: f64 = needs.iter()
+                .find(|(position, _)| *position == prospect.player.position)
+                .map(|(_, need)| *need)
+                .unwrap_or(0_f64);
+            let value: f64 = prospect.get_projected_overall()
+                + dst_misjudgment.sample(&mut rng)
+                + NEED_WEIGHT * intelligence * need;
+            let is_best: bool = match best_choice {
+                Some((_, best_value)) => value > best_value,
+                None => true
+            };
+            if is_best {
+                best_choice = Some((index, value));
+            }
+        }
+        best_choice.map(|(index, _)| index)
+    }
+
+    /// Make the current pick with the AI
+    pub fn make_ai_pick(&mut self, league: &mut League, rng: &mut impl Rng) -> Result<&DraftPick, LeagueError> {
+        let team_abbreviation: String = match self.get_current_pick() {
+            Some(pick) => pick.team.clone(),
+            None => return Err(LeagueError::DraftError(String::from("Cannot make pick: Draft is over")))
+        };
+        let team: &Team = match league.get_team(&team_abbreviation) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(team_abbreviation))
+        };
+        let prospect_index: usize = match self.get_ai_choice(team, rng) {
+            Some(prospect_index) => prospect_index,
+            None => return Err(LeagueError::DraftError(String::from("Cannot make pick: No prospects left")))
+        };
+        self.make_pick(league, prospect_index)
+    }
+
+    /// Make AI picks until the draft is over or a manually controlled team
+    /// is on the clock
+    pub fn simulate(&mut self, league: &mut League, manual_teams: &[&str], rng: &mut impl Rng) -> Result<(), LeagueError> {
+        while let Some(pick) = self.get_current_pick() {
+            if manual_teams.contains(&pick.team.as_str()) {
+                break;
+            }
+            self.make_ai_pick(league, rng)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Draft {
+    /// Format a `Draft` as its picks
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pick_strs: Vec<String> = self.picks.iter().map(|pick| format!("{}", pick)).collect();
+        f.write_str(&pick_strs.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Generate a league of two teams and a two round draft between them
+    fn create_draft() -> (League, Draft) {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for abbreviation in ["A", "B"].iter() {
+            let team = generate_test_team(abbreviation, abbreviation, &mut rng);
+            league.add_team(team, "Conference", "Division").unwrap();
+        }
+        let generator: PlayerGenerator = PlayerGenerator::new(5_f64, 1_f64);
+        let prospects: Vec<Prospect> = Draft::generate_prospects(8, &generator, 0.5_f64, &BTreeSet::new(), &mut rng);
+        let order: Vec<String> = vec![String::from("A"), String::from("B")];
+        (league, Draft::new(&order, 2, prospects))
+    }
+
+    #[test]
+    fn test_trade_picks_swaps_teams() {
+        let (_, mut draft) = create_draft();
+        draft.trade_picks("A", &[1], "B", &[2, 4]).unwrap();
+        assert_eq!(draft.picks[0].team, "B");
+        assert_eq!(draft.picks[1].team, "A");
+        assert_eq!(draft.picks[3].team, "A");
+        assert_eq!(draft.picks[0].original_team, "A");
+        assert_eq!(draft.get_team_picks("A").len(), 3);
+    }
+
+    #[test]
+    fn test_trade_picks_errors_leave_picks_unchanged() {
+        let (mut league, mut draft) = create_draft();
+
+        // A pick which does not exist, including pick zero
+        assert!(matches!(draft.trade_picks("A", &[5], "B", &[2]), Err(LeagueError::DraftError(_))));
+        assert!(matches!(draft.trade_picks("A", &[0], "B", &[2]), Err(LeagueError::DraftError(_))));
+
+        // A pick which belongs to the other team
+        assert!(matches!(draft.trade_picks("A", &[1], "B", &[3]), Err(LeagueError::DraftError(_))));
+
+        // A pick which has already been made
+        draft.make_pick(&mut league, 0).unwrap();
+        assert!(matches!(draft.trade_picks("A", &[1], "B", &[2]), Err(LeagueError::DraftError(_))));
+
+        // No failed trade moved any pick
+        for pick in draft.picks.iter() {
+            assert_eq!(pick.team, pick.original_team);
+        }
+    }
+
+    #[test]
+    fn test_make_pick_signs_rookie_contract() {
+        let (mut league, mut draft) = create_draft();
+        let roster_size: usize = league.get_team("A").unwrap().get_roster().len();
+        let name: String = draft.prospects[0].player.name.clone();
+        let pick: DraftPick = draft.make_pick(&mut league, 0).unwrap().clone();
+
+        // The player joins the picking team on the first pick's rookie deal
+        let player: Player = pick.player.unwrap();
+        assert_eq!(player.name, name);
+        assert_eq!(pick.team, "A");
+        assert_eq!(player.contract, Some(Contract::rookie(1, 4)));
+        let roster: Vec<Player> = league.get_team("A").unwrap().get_roster();
+        assert_eq!(roster.len(), roster_size + 1);
+        let signed: &Player = roster.iter().find(|signed| signed.name == name).unwrap();
+        assert_eq!(signed.contract, Some(Contract::rookie(1, 4)));
+        assert_eq!(draft.prospects.len(), 7);
+        assert_eq!(draft.get_current_pick().unwrap().number, 2);
+    }
+
+    #[test]
+    fn test_make_pick_errors() {
+        let (mut league, mut draft) = create_draft();
+        assert!(matches!(draft.make_pick(&mut league, 8), Err(LeagueError::DraftError(_))));
+        for _ in 0..4 {
+            draft.make_pick(&mut league, 0).unwrap();
+        }
+        assert!(draft.is_over());
+        assert!(matches!(draft.make_pick(&mut league, 0), Err(LeagueError::DraftError(_))));
+    }
+}
@@ -0,0 +1,441 @@
+use crate::league::draft::{Draft, Prospect};
+use crate::league::freeagency::FreeAgency;
+use crate::league::league::{League, LeagueError};
+use crate::league::playoffs::{PlayoffFormat, Playoffs};
+use crate::league::ratings::{EloRatings, SEASON_REGRESSION};
+use crate::league::schedule::{Schedule, ScheduleFormat};
+use crate::league::season::Season;
+use crate::league::standings::Standings;
+use crate::team::contract::{Contract, MIN_SALARY};
+use crate::team::generator::{PlayerGenerator, ROSTER_SIZES};
+use crate::team::player::Player;
+use crate::team::progression::{get_projected_overall, is_retiring, progress_player};
+use crate::team::team::Team;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The number of years on an undrafted rookie's contract
+const UNDRAFTED_CONTRACT_YEARS: usize = 3;
+
+/// The share of the salary cap teams fill before they stop re-signing
+/// players or bidding on free agents, leaving the rest for rookies
+const RE_SIGNING_CAP_FRACTION: f64 = 0.9;
+
+/// # OffseasonReport struct
+///
+/// An `OffseasonReport` lists the roster moves made during an offseason,
+/// each alongside the abbreviation of the team which made it.  Players
+/// who left are listed as they were when they left, and the others as
+/// they were when they signed.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OffseasonReport {
+    /// Players who retired
+    pub retired: Vec<(String, Player)>,
+    /// Players re-signed when their contracts ran out
+    pub re_signed: Vec<(String, Player)>,
+    /// Players who left when their contracts ran out
+    pub expired: Vec<(String, Player)>,
+    /// Rookies who signed, drafted or not
+    pub rookies: Vec<(String, Player)>,
+    /// Players released to make room or to get under the salary cap
+    pub released: Vec<(String, Player)>,
+    /// The offseason's draft
+    pub draft: Draft,
+    /// The offseason's free agency
+    pub free_agency: FreeAgency
+}
+
+impl OffseasonReport {
+    /// Initialize an empty offseason report
+    pub fn new() -> OffseasonReport {
+        OffseasonReport::default()
+    }
+}
+
+impl fmt::Display for OffseasonReport {
+    /// Format an `OffseasonReport` as its roster moves
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut report_str: String = String::new();
+        for (label, moves) in [("Retired", &self.retired), ("Re-signed", &self.re_signed), ("Expired", &self.expired), ("Rookies", &self.rookies), ("Released", &self.released)] {
+            report_str += &format!("{} ({})", label, moves.len());
+            for (team, player) in moves.iter() {
+                report_str += &format!("\n  {} {}, age {}", team, player, player.age);
+            }
+            report_str += "\n";
+        }
+        report_str += &format!("Free Agency {}", self.free_agency);
+        f.write_str(report_str.trim_end())
+    }
+}
+
+/// # FranchiseSeason struct
+///
+/// A `FranchiseSeason` records one season of a franchise: the regular
+/// season, the playoffs, and the offseason which followed
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FranchiseSeason {
+    pub season: Season,
+    pub playoffs: Playoffs,
+    pub offseason: OffseasonReport
+}
+
+impl FranchiseSeason {
+    /// Get the Elo ratings to start the next season from, updated for the
+    /// playoffs and pulled part of the way back to the league average
+    pub fn get_closing_ratings(&self) -> EloRatings {
+        let mut ratings: EloRatings = self.season.ratings.clone();
+        for bracket in self.playoffs.conference_brackets.iter().chain([&self.playoffs.final_bracket]) {
+            for game in bracket.rounds.iter().flat_map(|round| round.games.iter()) {
+                ratings.add_game(&game.home_team, &game.away_team, game.home_score, game.away_score, game.neutral_site);
+            }
+        }
+        ratings.regress_to_mean(SEASON_REGRESSION);
+        ratings
+    }
+}
+
+/// # Franchise struct
+///
+/// A `Franchise` simulates a league over many seasons, each made up of a
+/// regular season, the playoffs and an offseason.  Every season is seeded
+/// from the franchise's seed, so a franchise can be reproduced from it.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Franchise {
+    pub league: League,
+    pub schedule_format: ScheduleFormat,
+    pub playoff_format: PlayoffFormat,
+    /// The number of rounds in each draft
+    pub num_rookies: usize,
+    /// The overall prospects are generated around, below the typical
+    /// veteran, as they grow into their prime
+    pub rookie_overall: f64,
+    /// The standard deviation of prospect overalls
+    pub rookie_spread: f64,
+    /// The standard deviation of the error in each prospect's scouted
+    /// overall
+    pub scouting_error: f64,
+    pub seasons: Vec<FranchiseSeason>,
+    /// The seed each season is seeded from, offset by the season number
+    pub seed: u64
+}
+
+impl Franchise {
+    /// Initialize a new franchise given its league, formats and seed,
+    /// validating the league and playoff format.  Teams over the salary
+    /// cap release players until they are under it.
+    pub fn new(league: League, schedule_format: ScheduleFormat, playoff_format: PlayoffFormat, seed: u64) -> Result<Franchise, LeagueError> {
+        league.validate()?;
+        playoff_format.validate(&league)?;
+        let mut franchise: Franchise = Franchise {
+            league,
+            schedule_format,
+            playoff_format,
+            num_rookies: 7_usize,
+            rookie_overall: 4_f64,
+            rookie_spread: 1.5_f64,
+            scouting_error: 1_f64,
+            seasons: Vec::new(),
+            seed
+        };
+        let generator: PlayerGenerator = PlayerGenerator::new(franchise.rookie_overall, franchise.rookie_spread);
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut report: OffseasonReport = OffseasonReport::new();
+        for team in franchise.league.teams.iter_mut() {
+            fit_under_salary_cap(team, franchise.league.salary_cap, &generator, &mut report, &mut rng);
+        }
+        franchise.league.validate_salary_cap()?;
+        Ok(franchise)
+    }
+
+    /// Get the number of seasons played
+    pub fn get_num_seasons(&self) -> usize {
+        self.seasons.len()
+    }
+
+    /// Simulate a full season followed by its offseason, returning the
+    /// finished season.  The season starts from the previous season's Elo
+    /// ratings, regressed towards the league average, and players injured
+    /// in the regular season miss their team's next games.
+    pub fn simulate_season(&mut self) -> Result<&FranchiseSeason, LeagueError> {
+        let season_number: usize = self.seasons.len();
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(self.seed.wrapping_add(season_number as u64));
+
+        // Check every team is under the cap before the season begins
+        self.league.validate_salary_cap()?;
+
+        // Rotate the divisions faced each season
+        let schedule_format: ScheduleFormat = match self.schedule_format {
+            ScheduleFormat::Divisional { num_byes, .. } => ScheduleFormat::Divisional { rotation: season_number, num_byes },
+            schedule_format => schedule_format
+        };
+        let schedule: Schedule = Schedule::generate(&self.league, schedule_format, &mut rng)?;
+
+        // Play the regular season, playoffs and offseason
+        let mut season: Season = Season::new(&self.league, schedule, rng.gen());
+        if let Some(previous) = self.seasons.last() {
+            season.set_ratings(previous.get_closing_ratings());
+        }
+        season.simulate_with_injuries(&mut self.league)?;
+        let playoffs: Playoffs = Playoffs::simulate(&self.league, &season.standings, &self.playoff_format, &mut rng)?;
+        let offseason: OffseasonReport = self.simulate_offseason(&season.standings, &playoffs, &mut rng)?;
+        self.seasons.push(FranchiseSeason {
+            season,
+            playoffs,
+            offseason
+        });
+        Ok(self.seasons.last().unwrap())
+    }
+
+    /// Simulate a number of seasons
+    pub fn simulate(&mut self, num_seasons: usize) -> Result<(), LeagueError> {
+        for _ in 0..num_seasons {
+            self.simulate_season()?;
+        }
+        Ok(())
+    }
+
+    /// Simulate an offseason.  Every injury heals, veterans retire and the
+    /// remaining players age along the aging curve for their position.
+    /// The best players whose contracts expired are re-signed at their
+    /// market value while they fit under most of the salary cap.  The AI
+    /// then drafts for every team, and teams sign undrafted rookies to
+    /// refill any position.  Teams release the players they expect to be
+    /// worst at any position with more players than a full roster
+    /// carries, then their most overpaid players until they are under the
+    /// cap.  Finally, the players who left or were released enter free
+    /// agency, where the AI teams bid on those who improve their rosters.
+    pub fn simulate_offseason(&mut self, standings: &Standings, playoffs: &Playoffs, mut rng: &mut impl Rng) -> Result<OffseasonReport, LeagueError> {
+        let mut report: OffseasonReport = OffseasonReport::new();
+        for team in self.league.teams.iter_mut() {
+            // Heal every injury, retire veterans and age everyone else,
+            // starting a new year of their contracts
+            let mut roster: Vec<Player> = Vec::new();
+            let mut expiring: Vec<Player> = Vec::new();
+            for mut player in team.get_roster() {
+                player.injury = None;
+                if is_retiring(&player, &mut rng) {
+                    report.retired.push((team.abbreviation.clone(), player));
+                    continue;
+                }
+                progress_player(&mut player, &mut rng);
+                if let Some(contract) = player.contract.as_mut() {
+                    contract.advance_year();
+                    if contract.is_expired() {
+                        expiring.push(player);
+                        continue;
+                    }
+                }
+                roster.push(player);
+            }
+
+            // Re-sign the best players whose contracts expired at their
+            // market value, leaving room under the cap for rookies
+            let re_signing_budget: u64 = (self.league.salary_cap as f64 * RE_SIGNING_CAP_FRACTION) as u64;
+            let mut cap_hit: u64 = roster.iter().map(|player| player.get_cap_hit()).sum();
+            expiring.sort_by(|a, b| get_projected_overall(b).total_cmp(&get_projected_overall(a)));
+            for mut player in expiring {
+                let contract: Contract = Contract::from_market_value(&player);
+                if cap_hit + contract.get_cap_hit() <= re_signing_budget {
+                    cap_hit += contract.get_cap_hit();
+                    player.contract = Some(contract);
+                    report.re_signed.push((team.abbreviation.clone(), player.clone()));
+                    roster.push(player);
+                } else {
+                    player.contract = None;
+                    report.expired.push((team.abbreviation.clone(), player));
+                }
+            }
+            team.dead_money = 0;
+            team.set_roster(roster);
+        }
+
+        // Hold the draft, with enough prospects left over to fill the
+        // empty spots on every roster
+        let generator: PlayerGenerator = PlayerGenerator::new(self.rookie_overall, self.rookie_spread);
+        let order: Vec<String> = Draft::get_draft_order(&self.league, standings, Some(playoffs), &mut rng);
+        let num_prospects: usize = self.league.teams.len() * self.num_rookies * 3 / 2;
+        let league_names: BTreeSet<String> = self.league.teams.iter()
+            .flat_map(|team| team.get_roster().into_iter().map(|player| player.name))
+            .collect();
+        let prospects: Vec<Prospect> = Draft::generate_prospects(num_prospects, &generator, self.scouting_error, &league_names, &mut rng);
+        let mut draft: Draft = Draft::new(&order, self.num_rookies, prospects);
+        draft.simulate(&mut self.league, &[], &mut rng)?;
+        for pick in draft.picks.iter() {
+            if let Some(player) = &pick.player {
+                report.rookies.push((pick.team.clone(), player.clone()));
+            }
+        }
+
+        for team in self.league.teams.iter_mut() {
+            // Sign the best undrafted rookies at each position with open
+            // spots, generating more if the prospects run out
+            let mut roster: Vec<Player> = team.get_roster();
+            for (position, count) in ROSTER_SIZES.iter() {
+                let num_players: usize = roster.iter().filter(|player| player.position == *position).count();
+                for _ in num_players..*count {
+                    let mut rookie: Player = match draft.prospects.iter().position(|prospect| prospect.player.position == *position) {
+                        Some(index) => draft.prospects.remove(index).player,
+                        None => {
+                            let names: BTreeSet<String> = roster.iter().map(|player| player.name.clone()).collect();
+                            generator.generate_unique_rookie(*position, &names, &mut rng)
+                        }
+                    };
+                    rookie.contract = Some(Contract::minimum(UNDRAFTED_CONTRACT_YEARS));
+                    report.rookies.push((team.abbreviation.clone(), rookie.clone()));
+                    roster.push(rookie);
+                }
+            }
+
+            // Release the players expected to be worst next season at any
+            // position which is over its roster size
+            roster.sort_by(|a, b| get_projected_overall(b).total_cmp(&get_projected_overall(a)));
+            let mut kept: Vec<Player> = Vec::with_capacity(roster.len());
+            for player in roster {
+                let roster_size: usize = ROSTER_SIZES.iter()
+                    .find(|(position, _)| *position == player.position)
+                    .map(|(_, count)| *count)
+                    .unwrap_or(0);
+                let num_kept: usize = kept.iter().filter(|kept_player| kept_player.position == player.position).count();
+                if num_kept < roster_size {
+                    kept.push(player);
+                } else {
+                    team.dead_money += player.get_dead_money();
+                    report.released.push((team.abbreviation.clone(), player));
+                }
+            }
+            team.set_roster(kept);
+            fit_under_salary_cap(team, self.league.salary_cap, &generator, &mut report, &mut rng);
+        }
+        report.draft = draft;
+
+        // Hold free agency for the players who left or were released
+        let free_agents: Vec<Player> = report.expired.iter().chain(report.released.iter())
+            .map(|(_, player)| {
+                let mut player: Player = player.clone();
+                player.contract = None;
+                player
+            })
+            .collect();
+        let mut free_agency: FreeAgency = FreeAgency::new(free_agents);
+        free_agency.cap_reserve = (self.league.salary_cap as f64 * (1_f64 - RE_SIGNING_CAP_FRACTION)) as u64;
+        free_agency.simulate(&mut self.league, &[], &mut rng)?;
+        report.free_agency = free_agency;
+        self.league.validate()?;
+        Ok(report)
+    }
+
+    /// Get the champion of each season played
+    pub fn get_champions(&self) -> Vec<&str> {
+        self.seasons.iter().map(|season| season.playoffs.champion.as_str()).collect()
+    }
+}
+
+impl fmt::Display for Franchise {
+    /// Format a `Franchise` as its champion each season
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut franchise_str: String = format!("{}, {} seasons played, seed {}", self.league.name, self.seasons.len(), self.seed);
+        for (index, champion) in self.get_champions().iter().enumerate() {
+            franchise_str += &format!("\nSeason {}: {}", index + 1, champion);
+        }
+        f.write_str(&franchise_str)
+    }
+}
+
+/// Release a team's most overpaid players until the team is under the
+/// salary cap, replacing each with a minimum salary rookie.  A player is
+/// only released if doing so saves more than their replacement costs, and
+/// the player saving the most beyond their market value goes first.
+fn fit_under_salary_cap(team: &mut Team, salary_cap: u64, generator: &PlayerGenerator, report: &mut OffseasonReport, mut rng: &mut impl Rng) {
+    while team.get_cap_hit() > salary_cap {
+        let roster: Vec<Player> = team.get_roster();
+        let candidate: Option<&Player> = roster.iter()
+            .filter(|player| player.get_cap_hit() > player.get_dead_money() + MIN_SALARY)
+            .max_by_key(|player| (player.get_cap_hit() - player.get_dead_money()) as i64 - Contract::get_market_salary(player) as i64);
+        let (name, position) = match candidate {
+            Some(player) => (player.name.clone(), player.position),
+            None => break
+        };
+        if let Ok(player) = team.release_player(&name, position) {
+            report.released.push((team.abbreviation.clone(), player));
+        }
+        let names: BTreeSet<String> = team.get_roster().into_iter().map(|player| player.name).collect();
+        let mut rookie: Player = generator.generate_unique_rookie(position, &names, &mut rng);
+        rookie.contract = Some(Contract::minimum(UNDRAFTED_CONTRACT_YEARS));
+        report.rookies.push((team.abbreviation.clone(), rookie.clone()));
+        team.sign_player(rookie);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+
+    /// Generate a league of eight teams in two divisions of one conference
+    fn generate_league() -> League {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for (index, abbreviation) in ["A", "B", "C", "D", "E", "F", "G", "H"].iter().enumerate() {
+            let team = generate_test_team(abbreviation, abbreviation, &mut rng);
+            league.add_team(team, "Conference", if index < 4 { "North" } else { "South" }).unwrap();
+        }
+        league
+    }
+
+    /// Start a franchise with a short season and a four team playoff
+    fn create_franchise(seed: u64) -> Franchise {
+        let format: PlayoffFormat = PlayoffFormat {
+            num_teams: 4,
+            num_byes: 0,
+            ..Default::default()
+        };
+        Franchise::new(generate_league(), ScheduleFormat::RoundRobin { num_weeks: 7 }, format, seed).unwrap()
+    }
+
+    #[test]
+    fn test_offseason_ages_players_and_refills_rosters() {
+        let mut franchise: Franchise = create_franchise(0);
+        let before: Vec<Player> = franchise.league.teams[0].get_roster();
+        franchise.simulate_season().unwrap();
+        let after: Vec<Player> = franchise.league.teams[0].get_roster();
+
+        // Players still on the team are healed and a year older, and every
+        // roster is full, valid and under the cap
+        for player in after.iter() {
+            if let Some(previous) = before.iter().find(|previous| previous.name == player.name && previous.position == player.position) {
+                assert_eq!(player.age, previous.age + 1);
+            }
+            assert!(player.injury.is_none());
+        }
+        for team in franchise.league.teams.iter() {
+            assert!(team.validate().is_ok());
+            for (position, count) in ROSTER_SIZES.iter() {
+                assert_eq!(team.get_roster().iter().filter(|player| player.position == *position).count(), *count);
+            }
+        }
+        assert!(franchise.league.validate_salary_cap().is_ok());
+        let report: &OffseasonReport = &franchise.seasons[0].offseason;
+        assert!(!report.rookies.is_empty());
+        assert_eq!(report.draft.picks.len(), 8 * franchise.num_rookies);
+    }
+
+    #[test]
+    fn test_franchise_is_reproducible_from_its_seed() {
+        let mut franchise: Franchise = create_franchise(3);
+        let mut other: Franchise = create_franchise(3);
+        franchise.simulate(2).unwrap();
+        other.simulate(2).unwrap();
+        assert_eq!(franchise.get_num_seasons(), 2);
+        assert_eq!(franchise.get_champions(), other.get_champions());
+        let names = |franchise: &Franchise| franchise.league.teams[0].get_roster().into_iter().map(|player| player.name).collect::<Vec<String>>();
+        assert_eq!(names(&franchise), names(&other));
+    }
+}
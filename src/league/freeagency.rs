@@ -0,0 +1,220 @@
+use crate::league::draft::get_team_needs;
+use crate::league::league::{League, LeagueError};
+use crate::team::contract::Contract;
+use crate::team::generator::ROSTER_SIZES;
+use crate::team::player::Player;
+use crate::team::position::Position;
+use crate::team::progression::get_projected_overall;
+use crate::team::team::Team;
+
+use std::fmt;
+use rand::Rng;
+use rand::prelude::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// How much more than a player's asking salary a team bids for each point
+/// of need at the player's position
+const NEED_BID_WEIGHT: f64 = 0.25_f64;
+
+/// Get the average salary per season of a contract, bonus included
+fn get_average_salary(contract: &Contract) -> u64 {
+    contract.get_total_value() / contract.length.max(1) as u64
+}
+
+/// Scale the salary, bonus and guarantees of a contract
+fn scale_contract(contract: &Contract, scale: f64) -> Contract {
+    Contract::new(
+        contract.length,
+        (contract.salary as f64 * scale) as u64,
+        (contract.signing_bonus as f64 * scale) as u64,
+        (contract.guaranteed_salary as f64 * scale) as u64
+    )
+}
+
+/// Sign a free agent to a copy of a team, releasing the team's worst
+/// player at the position if it is over its roster size, then validate
+/// the team's roster and salary cap.  Returns the updated team and any
+/// released player.
+fn sign_free_agent(team: &Team, mut player: Player, contract: Contract, salary_cap: u64) -> Result<(Team, Option<Player>), LeagueError> {
+    let mut team: Team = team.clone();
+    let position: Position = player.position;
+    let name: String = player.name.clone();
+    let age: usize = player.age;
+    player.contract = Some(contract);
+    team.sign_player(player);
+    let roster_size: usize = ROSTER_SIZES.iter()
+        .find(|(roster_position, _)| *roster_position == position)
+        .map(|(_, count)| *count)
+        .unwrap_or(0);
+    let mut released: Option<Player> = None;
+    let mut players: Vec<Player> = team.get_roster().into_iter().filter(|player| player.position == position).collect();
+    if players.len() > roster_size {
+        players.sort_by(|a, b| get_projected_overall(a).total_cmp(&get_projected_overall(b)));
+        if players[0].name == name && players[0].age == age {
+            return Err(LeagueError::TransactionError(format!("{} would not make the {} roster", name, team.name)));
+        }
+        match team.release_player(&players[0].name, position) {
+            Ok(player) => released = Some(player),
+            Err(err) => return Err(LeagueError::TransactionError(err.to_string()))
+        }
+    }
+    if let Err(err) = team.validate() {
+        return Err(LeagueError::TransactionError(err.to_string()));
+    }
+    if let Err(err) = team.validate_salary_cap(salary_cap) {
+        return Err(LeagueError::TransactionError(err.to_string()));
+    }
+    Ok((team, released))
+}
+
+/// # FreeAgency struct
+///
+/// `FreeAgency` holds the pool of players without a contract and the
+/// signings made from it.  Each free agent asks for their market value,
+/// and signs with whichever team bids the most per season.  AI teams bid
+/// on players who would improve on the worst starter at their position, or
+/// fill an open spot, paying more at positions of need, as long as the bid
+/// leaves `cap_reserve` of room under the salary cap.  Teams over their
+/// roster size at a position after signing a player release their worst
+/// player there, who joins the pool.  Every signing is validated against
+/// the team's roster and the salary cap before it goes through.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FreeAgency {
+    pub free_agents: Vec<Player>,
+    pub signings: Vec<(String, Player)>,
+    pub cap_reserve: u64
+}
+
+impl FreeAgency {
+    /// Initialize a new free agency given the pool of free agents, best
+    /// player first
+    pub fn new(mut free_agents: Vec<Player>) -> FreeAgency {
+        free_agents.sort_by(|a, b| get_projected_overall(b).total_cmp(&get_projected_overall(a)));
+        FreeAgency {
+            free_agents,
+            signings: Vec::new(),
+            cap_reserve: 0
+        }
+    }
+
+    /// Get the contract a free agent asks for
+    pub fn get_asking_contract(player: &Player) -> Contract {
+        Contract::from_market_value(player)
+    }
+
+    /// Offer a free agent a contract on behalf of a team.  The player signs
+    /// if the offer pays at least their asking salary per season and the
+    /// team's roster and cap remain valid.  Returns whether they signed.
+    pub fn make_offer(&mut self, league: &mut League, abbreviation: &str, free_agent_index: usize, contract: Contract) -> Result<bool, LeagueError> {
+        let player: &Player = match self.free_agents.get(free_agent_index) {
+            Some(player) => player,
+            None => return Err(LeagueError::TransactionError(format!("No free agent at index {}", free_agent_index)))
+        };
+        if get_average_salary(&contract) < get_average_salary(&FreeAgency::get_asking_contract(player)) {
+            return Ok(false);
+        }
+        let team: &Team = match league.get_team(abbreviation) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(String::from(abbreviation)))
+        };
+        let (team, released) = sign_free_agent(team, player.clone(), contract.clone(), league.salary_cap)?;
+        self.complete_signing(league, team, free_agent_index, contract, released);
+        Ok(true)
+    }
+
+    /// Get the contract an AI team would bid for a free agent, if any
+    pub fn get_ai_bid(&self, team: &Team, salary_cap: u64, player: &Player) -> Option<Contract> {
+        // Check whether the player would start or fill an open spot
+        let roster_size: usize = ROSTER_SIZES.iter()
+            .find(|(position, _)| *position == player.position)
+            .map(|(_, count)| *count)
+            .unwrap_or(0);
+        let mut overalls: Vec<f64> = team.get_roster().iter()
+            .filter(|rostered| rostered.position == player.position)
+            .map(get_projected_overall)
+            .collect();
+        overalls.sort_by(|a, b| b.total_cmp(a));
+        let is_upgrade: bool = overalls.len() < roster_size || overalls.iter().any(|overall| get_projected_overall(player) > *overall);
+        if !is_upgrade {
+            return None;
+        }
+
+        // Bid more at positions of need, as far as the cap allows
+        let need: f64 = get_team_needs(team).iter()
+            .find(|(position, _)| *position == player.position)
+            .map(|(_, need)| *need)
+            .unwrap_or(0_f64);
+        let asking: Contract = FreeAgency::get_asking_contract(player);
+        let cap_space: i64 = team.get_cap_space(salary_cap) - self.cap_reserve as i64;
+        let bid: Contract = scale_contract(&asking, 1_f64 + NEED_BID_WEIGHT * need);
+        if (bid.get_cap_hit() as i64) <= cap_space {
+            Some(bid)
+        } else if (asking.get_cap_hit() as i64) <= cap_space {
+            Some(asking)
+        } else {
+            None
+        }
+    }
+
+    /// Run free agency for the AI teams.  Free agents are taken best
+    /// first, and each signs with the AI team bidding the most per season.
+    /// Manually controlled teams do not bid.
+    pub fn simulate(&mut self, league: &mut League, manual_teams: &[&str], rng: &mut impl Rng) -> Result<(), LeagueError> {
+        let mut index: usize = 0;
+        while index < self.free_agents.len() {
+            // Collect the AI teams' bids, shuffled to break ties at random
+            let player: Player = self.free_agents[index].clone();
+            let mut bids: Vec<(usize, Contract)> = league.teams.iter().enumerate()
+                .filter(|(_, team)| !manual_teams.contains(&team.abbreviation.as_str()))
+                .filter_map(|(team_index, team)| self.get_ai_bid(team, league.salary_cap, &player).map(|bid| (team_index, bid)))
+                .collect();
+            bids.shuffle(rng);
+            bids.sort_by_key(|(_, bid)| std::cmp::Reverse(get_average_salary(bid)));
+
+            // Sign with the best bidder whose roster and cap allow it
+            let mut signed: bool = false;
+            for (team_index, bid) in bids {
+                if let Ok((team, released)) = sign_free_agent(&league.teams[team_index], player.clone(), bid.clone(), league.salary_cap) {
+                    self.complete_signing(league, team, index, bid, released);
+                    signed = true;
+                    break;
+                }
+            }
+            if !signed {
+                index += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Record a signing, replacing the team in the league with its signed
+    /// copy and moving any released player into the pool
+    fn complete_signing(&mut self, league: &mut League, team: Team, free_agent_index: usize, contract: Contract, released: Option<Player>) {
+        let mut player: Player = self.free_agents.remove(free_agent_index);
+        player.contract = Some(contract);
+        self.signings.push((team.abbreviation.clone(), player));
+        if let Some(released) = released {
+            self.free_agents.push(released);
+        }
+        if let Some(league_team) = league.get_team_mut(&team.abbreviation) {
+            *league_team = team;
+        }
+    }
+}
+
+impl fmt::Display for FreeAgency {
+    /// Format a `FreeAgency` as its signings
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut free_agency_str: String = format!("Signings ({})", self.signings.len());
+        for (team, player) in self.signings.iter() {
+            free_agency_str += &format!("\n  {} {}", team, player);
+            if let Some(contract) = &player.contract {
+                free_agency_str += &format!(", {}", contract);
+            }
+        }
+        free_agency_str += &format!("\nUnsigned ({})", self.free_agents.len());
+        f.write_str(&free_agency_str)
+    }
+}
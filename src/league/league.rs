@@ -0,0 +1,298 @@
+use crate::game::game::Game;
+use crate::game::homefield::HomeFieldAdvantage;
+use crate::game::injury::PlayerInjury;
+use crate::league::schedule::Matchup;
+use crate::team::contract::DEFAULT_SALARY_CAP;
+use crate::team::roster::RosterError;
+use crate::team::team::Team;
+
+use std::collections::BTreeSet;
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # Division struct
+///
+/// A `Division` is a group of teams within a conference, listed by
+/// abbreviation
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Division {
+    pub name: String,
+    pub teams: Vec<String>
+}
+
+impl Division {
+    /// Initialize a new empty division
+    pub fn new(name: &str) -> Division {
+        Division {
+            name: String::from(name),
+            teams: Vec::new()
+        }
+    }
+}
+
+/// # Conference struct
+///
+/// A `Conference` is a group of divisions within a league
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Conference {
+    pub name: String,
+    pub divisions: Vec<Division>
+}
+
+impl Conference {
+    /// Initialize a new empty conference
+    pub fn new(name: &str) -> Conference {
+        Conference {
+            name: String::from(name),
+            divisions: Vec::new()
+        }
+    }
+
+    /// Get the abbreviations of every team in the conference
+    pub fn get_teams(&self) -> Vec<&str> {
+        self.divisions.iter()
+            .flat_map(|division| division.teams.iter().map(|team| team.as_str()))
+            .collect()
+    }
+}
+
+/// # League struct
+///
+/// A `League` owns its teams and groups them into conferences and
+/// divisions.  Teams are referred to by abbreviation throughout the league
+/// module, so abbreviations must be unique.  Every team's cap hit must fit
+/// under the league's salary cap before a season begins.  The league's
+/// home field advantage applies to every game between its teams.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct League {
+    pub name: String,
+    pub teams: Vec<Team>,
+    pub conferences: Vec<Conference>,
+    pub salary_cap: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub home_field: HomeFieldAdvantage
+}
+
+impl League {
+    /// Initialize a new empty league
+    pub fn new(name: &str) -> League {
+        League {
+            name: String::from(name),
+            teams: Vec::new(),
+            conferences: Vec::new(),
+            salary_cap: DEFAULT_SALARY_CAP,
+            home_field: HomeFieldAdvantage::default()
+        }
+    }
+
+    /// Add a team to a division, creating the conference and division if
+    /// they do not exist yet
+    pub fn add_team(&mut self, team: Team, conference_name: &str, division_name: &str) -> Result<(), LeagueError> {
+        if self.get_team(&team.abbreviation).is_some() {
+            return Err(LeagueError::ValidationError(format!("Cannot add team: Abbreviation {} is already in the league", team.abbreviation)));
+        }
+
+        // Find or create the conference and division
+        let conference: &mut Conference = match self.conferences.iter().position(|c| c.name == conference_name) {
+            Some(index) => &mut self.conferences[index],
+            None => {
+                self.conferences.push(Conference::new(conference_name));
+                self.conferences.last_mut().unwrap()
+            }
+        };
+        let division: &mut Division = match conference.divisions.iter().position(|d| d.name == division_name) {
+            Some(index) => &mut conference.divisions[index],
+            None => {
+                conference.divisions.push(Division::new(division_name));
+                conference.divisions.last_mut().unwrap()
+            }
+        };
+        division.teams.push(team.abbreviation.clone());
+        self.teams.push(team);
+        Ok(())
+    }
+
+    /// Get a team given its abbreviation
+    pub fn get_team(&self, abbreviation: &str) -> Option<&Team> {
+        self.teams.iter().find(|team| team.abbreviation == abbreviation)
+    }
+
+    /// Get a mutable reference to a team given its abbreviation
+    pub fn get_team_mut(&mut self, abbreviation: &str) -> Option<&mut Team> {
+        self.teams.iter_mut().find(|team| team.abbreviation == abbreviation)
+    }
+
+    /// Get the indices of the conference and division a team plays in
+    pub fn get_division_index(&self, abbreviation: &str) -> Option<(usize, usize)> {
+        for (conference_index, conference) in self.conferences.iter().enumerate() {
+            for (division_index, division) in conference.divisions.iter().enumerate() {
+                if division.teams.iter().any(|team| team == abbreviation) {
+                    return Some((conference_index, division_index));
+                }
+            }
+        }
+        None
+    }
+
+    /// Get the division a team plays in
+    pub fn get_division(&self, abbreviation: &str) -> Option<&Division> {
+        let (conference_index, division_index) = self.get_division_index(abbreviation)?;
+        Some(&self.conferences[conference_index].divisions[division_index])
+    }
+
+    /// Get the conference a team plays in
+    pub fn get_conference(&self, abbreviation: &str) -> Option<&Conference> {
+        let (conference_index, _) = self.get_division_index(abbreviation)?;
+        Some(&self.conferences[conference_index])
+    }
+
+    /// Check whether two teams play in the same division
+    pub fn is_division_game(&self, team_a: &str, team_b: &str) -> bool {
+        match (self.get_division_index(team_a), self.get_division_index(team_b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => false
+        }
+    }
+
+    /// Check whether two teams play in the same conference
+    pub fn is_conference_game(&self, team_a: &str, team_b: &str) -> bool {
+        match (self.get_division_index(team_a), self.get_division_index(team_b)) {
+            (Some(a), Some(b)) => a.0 == b.0,
+            _ => false
+        }
+    }
+
+    /// Initialize a seeded game for a matchup between two of the league's
+    /// teams with the league's home field advantage
+    pub fn get_game(&self, matchup: &Matchup, seed: u64) -> Result<Game, LeagueError> {
+        let home_team: &Team = match self.get_team(&matchup.home_team) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(matchup.home_team.clone()))
+        };
+        let away_team: &Team = match self.get_team(&matchup.away_team) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(matchup.away_team.clone()))
+        };
+        let mut game: Game = Game::from_seed(home_team.clone(), away_team.clone(), seed);
+        game.set_home_field(self.home_field);
+        Ok(game)
+    }
+
+    /// Carry the injuries from a set of finished games over to the
+    /// league's teams, so that injured players miss their team's upcoming
+    /// games.  Each team which played counts a game towards its injured
+    /// players' recovery.
+    pub fn add_game_injuries(&mut self, games: &[Game]) {
+        for game in games.iter() {
+            let injuries: Vec<PlayerInjury> = game.get_injuries().into_iter().cloned().collect();
+            for abbreviation in [&game.home_team.abbreviation, &game.away_team.abbreviation] {
+                if let Some(team) = self.get_team_mut(abbreviation) {
+                    team.add_game_injuries(&injuries);
+                }
+            }
+        }
+    }
+
+    /// Validate the league, checking that abbreviations are unique and
+    /// that every team plays in exactly one division
+    pub fn validate(&self) -> Result<(), LeagueError> {
+        let mut errors: Vec<String> = Vec::new();
+        let mut abbreviations: BTreeSet<&str> = BTreeSet::new();
+        for team in self.teams.iter() {
+            if !abbreviations.insert(&team.abbreviation) {
+                errors.push(format!("abbreviation {} is used by more than one team", team.abbreviation));
+            }
+        }
+        let mut placed: BTreeSet<&str> = BTreeSet::new();
+        for conference in self.conferences.iter() {
+            for division in conference.divisions.iter() {
+                for team in division.teams.iter() {
+                    if !abbreviations.contains(team.as_str()) {
+                        errors.push(format!("{} in division {} is not a team in the league", team, division.name));
+                    }
+                    if !placed.insert(team) {
+                        errors.push(format!("{} plays in more than one division", team));
+                    }
+                }
+            }
+        }
+        for abbreviation in abbreviations.iter() {
+            if !placed.contains(abbreviation) {
+                errors.push(format!("{} does not play in a division", abbreviation));
+            }
+        }
+        if self.teams.len() < 2 {
+            errors.push(format!("a league needs at least 2 teams, found {}", self.teams.len()));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(LeagueError::ValidationError(errors.join("; ")))
+        }
+    }
+
+    /// Validate that every team's cap hit is within the salary cap
+    pub fn validate_salary_cap(&self) -> Result<(), LeagueError> {
+        let mut errors: Vec<String> = Vec::new();
+        for team in self.teams.iter() {
+            match team.validate_salary_cap(self.salary_cap) {
+                Ok(()) => (),
+                Err(RosterError::ValidationError(team_errors)) => errors.extend(team_errors),
+                Err(err) => errors.push(err.to_string())
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(LeagueError::ValidationError(errors.join("; ")))
+        }
+    }
+}
+
+impl fmt::Display for League {
+    /// Format a `League` as its conferences and divisions
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut league_str: String = self.name.clone();
+        for conference in self.conferences.iter() {
+            league_str += &format!("\n{}", conference.name);
+            for division in conference.divisions.iter() {
+                league_str += &format!("\n  {}: {}", division.name, division.teams.join(", "));
+            }
+        }
+        f.write_str(&league_str)
+    }
+}
+
+/// # LeagueError enum
+///
+/// The `LeagueError` enum represents the errors that can be generated
+/// while organizing or simulating a league
+#[derive(Debug)]
+pub enum LeagueError {
+    ValidationError(String),
+    TeamNotFound(String),
+    ScheduleError(String),
+    PlayoffError(String),
+    SimulationError(String),
+    DraftError(String),
+    TransactionError(String)
+}
+
+impl fmt::Display for LeagueError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            LeagueError::ValidationError(err) => format!("Validation Error: {}", err),
+            LeagueError::TeamNotFound(err) => format!("Team Not Found: {}", err),
+            LeagueError::ScheduleError(err) => format!("Schedule Error: {}", err),
+            LeagueError::PlayoffError(err) => format!("Playoff Error: {}", err),
+            LeagueError::SimulationError(err) => format!("Simulation Error: {}", err),
+            LeagueError::DraftError(err) => format!("Draft Error: {}", err),
+            LeagueError::TransactionError(err) => format!("Transaction Error: {}", err)
+        };
+        f.write_str(&description)
+    }
+}
@@ -0,0 +1,631 @@
+use crate::game::batch::{get_num_threads, parallel_map};
+use crate::game::game::Game;
+use crate::game::overtime::OvertimeRules;
+use crate::league::league::{League, LeagueError};
+use crate::league::schedule::Matchup;
+use crate::league::standings::Standings;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # PlayoffFormat struct
+///
+/// A `PlayoffFormat` configures the playoffs.  Each conference seeds
+/// `num_teams` teams into its own bracket, with byes for the top
+/// `num_byes` seeds, and the conference champions meet in a final
+/// bracket.  With `reseed` the best remaining seed plays the worst
+/// remaining seed each round, and otherwise the bracket is fixed.  The
+/// number of teams plus byes, and the number of conferences, must each be
+/// a power of two.  The default format follows the NFL.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayoffFormat {
+    pub num_teams: usize,
+    pub num_byes: usize,
+    pub reseed: bool,
+    pub neutral_site_final: bool,
+    pub division_winners_first: bool
+}
+
+impl Default for PlayoffFormat {
+    /// Seven teams per conference with a bye for the top seed, reseeded
+    /// each round, with division winners seeded first and a neutral site
+    /// final
+    fn default() -> PlayoffFormat {
+        PlayoffFormat {
+            num_teams: 7,
+            num_byes: 1,
+            reseed: true,
+            neutral_site_final: true,
+            division_winners_first: true
+        }
+    }
+}
+
+impl PlayoffFormat {
+    /// Validate the format against a league
+    pub fn validate(&self, league: &League) -> Result<(), LeagueError> {
+        let mut errors: Vec<String> = Vec::new();
+        if self.num_teams == 0 {
+            errors.push(String::from("at least one team per conference must make the playoffs"));
+        }
+        if !(self.num_teams + self.num_byes).is_power_of_two() {
+            errors.push(format!("{} teams and {} byes do not fill a bracket", self.num_teams, self.num_byes));
+        }
+        if self.num_byes >= self.num_teams && self.num_teams > 1 {
+            errors.push(format!("{} byes leave no games among {} teams", self.num_byes, self.num_teams));
+        }
+        if !league.conferences.len().is_power_of_two() {
+            errors.push(format!("{} conference champions do not fill a bracket", league.conferences.len()));
+        }
+        for conference in league.conferences.iter() {
+            let num_teams: usize = conference.get_teams().len();
+            if num_teams < self.num_teams {
+                errors.push(format!("conference {} has {} teams, fewer than {} playoff spots", conference.name, num_teams, self.num_teams));
+            }
+            if self.division_winners_first && conference.divisions.len() > self.num_teams {
+                errors.push(format!("conference {} has more division winners than playoff spots", conference.name));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(LeagueError::PlayoffError(errors.join("; ")))
+        }
+    }
+
+    /// Get the number of rounds in a conference bracket
+    pub fn get_num_conference_rounds(&self) -> usize {
+        (self.num_teams + self.num_byes).trailing_zeros() as usize
+    }
+}
+
+/// # PlayoffGame struct
+///
+/// A `PlayoffGame` records a playoff game between two seeds.  The better
+/// seed is the home team unless the game is at a neutral site.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayoffGame {
+    pub home_team: String,
+    pub away_team: String,
+    pub home_seed: usize,
+    pub away_seed: usize,
+    pub home_score: usize,
+    pub away_score: usize,
+    pub num_overtime_periods: usize,
+    pub neutral_site: bool
+}
+
+impl PlayoffGame {
+    /// Get the winner of the game
+    pub fn get_winner(&self) -> (&str, usize) {
+        match self.home_score >= self.away_score {
+            true => (&self.home_team, self.home_seed),
+            false => (&self.away_team, self.away_seed)
+        }
+    }
+
+    /// Check whether a team played in the game
+    pub fn involves(&self, abbreviation: &str) -> bool {
+        self.home_team == abbreviation || self.away_team == abbreviation
+    }
+}
+
+impl fmt::Display for PlayoffGame {
+    /// Format a `PlayoffGame` as seeded teams and the final score
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut game_str = format!(
+            "({}) {} {} {} ({}) {} {}",
+            self.away_seed,
+            self.away_team,
+            self.away_score,
+            match self.neutral_site {
+                true => "vs",
+                false => "@"
+            },
+            self.home_seed,
+            self.home_team,
+            self.home_score
+        );
+        match self.num_overtime_periods {
+            0 => {},
+            1 => game_str += " (OT)",
+            num_periods => game_str += &format!(" ({}OT)", num_periods)
+        }
+        f.write_str(&game_str)
+    }
+}
+
+/// # PlayoffRound struct
+///
+/// A `PlayoffRound` holds the games of a single playoff round and the
+/// teams with a bye.  Rounds are numbered from 1.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayoffRound {
+    pub round: usize,
+    pub games: Vec<PlayoffGame>,
+    pub byes: Vec<String>
+}
+
+impl PlayoffRound {
+    /// Check whether a team played in or had a bye during the round
+    pub fn involves(&self, abbreviation: &str) -> bool {
+        self.games.iter().any(|game| game.involves(abbreviation)) || self.byes.iter().any(|team| team == abbreviation)
+    }
+}
+
+impl fmt::Display for PlayoffRound {
+    /// Format a `PlayoffRound` one game per line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut round_str: String = format!("Round {}", self.round);
+        for game in self.games.iter() {
+            round_str += &format!("\n  {}", game);
+        }
+        if !self.byes.is_empty() {
+            round_str += &format!("\n  Bye: {}", self.byes.join(", "));
+        }
+        f.write_str(&round_str)
+    }
+}
+
+/// # PlayoffBracket struct
+///
+/// A `PlayoffBracket` is a single elimination bracket between seeded
+/// teams, listed best seed first
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayoffBracket {
+    pub name: String,
+    pub seeds: Vec<String>,
+    pub rounds: Vec<PlayoffRound>,
+    pub champion: String
+}
+
+impl PlayoffBracket {
+    /// Simulate a bracket between seeded teams with byes for the top seeds.
+    /// Every game is played under playoff overtime rules, and the last
+    /// game is at a neutral site if `neutral_final` is set.
+    pub fn simulate(league: &League, name: &str, seeds: Vec<String>, num_byes: usize, reseed: bool, neutral_final: bool, rng: &mut impl Rng) -> Result<PlayoffBracket, LeagueError> {
+        // Lay out the seeds in bracket order, with empty slots for byes
+        let num_slots: usize = (seeds.len() + num_byes).next_power_of_two();
+        let mut slots: Vec<Option<usize>> = get_bracket_order(num_slots).into_iter()
+            .map(|seed| match seed <= seeds.len() {
+                true => Some(seed),
+                false => None
+            })
+            .collect();
+
+        // Play each round until one team remains
+        let mut rounds: Vec<PlayoffRound> = Vec::new();
+        while slots.len() > 1 {
+            if reseed && !rounds.is_empty() {
+                let mut remaining: Vec<usize> = slots.iter().flatten().copied().collect();
+                remaining.sort();
+                slots = get_bracket_order(remaining.len()).into_iter().map(|index| Some(remaining[index - 1])).collect();
+            }
+            let is_final: bool = slots.len() == 2;
+            let mut round: PlayoffRound = PlayoffRound {
+                round: rounds.len() + 1,
+                ..Default::default()
+            };
+            let mut winners: Vec<Option<usize>> = Vec::with_capacity(slots.len() / 2);
+            for pair in slots.chunks(2) {
+                let (home_seed, away_seed) = match (pair[0], pair[1]) {
+                    (Some(a), Some(b)) => (a.min(b), a.max(b)),
+                    (Some(seed), None) | (None, Some(seed)) => {
+                        round.byes.push(seeds[seed - 1].clone());
+                        winners.push(Some(seed));
+                        continue;
+                    },
+                    (None, None) => {
+                        winners.push(None);
+                        continue;
+                    }
+                };
+                let game: PlayoffGame = simulate_game(
+                    league,
+                    &seeds[home_seed - 1],
+                    &seeds[away_seed - 1],
+                    (home_seed, away_seed),
+                    is_final && neutral_final,
+                    rng
+                )?;
+                winners.push(Some(game.get_winner().1));
+                round.games.push(game);
+            }
+            rounds.push(round);
+            slots = winners;
+        }
+        let champion: String = match slots.first().copied().flatten() {
+            Some(seed) => seeds[seed - 1].clone(),
+            None => return Err(LeagueError::PlayoffError(format!("Cannot simulate bracket {}: No teams were seeded", name)))
+        };
+        Ok(PlayoffBracket {
+            name: String::from(name),
+            seeds,
+            rounds,
+            champion
+        })
+    }
+
+    /// Get the last round a team reached in the bracket, counting a bye as
+    /// reaching the round, or None if the team was not seeded
+    pub fn get_round_reached(&self, abbreviation: &str) -> Option<usize> {
+        if !self.seeds.iter().any(|team| team == abbreviation) {
+            return None;
+        }
+        Some(self.rounds.iter()
+            .filter(|round| round.involves(abbreviation))
+            .map(|round| round.round)
+            .max()
+            .unwrap_or(1))
+    }
+}
+
+impl fmt::Display for PlayoffBracket {
+    /// Format a `PlayoffBracket` as its seeds, rounds and champion
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seed_strs: Vec<String> = self.seeds.iter().enumerate()
+            .map(|(index, team)| format!("{}. {}", index + 1, team))
+            .collect();
+        let mut bracket_str: String = format!("{}\nSeeds: {}", self.name, seed_strs.join(", "));
+        for round in self.rounds.iter() {
+            bracket_str += &format!("\n{}", round);
+        }
+        bracket_str += &format!("\nChampion: {}", self.champion);
+        f.write_str(&bracket_str)
+    }
+}
+
+/// # Playoffs struct
+///
+/// The `Playoffs` struct holds a bracket for each conference and the final
+/// bracket between the conference champions.  Rounds are numbered through
+/// the whole playoffs, so the final bracket's first round follows the last
+/// conference round.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Playoffs {
+    pub conference_brackets: Vec<PlayoffBracket>,
+    pub final_bracket: PlayoffBracket,
+    pub champion: String
+}
+
+impl Playoffs {
+    /// Seed each conference's playoff teams from the standings.  Division
+    /// winners take the top seeds if the format says so, and the remaining
+    /// spots go to the best of the rest.
+    pub fn seed(league: &League, standings: &Standings, format: &PlayoffFormat, rng: &mut impl Rng) -> Result<Vec<Vec<String>>, LeagueError> {
+        format.validate(league)?;
+        let mut conference_seeds: Vec<Vec<String>> = Vec::with_capacity(league.conferences.len());
+        for (conference_index, conference) in league.conferences.iter().enumerate() {
+            let mut seeds: Vec<String> = Vec::with_capacity(format.num_teams);
+            if format.division_winners_first {
+                let division_winners: Vec<String> = (0..conference.divisions.len())
+                    .filter_map(|division_index| standings.get_division_ranking(league, conference_index, division_index, rng).into_iter().next())
+                    .collect();
+                let division_winners: Vec<&str> = division_winners.iter().map(|team| team.as_str()).collect();
+                seeds.extend(standings.rank_teams(league, &division_winners, rng));
+            }
+            let rest: Vec<&str> = conference.get_teams().into_iter()
+                .filter(|team| !seeds.iter().any(|seed| seed == team))
+                .collect();
+            seeds.extend(standings.rank_teams(league, &rest, rng));
+            seeds.truncate(format.num_teams);
+            conference_seeds.push(seeds);
+        }
+        Ok(conference_seeds)
+    }
+
+    /// Seed and simulate the playoffs
+    pub fn simulate(league: &League, standings: &Standings, format: &PlayoffFormat, rng: &mut impl Rng) -> Result<Playoffs, LeagueError> {
+        let conference_seeds: Vec<Vec<String>> = Playoffs::seed(league, standings, format, rng)?;
+        let single_conference: bool = conference_seeds.len() == 1;
+        let mut conference_brackets: Vec<PlayoffBracket> = Vec::with_capacity(conference_seeds.len());
+        for (conference, seeds) in league.conferences.iter().zip(conference_seeds) {
+            conference_brackets.push(PlayoffBracket::simulate(
+                league,
+                &conference.name,
+                seeds,
+                format.num_byes,
+                format.reseed,
+                format.neutral_site_final && single_conference,
+                rng
+            )?);
+        }
+
+        // Seed the conference champions by their records
+        let champions: Vec<&str> = conference_brackets.iter().map(|bracket| bracket.champion.as_str()).collect();
+        let final_seeds: Vec<String> = standings.rank_teams(league, &champions, rng);
+        let final_bracket: PlayoffBracket = PlayoffBracket::simulate(league, "Final", final_seeds, 0, format.reseed, format.neutral_site_final, rng)?;
+        let champion: String = final_bracket.champion.clone();
+        Ok(Playoffs {
+            conference_brackets,
+            final_bracket,
+            champion
+        })
+    }
+
+    /// Get the number of rounds in the playoffs
+    pub fn get_num_rounds(&self) -> usize {
+        let num_conference_rounds: usize = self.conference_brackets.iter().map(|bracket| bracket.rounds.len()).max().unwrap_or(0);
+        num_conference_rounds + self.final_bracket.rounds.len()
+    }
+
+    /// Get the last round a team reached in the playoffs, or None if the
+    /// team did not make the playoffs
+    pub fn get_round_reached(&self, abbreviation: &str) -> Option<usize> {
+        let num_conference_rounds: usize = self.conference_brackets.iter().map(|bracket| bracket.rounds.len()).max().unwrap_or(0);
+        if let Some(round) = self.final_bracket.get_round_reached(abbreviation) {
+            if !self.final_bracket.rounds.is_empty() {
+                return Some(num_conference_rounds + round);
+            }
+        }
+        self.conference_brackets.iter().find_map(|bracket| bracket.get_round_reached(abbreviation))
+    }
+}
+
+impl fmt::Display for Playoffs {
+    /// Format the `Playoffs` bracket by bracket
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bracket_strs: Vec<String> = self.conference_brackets.iter().map(|bracket| format!("{}", bracket)).collect();
+        if !self.final_bracket.rounds.is_empty() {
+            bracket_strs.push(format!("{}", self.final_bracket));
+        }
+        f.write_str(&bracket_strs.join("\n\n"))
+    }
+}
+
+/// # PlayoffOdds struct
+///
+/// `PlayoffOdds` estimate each team's chances of reaching each round of
+/// the playoffs by simulating them many times in parallel.  Run `i` is
+/// seeded with `seed + i`, so the odds are the same whatever the number of
+/// threads.  Each team's odds are listed by round, followed by its odds of
+/// winning the championship.
+pub struct PlayoffOdds {
+    pub num_runs: usize,
+    pub num_rounds: usize,
+    pub odds: BTreeMap<String, Vec<f64>>
+}
+
+impl PlayoffOdds {
+    /// Simulate the playoffs a number of times and tally the odds
+    pub fn simulate(league: &League, standings: &Standings, format: &PlayoffFormat, num_runs: usize, seed: u64) -> Result<PlayoffOdds, LeagueError> {
+        format.validate(league)?;
+        let runs: Vec<Playoffs> = parallel_map(num_runs, get_num_threads(), |index| {
+            let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(seed.wrapping_add(index as u64));
+            Playoffs::simulate(league, standings, format, &mut rng)
+        })?;
+
+        // Count the times each team reached each round
+        let num_rounds: usize = format.get_num_conference_rounds() + league.conferences.len().trailing_zeros() as usize;
+        let mut counts: BTreeMap<String, Vec<usize>> = league.teams.iter()
+            .map(|team| (team.abbreviation.clone(), vec![0_usize; num_rounds + 1]))
+            .collect();
+        for playoffs in runs.iter() {
+            for (team, team_counts) in counts.iter_mut() {
+                if let Some(round) = playoffs.get_round_reached(team) {
+                    for count in team_counts.iter_mut().take(round) {
+                        *count += 1;
+                    }
+                }
+            }
+            if let Some(team_counts) = counts.get_mut(&playoffs.champion) {
+                team_counts[num_rounds] += 1;
+            }
+        }
+        let odds: BTreeMap<String, Vec<f64>> = counts.into_iter()
+            .map(|(team, team_counts)| {
+                let team_odds: Vec<f64> = team_counts.iter().map(|count| *count as f64 / num_runs.max(1) as f64).collect();
+                (team, team_odds)
+            })
+            .collect();
+        Ok(PlayoffOdds {
+            num_runs,
+            num_rounds,
+            odds
+        })
+    }
+
+    /// Get a team's odds of reaching a round, numbered from 1
+    pub fn get_round_odds(&self, abbreviation: &str, round: usize) -> f64 {
+        match self.odds.get(abbreviation) {
+            Some(team_odds) if round >= 1 => team_odds.get(round - 1).copied().unwrap_or(0_f64),
+            _ => 0_f64
+        }
+    }
+
+    /// Get a team's odds of winning the championship
+    pub fn get_championship_odds(&self, abbreviation: &str) -> f64 {
+        self.get_round_odds(abbreviation, self.num_rounds + 1)
+    }
+}
+
+impl fmt::Display for PlayoffOdds {
+    /// Format the `PlayoffOdds` as a table of percentages, best odds of
+    /// winning the championship first
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut odds_str: String = format!("{:<5}", "TEAM");
+        for round in 1..=self.num_rounds {
+            odds_str += &format!(" {:>6}", format!("R{}", round));
+        }
+        odds_str += &format!(" {:>6}", "CHAMP");
+        let mut teams: Vec<(&String, &Vec<f64>)> = self.odds.iter().collect();
+        teams.sort_by(|a, b| b.1.iter().rev().partial_cmp(a.1.iter().rev()).unwrap_or(std::cmp::Ordering::Equal));
+        for (team, team_odds) in teams {
+            odds_str += &format!("\n{:<5}", team);
+            for odds in team_odds.iter() {
+                odds_str += &format!(" {:>5.1}%", 100_f64 * odds);
+            }
+        }
+        f.write_str(&odds_str)
+    }
+}
+
+/// Get the order of seeds in a standard bracket of a given size, such that
+/// the best seeds meet as late as possible.  Adjacent seeds meet in the
+/// first round.
+fn get_bracket_order(num_slots: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = vec![1];
+    while order.len() < num_slots {
+        let size: usize = order.len() * 2;
+        order = order.into_iter().flat_map(|seed| [seed, size + 1 - seed]).collect();
+    }
+    order
+}
+
+/// Simulate a playoff game between two seeds under playoff overtime rules,
+/// without a home field advantage at a neutral site
+fn simulate_game(league: &League, home_team: &str, away_team: &str, seeds: (usize, usize), neutral_site: bool, rng: &mut impl Rng) -> Result<PlayoffGame, LeagueError> {
+    let mut game: Game = league.get_game(&Matchup::new(home_team, away_team), rng.gen::<u64>())?;
+    game.set_overtime_rules(OvertimeRules::Playoff);
+    game.set_neutral_site(neutral_site);
+    if let Err(err) = game.simulate_seeded() {
+        return Err(LeagueError::SimulationError(err.to_string()));
+    }
+    Ok(PlayoffGame {
+        home_team: String::from(home_team),
+        away_team: String::from(away_team),
+        home_seed: seeds.0,
+        away_seed: seeds.1,
+        home_score: game.context.score.home_team_score,
+        away_score: game.context.score.away_team_score,
+        num_overtime_periods: game.overtime.num_periods,
+        neutral_site
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+
+    /// Generate a league of two conferences, each with two divisions of
+    /// four teams
+    fn generate_league() -> League {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for conference in 0..2 {
+            for division in 0..2 {
+                for index in 0..4 {
+                    let abbreviation: String = format!("T{}{}{}", conference, division, index);
+                    let team = generate_test_team(&abbreviation, &abbreviation, &mut rng);
+                    league.add_team(team, &format!("C{}", conference), &format!("D{}", division)).unwrap();
+                }
+            }
+        }
+        league
+    }
+
+    /// Get the first seven teams of the league as seeds
+    fn get_seeds(league: &League) -> Vec<String> {
+        league.teams.iter().take(7).map(|team| team.abbreviation.clone()).collect()
+    }
+
+    #[test]
+    fn test_bracket_order_pairs_best_with_worst() {
+        assert_eq!(get_bracket_order(1), vec![1]);
+        assert_eq!(get_bracket_order(4), vec![1, 4, 2, 3]);
+        assert_eq!(get_bracket_order(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+    }
+
+    #[test]
+    fn test_top_seed_has_a_bye_and_plays_the_worst_seed_left() {
+        let league: League = generate_league();
+        let seeds: Vec<String> = get_seeds(&league);
+        for seed in 0..10_u64 {
+            let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(seed);
+            let bracket: PlayoffBracket = PlayoffBracket::simulate(&league, "C0", seeds.clone(), 1, true, true, &mut rng).unwrap();
+            assert_eq!(bracket.rounds.len(), 3);
+            assert_eq!(bracket.rounds[0].byes, vec![seeds[0].clone()]);
+            let first_round: Vec<(usize, usize)> = bracket.rounds[0].games.iter().map(|game| (game.home_seed, game.away_seed)).collect();
+            assert_eq!(first_round, vec![(4, 5), (2, 7), (3, 6)]);
+
+            // With reseeding the top seed hosts the worst seed left
+            let worst_left: usize = bracket.rounds[0].games.iter().map(|game| game.get_winner().1).max().unwrap();
+            let top_game: &PlayoffGame = bracket.rounds[1].games.iter().find(|game| game.home_seed == 1).unwrap();
+            assert_eq!(top_game.away_seed, worst_left);
+
+            // Only the final is at a neutral site
+            assert!(bracket.rounds[2].games[0].neutral_site);
+            assert!(bracket.rounds[..2].iter().flat_map(|round| round.games.iter()).all(|game| !game.neutral_site));
+            assert!(seeds.contains(&bracket.champion));
+        }
+    }
+
+    #[test]
+    fn test_fixed_bracket_keeps_its_paths() {
+        let league: League = generate_league();
+        let seeds: Vec<String> = get_seeds(&league);
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let bracket: PlayoffBracket = PlayoffBracket::simulate(&league, "C0", seeds, 1, false, false, &mut rng).unwrap();
+
+        // The top seed plays the winner of the 4-5 game
+        let four_five_winner: usize = bracket.rounds[0].games[0].get_winner().1;
+        let top_game: &PlayoffGame = &bracket.rounds[1].games[0];
+        assert_eq!((top_game.home_seed, top_game.away_seed), (1, four_five_winner));
+        assert!(!bracket.rounds[2].games[0].neutral_site);
+    }
+
+    #[test]
+    fn test_round_reached() {
+        let league: League = generate_league();
+        let seeds: Vec<String> = get_seeds(&league);
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let bracket: PlayoffBracket = PlayoffBracket::simulate(&league, "C0", seeds.clone(), 1, true, true, &mut rng).unwrap();
+        assert_eq!(bracket.get_round_reached(&bracket.champion), Some(3));
+        assert!(bracket.get_round_reached(&seeds[0]).unwrap() >= 2);
+        for game in bracket.rounds[0].games.iter() {
+            let loser: &str = match game.get_winner().0 == game.home_team {
+                true => &game.away_team,
+                false => &game.home_team
+            };
+            assert_eq!(bracket.get_round_reached(loser), Some(1));
+        }
+        assert_eq!(bracket.get_round_reached("T113"), None);
+    }
+
+    #[test]
+    fn test_playoffs_number_rounds_through_the_final() {
+        let league: League = generate_league();
+        let standings: Standings = Standings::from_results(&league, &[]).unwrap();
+        let format: PlayoffFormat = PlayoffFormat {
+            num_teams: 3,
+            num_byes: 1,
+            ..Default::default()
+        };
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let playoffs: Playoffs = Playoffs::simulate(&league, &standings, &format, &mut rng).unwrap();
+        assert_eq!(playoffs.get_num_rounds(), 3);
+        assert_eq!(playoffs.get_round_reached(&playoffs.champion), Some(3));
+        let finalists: Vec<&String> = playoffs.conference_brackets.iter().map(|bracket| &bracket.champion).collect();
+        assert!(finalists.contains(&&playoffs.champion));
+        assert!(playoffs.final_bracket.rounds[0].games[0].neutral_site);
+
+        // The odds of reaching each round never increase, and the
+        // champion odds sum to one
+        let odds: PlayoffOdds = PlayoffOdds::simulate(&league, &standings, &format, 8, 0).unwrap();
+        for team_odds in odds.odds.values() {
+            assert!(team_odds.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+        let champion_odds: f64 = odds.odds.values().map(|team_odds| team_odds[odds.num_rounds]).sum();
+        assert!((champion_odds - 1_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_invalid_formats_are_rejected() {
+        let league: League = generate_league();
+        assert!(PlayoffFormat { num_teams: 3, num_byes: 1, ..Default::default() }.validate(&league).is_ok());
+        for (num_teams, num_byes) in [(0_usize, 0_usize), (6, 1), (9, 7), (2, 2)] {
+            let format: PlayoffFormat = PlayoffFormat { num_teams, num_byes, ..Default::default() };
+            assert!(matches!(format.validate(&league), Err(LeagueError::PlayoffError(_))), "{} teams and {} byes", num_teams, num_byes);
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use crate::league::league::League;
+use crate::league::schedule::{Matchup, ScheduleWeek};
+use crate::league::standings::{GameResult, Standings};
+
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The Elo rating every team starts with, and the league's average rating
+pub const DEFAULT_ELO: f64 = 1500_f64;
+
+/// The Elo points exchanged after a game before scaling for the margin of
+/// victory
+pub const DEFAULT_K_FACTOR: f64 = 20_f64;
+
+/// The Elo points the home team is credited with
+pub const DEFAULT_HOME_FIELD_ELO: f64 = 48_f64;
+
+/// The Elo points worth one point on the scoreboard
+const ELO_PER_POINT: f64 = 25_f64;
+
+/// The share of the gap to the league average a team's rating closes
+/// between seasons
+pub const SEASON_REGRESSION: f64 = 1_f64 / 3_f64;
+
+/// The weights of Elo, team overall and point differential per game in a
+/// team's power ranking score
+const POWER_RANKING_WEIGHTS: [f64; 3] = [0.5_f64, 0.25_f64, 0.25_f64];
+
+/// # EloRatings struct
+///
+/// `EloRatings` rate every team in a league from the results of its games.
+/// After each game the winner takes Elo points from the loser, more for an
+/// upset than an expected win, and more for a rout than a close game,
+/// though a favorite's margin counts for less than an underdog's.  The
+/// home team is credited with `home_field` points unless the game is at a
+/// neutral site.  Every `ELO_PER_POINT` points of difference between two
+/// teams is worth a point on the predicted spread.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EloRatings {
+    pub ratings: BTreeMap<String, f64>,
+    pub k_factor: f64,
+    pub home_field: f64
+}
+
+impl Default for EloRatings {
+    /// Empty ratings with the default K factor and home field advantage
+    fn default() -> Self {
+        EloRatings {
+            ratings: BTreeMap::new(),
+            k_factor: DEFAULT_K_FACTOR,
+            home_field: DEFAULT_HOME_FIELD_ELO
+        }
+    }
+}
+
+impl EloRatings {
+    /// Initialize new ratings with every team in a league at the default
+    /// rating
+    pub fn new(league: &League) -> EloRatings {
+        EloRatings {
+            ratings: league.teams.iter().map(|team| (team.abbreviation.clone(), DEFAULT_ELO)).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Rate the teams of a league from the results of its games, which
+    /// should be given in the order they were played
+    pub fn from_results(league: &League, results: &[GameResult]) -> EloRatings {
+        let mut ratings: EloRatings = EloRatings::new(league);
+        for result in results.iter() {
+            ratings.add_result(result, false);
+        }
+        ratings
+    }
+
+    /// Get a team's rating, or the default rating if it has not been rated
+    pub fn get_rating(&self, abbreviation: &str) -> f64 {
+        self.ratings.get(abbreviation).copied().unwrap_or(DEFAULT_ELO)
+    }
+
+    /// Get the home team's rating edge over the away team, including the
+    /// home field advantage
+    fn get_home_edge(&self, home_team: &str, away_team: &str, neutral_site: bool) -> f64 {
+        let home_field: f64 = match neutral_site {
+            true => 0_f64,
+            false => self.home_field
+        };
+        self.get_rating(home_team) - self.get_rating(away_team) + home_field
+    }
+
+    /// Get the probability that the home team beats the away team
+    pub fn get_home_win_probability(&self, home_team: &str, away_team: &str, neutral_site: bool) -> f64 {
+        let edge: f64 = self.get_home_edge(home_team, away_team, neutral_site);
+        1_f64 / (1_f64 + 10_f64.powf(-edge / 400_f64))
+    }
+
+    /// Get the points the home team is expected to win by, negative when
+    /// the away team is favored
+    pub fn get_predicted_spread(&self, home_team: &str, away_team: &str, neutral_site: bool) -> f64 {
+        self.get_home_edge(home_team, away_team, neutral_site) / ELO_PER_POINT
+    }
+
+    /// Predict the outcome of a matchup
+    pub fn predict(&self, matchup: &Matchup, neutral_site: bool) -> Prediction {
+        Prediction {
+            home_team: matchup.home_team.clone(),
+            away_team: matchup.away_team.clone(),
+            home_win_probability: self.get_home_win_probability(&matchup.home_team, &matchup.away_team, neutral_site),
+            spread: self.get_predicted_spread(&matchup.home_team, &matchup.away_team, neutral_site)
+        }
+    }
+
+    /// Predict the outcome of every matchup in a week of the schedule
+    pub fn predict_week(&self, week: &ScheduleWeek) -> Vec<Prediction> {
+        week.matchups.iter().map(|matchup| self.predict(matchup, false)).collect()
+    }
+
+    /// Update the ratings for the final score of a game
+    pub fn add_game(&mut self, home_team: &str, away_team: &str, home_score: usize, away_score: usize, neutral_site: bool) {
+        let edge: f64 = self.get_home_edge(home_team, away_team, neutral_site);
+        let expected: f64 = 1_f64 / (1_f64 + 10_f64.powf(-edge / 400_f64));
+        let actual: f64 = if home_score > away_score {
+            1_f64
+        } else if home_score < away_score {
+            0_f64
+        } else {
+            0.5_f64
+        };
+
+        // Scale the update by the margin of victory, discounting the margin
+        // of a favorite who was expected to win big
+        let margin: f64 = (home_score as f64 - away_score as f64).abs();
+        let winner_edge: f64 = match home_score >= away_score {
+            true => edge,
+            false => -edge
+        };
+        let multiplier: f64 = match home_score == away_score {
+            true => 1_f64,
+            false => (margin + 1_f64).ln() * 2.2_f64 / (0.001_f64 * winner_edge + 2.2_f64)
+        };
+        let shift: f64 = self.k_factor * multiplier * (actual - expected);
+        *self.ratings.entry(String::from(home_team)).or_insert(DEFAULT_ELO) += shift;
+        *self.ratings.entry(String::from(away_team)).or_insert(DEFAULT_ELO) -= shift;
+    }
+
+    /// Update the ratings for the result of a league game
+    pub fn add_result(&mut self, result: &GameResult, neutral_site: bool) {
+        self.add_game(&result.home_team, &result.away_team, result.home_score, result.away_score, neutral_site);
+    }
+
+    /// Pull every team's rating part of the way back to the league average,
+    /// as rosters turn over between seasons
+    pub fn regress_to_mean(&mut self, fraction: f64) {
+        let fraction: f64 = fraction.clamp(0_f64, 1_f64);
+        for rating in self.ratings.values_mut() {
+            *rating += fraction * (DEFAULT_ELO - *rating);
+        }
+    }
+
+    /// Get every team and its rating, best first
+    pub fn get_rankings(&self) -> Vec<(&str, f64)> {
+        let mut rankings: Vec<(&str, f64)> = self.ratings.iter().map(|(team, rating)| (team.as_str(), *rating)).collect();
+        rankings.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        rankings
+    }
+}
+
+impl fmt::Display for EloRatings {
+    /// Format the `EloRatings` as a table, best first
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ratings_str: String = format!("{:>4} {:<5} {:>6}", "RK", "TEAM", "ELO");
+        for (index, (team, rating)) in self.get_rankings().iter().enumerate() {
+            ratings_str += &format!("\n{:>4} {:<5} {:>6.0}", index + 1, team, rating);
+        }
+        f.write_str(&ratings_str)
+    }
+}
+
+/// # Prediction struct
+///
+/// A `Prediction` is the expected outcome of a matchup, the home team's
+/// chance of winning and the points they are expected to win by
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Prediction {
+    pub home_team: String,
+    pub away_team: String,
+    pub home_win_probability: f64,
+    pub spread: f64
+}
+
+impl Prediction {
+    /// Get the team favored to win and the points they are favored by
+    pub fn get_favorite(&self) -> (&str, f64) {
+        match self.spread >= 0_f64 {
+            true => (&self.home_team, self.spread),
+            false => (&self.away_team, -self.spread)
+        }
+    }
+}
+
+impl fmt::Display for Prediction {
+    /// Format a `Prediction` as the matchup, favorite and win probability
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (favorite, spread) = self.get_favorite();
+        let prediction_str = format!(
+            "{} @ {}: {} by {:.1}, {} win {:.1}%",
+            self.away_team,
+            self.home_team,
+            favorite,
+            spread,
+            self.home_team,
+            100_f64 * self.home_win_probability
+        );
+        f.write_str(&prediction_str)
+    }
+}
+
+/// # PowerRanking struct
+///
+/// A `PowerRanking` ranks a team on a composite score which blends its Elo
+/// rating, the overall rating of its roster and its point differential per
+/// game.  Each component is standardized across the league before being
+/// weighted, so the score is in standard deviations from the average team.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PowerRanking {
+    pub rank: usize,
+    pub abbreviation: String,
+    pub elo: f64,
+    pub overall: f64,
+    pub point_differential: isize,
+    pub score: f64
+}
+
+impl PowerRanking {
+    /// Rank every team in a league given its Elo ratings and standings,
+    /// best first
+    pub fn rank(league: &League, ratings: &EloRatings, standings: &Standings) -> Vec<PowerRanking> {
+        let mut rankings: Vec<PowerRanking> = league.teams.iter()
+            .map(|team| {
+                let point_differential: isize = standings.get_team(&team.abbreviation)
+                    .map(|standing| standing.get_point_differential())
+                    .unwrap_or(0);
+                PowerRanking {
+                    rank: 0,
+                    abbreviation: team.abbreviation.clone(),
+                    elo: ratings.get_rating(&team.abbreviation),
+                    overall: team.get_overall(),
+                    point_differential,
+                    score: 0_f64
+                }
+            })
+            .collect();
+
+        // Standardize each component and blend them into the score
+        let differentials_per_game: Vec<f64> = rankings.iter()
+            .map(|ranking| {
+                let num_games: usize = standings.get_team(&ranking.abbreviation)
+                    .map(|standing| standing.record.get_num_games())
+                    .unwrap_or(0);
+                ranking.point_differential as f64 / num_games.max(1) as f64
+            })
+            .collect();
+        let components: [Vec<f64>; 3] = [
+            standardize(&rankings.iter().map(|ranking| ranking.elo).collect::<Vec<f64>>()),
+            standardize(&rankings.iter().map(|ranking| ranking.overall).collect::<Vec<f64>>()),
+            standardize(&differentials_per_game)
+        ];
+        for (index, ranking) in rankings.iter_mut().enumerate() {
+            ranking.score = components.iter()
+                .zip(POWER_RANKING_WEIGHTS.iter())
+                .map(|(component, weight)| weight * component[index])
+                .sum();
+        }
+        rankings.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        for (index, ranking) in rankings.iter_mut().enumerate() {
+            ranking.rank = index + 1;
+        }
+        rankings
+    }
+}
+
+impl fmt::Display for PowerRanking {
+    /// Format a `PowerRanking` as a single line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranking_str = format!(
+            "{:>4} {:<5} {:>6.2} (Elo {:.0}, overall {:.1}, {:+} points)",
+            self.rank,
+            self.abbreviation,
+            self.score,
+            self.elo,
+            self.overall,
+            self.point_differential
+        );
+        f.write_str(&ranking_str)
+    }
+}
+
+/// Convert values to standard deviations from their mean, or all zeros if
+/// they do not vary
+fn standardize(values: &[f64]) -> Vec<f64> {
+    let num_values: f64 = values.len().max(1) as f64;
+    let mean: f64 = values.iter().sum::<f64>() / num_values;
+    let std_dev: f64 = (values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / num_values).sqrt();
+    values.iter()
+        .map(|value| match std_dev > 0_f64 {
+            true => (value - mean) / std_dev,
+            false => 0_f64
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the sum of every rating
+    fn get_total(ratings: &EloRatings) -> f64 {
+        ratings.ratings.values().sum()
+    }
+
+    #[test]
+    fn test_ratings_are_zero_sum() {
+        let mut ratings: EloRatings = EloRatings::default();
+        let games: [(&str, &str, usize, usize, bool); 5] = [
+            ("A", "B", 31, 3, false),
+            ("B", "C", 17, 20, false),
+            ("C", "A", 14, 14, false),
+            ("A", "C", 7, 24, true),
+            ("B", "A", 10, 9, false)
+        ];
+        for (home_team, away_team, home_score, away_score, neutral_site) in games.iter() {
+            ratings.add_game(home_team, away_team, *home_score, *away_score, *neutral_site);
+            assert!((get_total(&ratings) - DEFAULT_ELO * ratings.ratings.len() as f64).abs() < 1e-9);
+        }
+
+        // Regressing towards the mean keeps the total
+        ratings.regress_to_mean(SEASON_REGRESSION);
+        assert!((get_total(&ratings) - DEFAULT_ELO * 3_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bigger_wins_and_upsets_move_ratings_more() {
+        let mut close: EloRatings = EloRatings::default();
+        close.add_game("A", "B", 21, 20, true);
+        let mut blowout: EloRatings = EloRatings::default();
+        blowout.add_game("A", "B", 42, 0, true);
+        assert!(close.get_rating("A") > DEFAULT_ELO);
+        assert!(blowout.get_rating("A") > close.get_rating("A"));
+
+        // The home team gains less for winning than the away team does,
+        // since it was expected to win
+        let mut home_win: EloRatings = EloRatings::default();
+        home_win.add_game("A", "B", 21, 20, false);
+        let mut away_win: EloRatings = EloRatings::default();
+        away_win.add_game("A", "B", 20, 21, false);
+        assert!(away_win.get_rating("B") - DEFAULT_ELO > home_win.get_rating("A") - DEFAULT_ELO);
+    }
+
+    #[test]
+    fn test_home_field_widens_spread_over_neutral_site() {
+        let mut ratings: EloRatings = EloRatings::default();
+        assert_eq!(ratings.get_predicted_spread("A", "B", true), 0_f64);
+        assert_eq!(ratings.get_home_win_probability("A", "B", true), 0.5_f64);
+        let home_spread: f64 = ratings.get_predicted_spread("A", "B", false);
+        assert!((home_spread - DEFAULT_HOME_FIELD_ELO / ELO_PER_POINT).abs() < 1e-9);
+        assert!(ratings.get_home_win_probability("A", "B", false) > 0.5_f64);
+
+        // A better home team is favored by more at home than at a neutral
+        // site, and the away team's favoritism shrinks when it travels
+        ratings.ratings.insert(String::from("A"), 1600_f64);
+        assert!(ratings.get_predicted_spread("A", "B", false) > ratings.get_predicted_spread("A", "B", true));
+        assert!(ratings.get_predicted_spread("B", "A", false) > ratings.get_predicted_spread("B", "A", true));
+        assert!(ratings.get_predicted_spread("B", "A", false) < 0_f64);
+    }
+}
@@ -0,0 +1,586 @@
+use crate::league::league::{League, LeagueError};
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use rand::Rng;
+use rand::prelude::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The number of times to attempt packing a divisional schedule into weeks
+/// before giving up
+const MAX_SCHEDULE_ATTEMPTS: usize = 200;
+
+/// # ScheduleFormat enum
+///
+/// A `ScheduleFormat` represents the rules used to generate a schedule.
+///
+/// - `RoundRobin` plays every team against every other team in turn for
+///   the given number of weeks, swapping home and away each time the
+///   cycle repeats.  With an odd number of teams one team has a bye each
+///   week.
+/// - `Divisional` follows NFL-style rules: each team plays its division
+///   rivals home and away, every team in one other division of its
+///   conference and one division of another conference, and the teams
+///   which share its place in the remaining divisions.  The divisions
+///   faced rotate with `rotation`, typically the season number, and each
+///   team gets `num_byes` bye weeks.  Divisions must all be the same size,
+///   and there must be one or an even number of conferences, each with one
+///   or an even number of divisions.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScheduleFormat {
+    RoundRobin { num_weeks: usize },
+    Divisional { rotation: usize, num_byes: usize }
+}
+
+/// # Matchup struct
+///
+/// A `Matchup` represents a scheduled game between two teams
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Matchup {
+    pub home_team: String,
+    pub away_team: String
+}
+
+impl Matchup {
+    /// Initialize a new matchup given the home and away teams
+    pub fn new(home_team: &str, away_team: &str) -> Matchup {
+        Matchup {
+            home_team: String::from(home_team),
+            away_team: String::from(away_team)
+        }
+    }
+
+    /// Check whether a team plays in the matchup
+    pub fn involves(&self, abbreviation: &str) -> bool {
+        self.home_team == abbreviation || self.away_team == abbreviation
+    }
+
+    /// Get a team's opponent in the matchup
+    pub fn get_opponent(&self, abbreviation: &str) -> Option<&str> {
+        if self.home_team == abbreviation {
+            Some(&self.away_team)
+        } else if self.away_team == abbreviation {
+            Some(&self.home_team)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Matchup {
+    /// Format a `Matchup` as away @ home
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let matchup_str = format!("{} @ {}", self.away_team, self.home_team);
+        f.write_str(&matchup_str)
+    }
+}
+
+/// # ScheduleWeek struct
+///
+/// A `ScheduleWeek` holds the matchups of a single week and the teams
+/// with a bye
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScheduleWeek {
+    pub week: usize,
+    pub matchups: Vec<Matchup>,
+    pub byes: Vec<String>
+}
+
+impl fmt::Display for ScheduleWeek {
+    /// Format a `ScheduleWeek` as a single line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let matchup_strs: Vec<String> = self.matchups.iter().map(|matchup| format!("{}", matchup)).collect();
+        let mut week_str = format!("Week {}: {}", self.week, matchup_strs.join(", "));
+        if !self.byes.is_empty() {
+            week_str += &format!(" | Bye: {}", self.byes.join(", "));
+        }
+        f.write_str(&week_str)
+    }
+}
+
+/// # Schedule struct
+///
+/// A `Schedule` is a season of weeks of matchups.  Weeks are numbered
+/// from 1.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Schedule {
+    pub weeks: Vec<ScheduleWeek>
+}
+
+impl Schedule {
+    /// Initialize a new empty schedule
+    pub fn new() -> Schedule {
+        Schedule {
+            weeks: Vec::new()
+        }
+    }
+
+    /// Add a hand-coded week of matchups to the end of the schedule.  Every
+    /// league team without a matchup has a bye.
+    pub fn add_week(&mut self, league: &League, matchups: Vec<Matchup>) {
+        let byes: Vec<String> = league.teams.iter()
+            .map(|team| team.abbreviation.clone())
+            .filter(|team| !matchups.iter().any(|matchup| matchup.involves(team)))
+            .collect();
+        self.weeks.push(ScheduleWeek {
+            week: self.weeks.len() + 1,
+            matchups,
+            byes
+        });
+    }
+
+    /// Generate a schedule for a league given a format
+    pub fn generate(league: &League, format: ScheduleFormat, mut rng: &mut impl Rng) -> Result<Schedule, LeagueError> {
+        league.validate()?;
+        let weeks: Vec<Vec<Matchup>> = match format {
+            ScheduleFormat::RoundRobin { num_weeks } => Schedule::generate_round_robin(league, num_weeks, &mut rng),
+            ScheduleFormat::Divisional { rotation, num_byes } => Schedule::generate_divisional(league, rotation, num_byes, &mut rng)?
+        };
+        let mut schedule: Schedule = Schedule::new();
+        for matchups in weeks {
+            schedule.add_week(league, matchups);
+        }
+        Ok(schedule)
+    }
+
+    /// Generate the weeks of a round robin schedule using the circle method
+    fn generate_round_robin(league: &League, num_weeks: usize, rng: &mut impl Rng) -> Vec<Vec<Matchup>> {
+        let mut teams: Vec<&str> = league.teams.iter().map(|team| team.abbreviation.as_str()).collect();
+        teams.shuffle(rng);
+        let num_rounds: usize = get_num_rounds(teams.len());
+        (0..num_weeks)
+            .map(|week| {
+                let swap: bool = (week / num_rounds) % 2 == 1;
+                get_round_pairings(teams.len(), week % num_rounds).into_iter()
+                    .map(|(home, away)| match swap {
+                        false => Matchup::new(teams[home], teams[away]),
+                        true => Matchup::new(teams[away], teams[home])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Generate the weeks of a divisional schedule
+    fn generate_divisional(league: &League, rotation: usize, num_byes: usize, rng: &mut impl Rng) -> Result<Vec<Vec<Matchup>>, LeagueError> {
+        // Check that every division is the same size
+        let divisions: Vec<Vec<&Vec<String>>> = league.conferences.iter()
+            .map(|conference| conference.divisions.iter().map(|division| &division.teams).collect())
+            .collect();
+        let num_divisions: usize = divisions[0].len();
+        let division_size: usize = divisions[0].first().map(|division| division.len()).unwrap_or(0);
+        for conference in divisions.iter() {
+            if num_divisions == 0 || conference.len() != num_divisions || conference.iter().any(|division| division.len() != division_size) {
+                return Err(LeagueError::ScheduleError(String::from(
+                    "Cannot generate divisional schedule: Every conference must have the same number of equally sized divisions"
+                )));
+            }
+        }
+
+        // Rotations pair up divisions and conferences, so an odd number
+        // would leave some teams with fewer games
+        let num_conferences: usize = divisions.len();
+        if (num_divisions > 1 && num_divisions % 2 == 1) || (num_conferences > 1 && num_conferences % 2 == 1) {
+            return Err(LeagueError::ScheduleError(String::from(
+                "Cannot generate divisional schedule: Conferences and divisions per conference must number one or an even count"
+            )));
+        }
+
+        // Games with a fixed home team, and games to be balanced afterwards
+        let mut games: Vec<Matchup> = Vec::new();
+        let mut unbalanced: Vec<(&str, &str)> = Vec::new();
+        let add_division_series = |games: &mut Vec<Matchup>, a: &Vec<String>, b: &Vec<String>| {
+            for (i, team_a) in a.iter().enumerate() {
+                for (j, team_b) in b.iter().enumerate() {
+                    match (i + j + rotation) % 2 {
+                        0 => games.push(Matchup::new(team_a, team_b)),
+                        _ => games.push(Matchup::new(team_b, team_a))
+                    }
+                }
+            }
+        };
+        for conference in divisions.iter() {
+            // Division rivals home and away
+            for division in conference.iter() {
+                for (i, team_a) in division.iter().enumerate() {
+                    for team_b in division.iter().skip(i + 1) {
+                        games.push(Matchup::new(team_a, team_b));
+                        games.push(Matchup::new(team_b, team_a));
+                    }
+                }
+            }
+
+            // A full rotating division, and same-place teams in the rest
+            if num_divisions > 1 {
+                let pairings = get_round_pairings(num_divisions, rotation % get_num_rounds(num_divisions));
+                for (a, b) in pairings.iter() {
+                    add_division_series(&mut games, conference[*a], conference[*b]);
+                }
+                for a in 0..num_divisions {
+                    for b in (a + 1)..num_divisions {
+                        if pairings.iter().any(|pairing| *pairing == (a, b) || *pairing == (b, a)) {
+                            continue;
+                        }
+                        for (team_a, team_b) in conference[a].iter().zip(conference[b].iter()) {
+                            unbalanced.push((team_a, team_b));
+                        }
+                    }
+                }
+            }
+        }
+
+        // A full rotating division of another conference, and its next
+        // division's same-place team
+        if num_conferences > 1 {
+            for (a, b) in get_round_pairings(num_conferences, rotation % get_num_rounds(num_conferences)) {
+                for d in 0..num_divisions {
+                    let other: usize = (d + rotation) % num_divisions;
+                    add_division_series(&mut games, divisions[a][d], divisions[b][other]);
+                    if num_divisions > 1 {
+                        let other: usize = (d + rotation + 1) % num_divisions;
+                        for (team_a, team_b) in divisions[a][d].iter().zip(divisions[b][other].iter()) {
+                            unbalanced.push((team_a, team_b));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Give the home game to whichever team has fewer so far
+        let mut home_games: BTreeMap<&str, usize> = BTreeMap::new();
+        for game in games.iter() {
+            *home_games.entry(&game.home_team).or_insert(0) += 1;
+        }
+        let mut balanced: Vec<Matchup> = Vec::new();
+        for (team_a, team_b) in unbalanced {
+            let home_a: usize = *home_games.get(team_a).unwrap_or(&0);
+            let home_b: usize = *home_games.get(team_b).unwrap_or(&0);
+            let (home, away) = if home_a < home_b || (home_a == home_b && rng.gen::<bool>()) {
+                (team_a, team_b)
+            } else {
+                (team_b, team_a)
+            };
+            *home_games.entry(home).or_insert(0) += 1;
+            balanced.push(Matchup::new(home, away));
+        }
+
+        // Hand home games back while a team has two more than its opponent
+        let mut changed: bool = true;
+        while changed {
+            changed = false;
+            for matchup in balanced.iter_mut() {
+                if home_games[matchup.home_team.as_str()] > home_games[matchup.away_team.as_str()] + 1 {
+                    *home_games.get_mut(matchup.home_team.as_str()).unwrap() -= 1;
+                    *home_games.get_mut(matchup.away_team.as_str()).unwrap() += 1;
+                    *matchup = Matchup::new(&matchup.away_team, &matchup.home_team);
+                    changed = true;
+                }
+            }
+        }
+        games.extend(balanced);
+
+        // Pack the games into weeks
+        let mut num_games: BTreeMap<&str, usize> = BTreeMap::new();
+        for game in games.iter() {
+            *num_games.entry(&game.home_team).or_insert(0) += 1;
+            *num_games.entry(&game.away_team).or_insert(0) += 1;
+        }
+        let num_weeks: usize = num_games.values().max().copied().unwrap_or(0) + num_byes;
+        for _ in 0..MAX_SCHEDULE_ATTEMPTS {
+            if let Some(weeks) = pack_weeks(&games, num_weeks, rng) {
+                return Ok(weeks);
+            }
+        }
+        Err(LeagueError::ScheduleError(format!(
+            "Cannot generate divisional schedule: Could not fit {} games into {} weeks",
+            games.len(),
+            num_weeks
+        )))
+    }
+
+    /// Get every matchup a team plays in alongside its week number
+    pub fn get_team_schedule(&self, abbreviation: &str) -> Vec<(usize, &Matchup)> {
+        self.weeks.iter()
+            .flat_map(|week| week.matchups.iter().map(move |matchup| (week.week, matchup)))
+            .filter(|(_, matchup)| matchup.involves(abbreviation))
+            .collect()
+    }
+
+    /// Get the number of home games a team plays
+    pub fn get_num_home_games(&self, abbreviation: &str) -> usize {
+        self.get_team_schedule(abbreviation).iter()
+            .filter(|(_, matchup)| matchup.home_team == abbreviation)
+            .count()
+    }
+
+    /// Validate the schedule against a league, checking that every team
+    /// exists and plays at most once a week
+    pub fn validate(&self, league: &League) -> Result<(), LeagueError> {
+        let mut errors: Vec<String> = Vec::new();
+        for week in self.weeks.iter() {
+            let mut playing: BTreeSet<&str> = BTreeSet::new();
+            for matchup in week.matchups.iter() {
+                for team in [&matchup.home_team, &matchup.away_team] {
+                    if league.get_team(team).is_none() {
+                        errors.push(format!("week {}: {} is not a team in the league", week.week, team));
+                    }
+                    if !playing.insert(team) {
+                        errors.push(format!("week {}: {} plays more than once", week.week, team));
+                    }
+                }
+                if matchup.home_team == matchup.away_team {
+                    errors.push(format!("week {}: {} cannot play itself", week.week, matchup.home_team));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(LeagueError::ScheduleError(errors.join("; ")))
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    /// Format a `Schedule` one week per line
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let week_strs: Vec<String> = self.weeks.iter().map(|week| format!("{}", week)).collect();
+        f.write_str(&week_strs.join("\n"))
+    }
+}
+
+/// Get the number of rounds in a single round robin between a number of
+/// teams
+fn get_num_rounds(num_teams: usize) -> usize {
+    match num_teams % 2 {
+        0 => num_teams.saturating_sub(1).max(1),
+        _ => num_teams
+    }
+}
+
+/// Get the pairings of a round of a round robin by the circle method.  The
+/// first team stays fixed while the rest rotate, and with an odd number of
+/// teams the team paired with the empty slot sits out.  Pairings are
+/// given as (home, away) indices.
+fn get_round_pairings(num_teams: usize, round: usize) -> Vec<(usize, usize)> {
+    let num_slots: usize = num_teams + num_teams % 2;
+    if num_slots < 2 {
+        return Vec::new();
+    }
+    let mut slots: Vec<usize> = (1..num_slots).collect();
+    slots.rotate_right(round % (num_slots - 1));
+    slots.insert(0, 0);
+    (0..(num_slots / 2))
+        .map(|i| {
+            let (a, b) = (slots[i], slots[num_slots - 1 - i]);
+            if (i == 0 && round % 2 == 1) || (i > 0 && i % 2 == 0) {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        })
+        .filter(|(a, b)| *a < num_teams && *b < num_teams)
+        .collect()
+}
+
+/// Try to pack games into a number of weeks.  Each game goes in a random
+/// week both teams have free, avoiding rematches in consecutive weeks where
+/// possible.  When no such week exists, the games alternating between a
+/// week free for the home team and a week free for the away team are
+/// swapped to free the former for both.
+fn pack_weeks(games: &[Matchup], num_weeks: usize, rng: &mut impl Rng) -> Option<Vec<Vec<Matchup>>> {
+    let teams: Vec<&str> = games.iter()
+        .flat_map(|game| [game.home_team.as_str(), game.away_team.as_str()])
+        .collect::<BTreeSet<&str>>()
+        .into_iter()
+        .collect();
+    let get_index = |team: &str| teams.binary_search(&team).unwrap();
+    let pairs: Vec<(usize, usize)> = games.iter()
+        .map(|game| (get_index(&game.home_team), get_index(&game.away_team)))
+        .collect();
+
+    // The game each team plays in each week, and the week of each game
+    let mut slots: Vec<Vec<Option<usize>>> = vec![vec![None; num_weeks]; teams.len()];
+    let mut game_weeks: Vec<usize> = vec![0; games.len()];
+    let mut order: Vec<usize> = (0..games.len()).collect();
+    order.shuffle(rng);
+    for game in order {
+        let (home, away) = pairs[game];
+        let is_rematch = |slots: &Vec<Vec<Option<usize>>>, week: usize| {
+            [week.checked_sub(1), Some(week + 1)].into_iter()
+                .flatten()
+                .filter_map(|other| slots[home].get(other).copied().flatten())
+                .any(|other| pairs[other] == (home, away) || pairs[other] == (away, home))
+        };
+        let mut free: Vec<usize> = (0..num_weeks)
+            .filter(|week| slots[home][*week].is_none() && slots[away][*week].is_none())
+            .collect();
+        if free.iter().any(|week| !is_rematch(&slots, *week)) {
+            free.retain(|week| !is_rematch(&slots, *week));
+        }
+        let week: usize = match free.choose(rng) {
+            Some(week) => *week,
+            None => {
+                let mut options: Vec<(usize, usize)> = (0..num_weeks)
+                    .filter(|week| slots[home][*week].is_none())
+                    .flat_map(|week_a| (0..num_weeks).filter(|week| slots[away][*week].is_none()).map(move |week_b| (week_a, week_b)))
+                    .collect();
+                options.shuffle(rng);
+                let mut placed: Option<usize> = None;
+                for (week_a, week_b) in options {
+                    // Walk the chain from the away team, which plays in
+                    // week a but not week b
+                    let mut chain: Vec<usize> = Vec::new();
+                    let mut team: usize = away;
+                    let mut week: usize = week_a;
+                    while let Some(other) = slots[team][week] {
+                        chain.push(other);
+                        let (other_home, other_away) = pairs[other];
+                        team = if other_home == team { other_away } else { other_home };
+                        week = if week == week_a { week_b } else { week_a };
+                    }
+                    if chain.iter().any(|other| pairs[*other].0 == home || pairs[*other].1 == home) {
+                        continue;
+                    }
+                    for other in chain.iter() {
+                        let (other_home, other_away) = pairs[*other];
+                        slots[other_home][game_weeks[*other]] = None;
+                        slots[other_away][game_weeks[*other]] = None;
+                    }
+                    for other in chain.iter() {
+                        let (other_home, other_away) = pairs[*other];
+                        game_weeks[*other] = if game_weeks[*other] == week_a { week_b } else { week_a };
+                        slots[other_home][game_weeks[*other]] = Some(*other);
+                        slots[other_away][game_weeks[*other]] = Some(*other);
+                    }
+                    placed = Some(week_a);
+                    break;
+                }
+                placed?
+            }
+        };
+        game_weeks[game] = week;
+        slots[home][week] = Some(game);
+        slots[away][week] = Some(game);
+    }
+
+    let mut weeks: Vec<Vec<Matchup>> = vec![Vec::new(); num_weeks];
+    for (game, week) in game_weeks.into_iter().enumerate() {
+        weeks[week].push(games[game].clone());
+    }
+    Some(weeks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::league::league::Division;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Generate a league with a number of conferences, divisions per
+    /// conference and teams per division
+    fn generate_league(num_conferences: usize, num_divisions: usize, division_size: usize) -> League {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for conference in 0..num_conferences {
+            for division in 0..num_divisions {
+                for index in 0..division_size {
+                    let abbreviation: String = format!("T{}{}{}", conference, division, index);
+                    let team = generate_test_team(&abbreviation, &abbreviation, &mut rng);
+                    league.add_team(team, &format!("C{}", conference), &format!("D{}", division)).unwrap();
+                }
+            }
+        }
+        league
+    }
+
+    /// Count the games between every pair of teams, keyed home team first
+    fn count_matchups(schedule: &Schedule) -> BTreeMap<(String, String), usize> {
+        let mut counts: BTreeMap<(String, String), usize> = BTreeMap::new();
+        for matchup in schedule.weeks.iter().flat_map(|week| week.matchups.iter()) {
+            *counts.entry((matchup.home_team.clone(), matchup.away_team.clone())).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    #[test]
+    fn test_double_round_robin_plays_every_pair_home_and_away() {
+        let league: League = generate_league(1, 2, 4);
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let schedule: Schedule = Schedule::generate(&league, ScheduleFormat::RoundRobin { num_weeks: 14 }, &mut rng).unwrap();
+        schedule.validate(&league).unwrap();
+        assert!(schedule.weeks.iter().all(|week| week.byes.is_empty()));
+        let counts: BTreeMap<(String, String), usize> = count_matchups(&schedule);
+        for team_a in league.teams.iter() {
+            for team_b in league.teams.iter().filter(|team_b| team_b.abbreviation != team_a.abbreviation) {
+                let key: (String, String) = (team_a.abbreviation.clone(), team_b.abbreviation.clone());
+                assert_eq!(counts.get(&key), Some(&1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_robin_with_odd_teams_gives_each_team_one_bye() {
+        let league: League = generate_league(1, 1, 7);
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let schedule: Schedule = Schedule::generate(&league, ScheduleFormat::RoundRobin { num_weeks: 7 }, &mut rng).unwrap();
+        schedule.validate(&league).unwrap();
+        for team in league.teams.iter() {
+            let num_byes: usize = schedule.weeks.iter().filter(|week| week.byes.contains(&team.abbreviation)).count();
+            assert_eq!(num_byes, 1);
+            let opponents: BTreeSet<&str> = schedule.get_team_schedule(&team.abbreviation).iter()
+                .filter_map(|(_, matchup)| matchup.get_opponent(&team.abbreviation))
+                .collect();
+            assert_eq!(opponents.len(), 6);
+        }
+    }
+
+    #[test]
+    fn test_divisional_schedule_is_balanced() {
+        let league: League = generate_league(2, 4, 4);
+        for rotation in 0..3_usize {
+            let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(rotation as u64);
+            let format: ScheduleFormat = ScheduleFormat::Divisional { rotation, num_byes: 1 };
+            let schedule: Schedule = Schedule::generate(&league, format, &mut rng).unwrap();
+            schedule.validate(&league).unwrap();
+            assert_eq!(schedule.weeks.len(), 18);
+            let counts: BTreeMap<(String, String), usize> = count_matchups(&schedule);
+            for team in league.teams.iter() {
+                let abbreviation: &str = &team.abbreviation;
+                assert_eq!(schedule.get_team_schedule(abbreviation).len(), 17);
+                assert_eq!(schedule.weeks.iter().filter(|week| week.byes.iter().any(|bye| bye == abbreviation)).count(), 1);
+                let num_home_games: usize = schedule.get_num_home_games(abbreviation);
+                assert!(num_home_games == 8 || num_home_games == 9);
+
+                // Division rivals meet once at each team's stadium
+                let division: &Division = league.get_division(abbreviation).unwrap();
+                for rival in division.teams.iter().filter(|rival| *rival != abbreviation) {
+                    assert_eq!(counts.get(&(String::from(abbreviation), rival.clone())), Some(&1));
+                    assert_eq!(counts.get(&(rival.clone(), String::from(abbreviation))), Some(&1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_double_booked_and_unknown_teams() {
+        let league: League = generate_league(1, 1, 4);
+        let mut schedule: Schedule = Schedule::new();
+        schedule.add_week(&league, vec![Matchup::new("T000", "T001"), Matchup::new("T000", "T002")]);
+        schedule.add_week(&league, vec![Matchup::new("T000", "XXX")]);
+        match schedule.validate(&league) {
+            Err(LeagueError::ScheduleError(error)) => {
+                assert!(error.contains("week 1: T000 plays more than once"));
+                assert!(error.contains("week 2: XXX is not a team in the league"));
+            },
+            _ => panic!("invalid schedule was not rejected")
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use crate::game::batch::{get_num_threads, parallel_map};
+use crate::game::boxscore::{BoxScore, PlayerBoxScore, TeamBoxScore};
+use crate::game::game::Game;
+use crate::game::overtime::OvertimeRules;
+use crate::league::league::{League, LeagueError};
+use crate::league::ratings::{EloRatings, PowerRanking, Prediction};
+use crate::league::schedule::{Matchup, Schedule};
+use crate::league::standings::{GameResult, Standings};
+
+use std::collections::BTreeMap;
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # PlayerStat enum
+///
+/// A `PlayerStat` is a season statistic players can be ranked by
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayerStat {
+    PassingYards,
+    PassingTouchdowns,
+    RushingYards,
+    RushingTouchdowns,
+    Receptions,
+    ReceivingYards,
+    ReceivingTouchdowns,
+    ScrimmageYards,
+    Tackles,
+    Sacks,
+    Interceptions,
+    FieldGoals
+}
+
+impl PlayerStat {
+    /// Get a player's value for the statistic
+    pub fn get_value(&self, box_score: &PlayerBoxScore) -> isize {
+        match self {
+            PlayerStat::PassingYards => box_score.passing_yards,
+            PlayerStat::PassingTouchdowns => box_score.passing_touchdowns as isize,
+            PlayerStat::RushingYards => box_score.rushing_yards,
+            PlayerStat::RushingTouchdowns => box_score.rushing_touchdowns as isize,
+            PlayerStat::Receptions => box_score.receptions as isize,
+            PlayerStat::ReceivingYards => box_score.receiving_yards,
+            PlayerStat::ReceivingTouchdowns => box_score.receiving_touchdowns as isize,
+            PlayerStat::ScrimmageYards => box_score.get_scrimmage_yards(),
+            PlayerStat::Tackles => box_score.tackles as isize,
+            PlayerStat::Sacks => box_score.sacks as isize,
+            PlayerStat::Interceptions => box_score.interceptions as isize,
+            PlayerStat::FieldGoals => box_score.field_goals_made as isize
+        }
+    }
+
+    /// Format the statistic as a string
+    pub fn format_stat(&self) -> String {
+        let stat_str = match self {
+            PlayerStat::PassingYards => "Passing Yards",
+            PlayerStat::PassingTouchdowns => "Passing Touchdowns",
+            PlayerStat::RushingYards => "Rushing Yards",
+            PlayerStat::RushingTouchdowns => "Rushing Touchdowns",
+            PlayerStat::Receptions => "Receptions",
+            PlayerStat::ReceivingYards => "Receiving Yards",
+            PlayerStat::ReceivingTouchdowns => "Receiving Touchdowns",
+            PlayerStat::ScrimmageYards => "Scrimmage Yards",
+            PlayerStat::Tackles => "Tackles",
+            PlayerStat::Sacks => "Sacks",
+            PlayerStat::Interceptions => "Interceptions",
+            PlayerStat::FieldGoals => "Field Goals"
+        };
+        String::from(stat_str)
+    }
+}
+
+/// # Award enum
+///
+/// An `Award` is a season award players are candidates for.  Offensive
+/// candidates are scored on yards and touchdowns, less turnovers, and
+/// defensive candidates on tackles, sacks and takeaways.  The most
+/// valuable player's offensive score is weighted by their team's win
+/// percentage.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Award {
+    MostValuablePlayer,
+    OffensivePlayerOfTheYear,
+    DefensivePlayerOfTheYear
+}
+
+impl Award {
+    /// Format the award as a string
+    pub fn format_award(&self) -> String {
+        let award_str = match self {
+            Award::MostValuablePlayer => "Most Valuable Player",
+            Award::OffensivePlayerOfTheYear => "Offensive Player of the Year",
+            Award::DefensivePlayerOfTheYear => "Defensive Player of the Year"
+        };
+        String::from(award_str)
+    }
+}
+
+/// # Season struct
+///
+/// A `Season` simulates a league's schedule week by week, updating the
+/// standings and accumulating every team's and player's statistics.  The
+/// `i`th game of the season is seeded with `seed + i`, so a season can be
+/// reproduced from its seed.  The games of each week are simulated in
+/// parallel.  Every team's Elo rating is updated from each result, from
+/// which the next week's games are predicted and the teams power ranked.
+/// Injuries only last beyond the game they happen in when the season is
+/// simulated with injuries, which updates the league's rosters.  Fatigue
+/// is per game, since every game starts with rested players, so nothing
+/// carries over between weeks.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Season {
+    pub schedule: Schedule,
+    pub standings: Standings,
+    pub team_stats: BTreeMap<String, TeamBoxScore>,
+    pub player_stats: Vec<PlayerBoxScore>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ratings: EloRatings,
+    pub overtime_rules: OvertimeRules,
+    pub num_weeks_played: usize,
+    pub num_games_played: usize,
+    pub seed: u64
+}
+
+impl Season {
+    /// Initialize a new season for a league given its schedule and seed.
+    /// Games go to regular season overtime when tied.
+    pub fn new(league: &League, schedule: Schedule, seed: u64) -> Season {
+        Season {
+            schedule,
+            standings: Standings::new(league),
+            team_stats: league.teams.iter().map(|team| (team.abbreviation.clone(), TeamBoxScore::default())).collect(),
+            player_stats: Vec::new(),
+            ratings: EloRatings::new(league),
+            overtime_rules: OvertimeRules::RegularSeason,
+            num_weeks_played: 0_usize,
+            num_games_played: 0_usize,
+            seed
+        }
+    }
+
+    /// Start the season from existing Elo ratings, such as those carried
+    /// over from the previous season
+    pub fn set_ratings(&mut self, ratings: EloRatings) {
+        self.ratings = ratings;
+    }
+
+    /// Check whether every week of the schedule has been played
+    pub fn is_over(&self) -> bool {
+        self.num_weeks_played >= self.schedule.weeks.len()
+    }
+
+    /// Simulate the next week of the schedule, returning its finished games
+    pub fn simulate_week(&mut self, league: &League) -> Result<Vec<Game>, LeagueError> {
+        if self.is_over() {
+            return Err(LeagueError::ScheduleError(String::from("Cannot simulate week: Season is over")));
+        }
+        let week: usize = self.schedule.weeks[self.num_weeks_played].week;
+        let matchups: &Vec<Matchup> = &self.schedule.weeks[self.num_weeks_played].matchups;
+        let games: Vec<Game> = parallel_map(matchups.len(), get_num_threads(), |index| {
+            let mut game: Game = league.get_game(&matchups[index], self.seed.wrapping_add((self.num_games_played + index) as u64))?;
+            game.set_overtime_rules(self.overtime_rules);
+            match game.simulate_seeded() {
+                Ok(()) => Ok(game),
+                Err(err) => Err(LeagueError::SimulationError(err.to_string()))
+            }
+        })?;
+
+        // Record the results and statistics
+        for game in games.iter() {
+            self.add_game(league, week, game)?;
+        }
+        self.num_weeks_played += 1;
+        Ok(games)
+    }
+
+    /// Record a finished game's result and statistics
+    fn add_game(&mut self, league: &League, week: usize, game: &Game) -> Result<(), LeagueError> {
+        let result: GameResult = GameResult::from_game(week, game);
+        self.ratings.add_result(&result, game.home_field.neutral_site);
+        self.standings.add_result(league, result)?;
+        let box_score: BoxScore = BoxScore::from_game(game);
+        for (team, team_box_score) in [(&game.home_team.abbreviation, &box_score.home), (&game.away_team.abbreviation, &box_score.away)] {
+            self.team_stats.entry(team.clone()).or_default().add(team_box_score);
+        }
+        for player_box_score in PlayerBoxScore::from_game(game) {
+            match self.player_stats.iter_mut().find(|stats| stats.name == player_box_score.name && stats.team == player_box_score.team) {
+                Some(stats) => stats.add(&player_box_score),
+                None => self.player_stats.push(player_box_score)
+            }
+        }
+        self.num_games_played += 1;
+        Ok(())
+    }
+
+    /// Simulate the rest of the season
+    pub fn simulate(&mut self, league: &League) -> Result<(), LeagueError> {
+        while !self.is_over() {
+            self.simulate_week(league)?;
+        }
+        Ok(())
+    }
+
+    /// Simulate the rest of the season, calling `between_weeks` with the
+    /// league and each week's finished games before the next week is
+    /// played, for example to update rosters for injuries
+    pub fn simulate_with(&mut self, league: &mut League, mut between_weeks: impl FnMut(&mut League, &[Game])) -> Result<(), LeagueError> {
+        while !self.is_over() {
+            let games: Vec<Game> = self.simulate_week(league)?;
+            between_weeks(league, &games);
+        }
+        Ok(())
+    }
+
+    /// Simulate the next week of the schedule, then carry its injuries
+    /// over to the league's teams so that injured players miss the
+    /// following weeks.  Returns the week's finished games.
+    pub fn simulate_week_with_injuries(&mut self, league: &mut League) -> Result<Vec<Game>, LeagueError> {
+        let games: Vec<Game> = self.simulate_week(league)?;
+        league.add_game_injuries(&games);
+        Ok(games)
+    }
+
+    /// Simulate the rest of the season, carrying each week's injuries over
+    /// to the league's teams before the next week is played
+    pub fn simulate_with_injuries(&mut self, league: &mut League) -> Result<(), LeagueError> {
+        self.simulate_with(league, |league, games| league.add_game_injuries(games))
+    }
+
+    /// Predict the games of the next week of the schedule from the current
+    /// Elo ratings, or none if the season is over
+    pub fn predict_next_week(&self) -> Vec<Prediction> {
+        match self.schedule.weeks.get(self.num_weeks_played) {
+            Some(week) => self.ratings.predict_week(week),
+            None => Vec::new()
+        }
+    }
+
+    /// Rank the league's teams on their Elo ratings, rosters and point
+    /// differentials so far this season
+    pub fn get_power_rankings(&self, league: &League) -> Vec<PowerRanking> {
+        PowerRanking::rank(league, &self.ratings, &self.standings)
+    }
+
+    /// Get a team's season statistics
+    pub fn get_team_stats(&self, abbreviation: &str) -> Option<&TeamBoxScore> {
+        self.team_stats.get(abbreviation)
+    }
+
+    /// Get a player's season statistics given their name and team
+    pub fn get_player_stats(&self, name: &str, team: &str) -> Option<&PlayerBoxScore> {
+        self.player_stats.iter().find(|stats| stats.name == name && stats.team == team)
+    }
+
+    /// Get the season leaders in a statistic, best first
+    pub fn get_leaders(&self, stat: PlayerStat, num_players: usize) -> Vec<&PlayerBoxScore> {
+        let mut leaders: Vec<&PlayerBoxScore> = self.player_stats.iter()
+            .filter(|stats| stat.get_value(stats) > 0)
+            .collect();
+        leaders.sort_by_key(|stats| std::cmp::Reverse(stat.get_value(stats)));
+        leaders.truncate(num_players);
+        leaders
+    }
+
+    /// Get the leading candidates for an award alongside their scores,
+    /// best first
+    pub fn get_award_candidates(&self, award: Award, num_players: usize) -> Vec<(&PlayerBoxScore, f64)> {
+        let mut candidates: Vec<(&PlayerBoxScore, f64)> = self.player_stats.iter()
+            .map(|stats| {
+                let score: f64 = match award {
+                    Award::MostValuablePlayer => {
+                        let win_percentage: f64 = self.standings.get_team(&stats.team)
+                            .map(|standing| standing.get_win_percentage())
+                            .unwrap_or(0_f64);
+                        get_offensive_score(stats) * (0.5_f64 + win_percentage)
+                    },
+                    Award::OffensivePlayerOfTheYear => get_offensive_score(stats),
+                    Award::DefensivePlayerOfTheYear => get_defensive_score(stats)
+                };
+                (stats, score)
+            })
+            .filter(|(_, score)| *score > 0_f64)
+            .collect();
+        candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        candidates.truncate(num_players);
+        candidates
+    }
+
+    /// Format the season leaders in a statistic, one player per line
+    pub fn format_leaders(&self, stat: PlayerStat, num_players: usize) -> String {
+        let mut leaders_str: String = stat.format_stat();
+        for (rank, stats) in self.get_leaders(stat, num_players).iter().enumerate() {
+            leaders_str += &format!("\n{:>2}. {} ({}) {}", rank + 1, stats.name, stats.team, stat.get_value(stats));
+        }
+        leaders_str
+    }
+}
+
+impl fmt::Display for Season {
+    /// Format a `Season` as its progress through the schedule
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let season_str = format!(
+            "Week {} of {}, {} games played, seed {}",
+            self.num_weeks_played,
+            self.schedule.weeks.len(),
+            self.num_games_played,
+            self.seed
+        );
+        f.write_str(&season_str)
+    }
+}
+
+/// Score a player's offensive production
+fn get_offensive_score(box_score: &PlayerBoxScore) -> f64 {
+    box_score.passing_yards as f64 / 25_f64
+        + 4_f64 * box_score.passing_touchdowns as f64
+        - 2_f64 * box_score.interceptions_thrown as f64
+        + box_score.get_scrimmage_yards() as f64 / 10_f64
+        + 6_f64 * (box_score.rushing_touchdowns + box_score.receiving_touchdowns) as f64
+        - 2_f64 * box_score.fumbles_lost as f64
+}
+
+/// Score a player's defensive production
+fn get_defensive_score(box_score: &PlayerBoxScore) -> f64 {
+    box_score.tackles as f64
+        + 4_f64 * box_score.sacks as f64
+        + 5_f64 * box_score.interceptions as f64
+        + 3_f64 * box_score.fumbles_recovered as f64
+        + 2_f64 * box_score.passes_defended as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Generate a league of four teams, A through D, in one division
+    fn generate_league() -> League {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for abbreviation in ["A", "B", "C", "D"] {
+            let team = generate_test_team(abbreviation, abbreviation, &mut rng);
+            league.add_team(team, "Conference", "Division").unwrap();
+        }
+        league
+    }
+
+    /// Generate a three week round robin schedule for the test league
+    fn generate_schedule(league: &League) -> Schedule {
+        let mut schedule: Schedule = Schedule::new();
+        for pairs in [[("A", "B"), ("C", "D")], [("A", "C"), ("D", "B")], [("D", "A"), ("B", "C")]] {
+            schedule.add_week(league, pairs.iter().map(|(home, away)| Matchup::new(home, away)).collect());
+        }
+        schedule
+    }
+
+    /// Create a player's statistics from yards and takeaways
+    fn create_player_stats(name: &str, team: &str, passing_yards: isize, interceptions: usize) -> PlayerBoxScore {
+        let mut stats: PlayerBoxScore = PlayerBoxScore::new(name, team);
+        stats.passing_yards = passing_yards;
+        stats.interceptions = interceptions;
+        stats
+    }
+
+    #[test]
+    fn test_season_accumulates_game_statistics() {
+        let league: League = generate_league();
+        let schedule: Schedule = generate_schedule(&league);
+        let mut season: Season = Season::new(&league, schedule, 7);
+        let mut games: Vec<Game> = Vec::new();
+        while !season.is_over() {
+            games.extend(season.simulate_week(&league).unwrap());
+        }
+        assert!(season.is_over());
+        assert_eq!(season.num_games_played, 6);
+        assert_eq!(games.len(), 6);
+
+        // Team and player statistics are the sums of their box scores
+        for abbreviation in ["A", "B", "C", "D"] {
+            let points: usize = games.iter().map(|game| match game.home_team.abbreviation == abbreviation {
+                true => game.context.score.home_team_score,
+                false if game.away_team.abbreviation == abbreviation => game.context.score.away_team_score,
+                false => 0
+            }).sum();
+            assert_eq!(season.get_team_stats(abbreviation).unwrap().points, points);
+        }
+        let mut player_yards: BTreeMap<(String, String), isize> = BTreeMap::new();
+        for box_score in games.iter().flat_map(PlayerBoxScore::from_game) {
+            *player_yards.entry((box_score.name.clone(), box_score.team.clone())).or_insert(0) += box_score.get_scrimmage_yards();
+        }
+        assert_eq!(player_yards.len(), season.player_stats.len());
+        for ((name, team), yards) in player_yards.iter() {
+            assert_eq!(season.get_player_stats(name, team).unwrap().get_scrimmage_yards(), *yards);
+        }
+
+        // A season replays the same from its seed
+        let mut replay: Season = Season::new(&league, generate_schedule(&league), 7);
+        replay.simulate(&league).unwrap();
+        assert_eq!(replay.get_team_stats("A").unwrap().points, season.get_team_stats("A").unwrap().points);
+    }
+
+    #[test]
+    fn test_leaders_are_ranked_best_first() {
+        let league: League = generate_league();
+        let mut season: Season = Season::new(&league, Schedule::new(), 0);
+        season.player_stats = vec![
+            create_player_stats("Low", "A", 1200, 0),
+            create_player_stats("High", "B", 4100, 0),
+            create_player_stats("None", "C", 0, 3),
+            create_player_stats("Middle", "D", 2500, 0)
+        ];
+        let leaders: Vec<&str> = season.get_leaders(PlayerStat::PassingYards, 2).iter().map(|stats| stats.name.as_str()).collect();
+        assert_eq!(leaders, vec!["High", "Middle"]);
+        let leaders: Vec<&str> = season.get_leaders(PlayerStat::PassingYards, 10).iter().map(|stats| stats.name.as_str()).collect();
+        assert_eq!(leaders, vec!["High", "Middle", "Low"]);
+        assert_eq!(season.format_leaders(PlayerStat::Interceptions, 5), "Interceptions\n 1. None (C) 3");
+    }
+
+    #[test]
+    fn test_award_candidates_are_ranked_by_score() {
+        let league: League = generate_league();
+        let mut season: Season = Season::new(&league, Schedule::new(), 0);
+        season.standings.add_result(&league, GameResult::new(1, "A", "B", 21, 7)).unwrap();
+        season.player_stats = vec![
+            create_player_stats("Winner", "A", 3000, 0),
+            create_player_stats("Loser", "B", 3500, 0),
+            create_player_stats("Ballhawk", "C", 0, 6)
+        ];
+
+        // The most valuable player's team record outweighs a few yards
+        let mvp: Vec<&str> = season.get_award_candidates(Award::MostValuablePlayer, 3).iter().map(|(stats, _)| stats.name.as_str()).collect();
+        assert_eq!(mvp, vec!["Winner", "Loser"]);
+        let opoy: Vec<&str> = season.get_award_candidates(Award::OffensivePlayerOfTheYear, 1).iter().map(|(stats, _)| stats.name.as_str()).collect();
+        assert_eq!(opoy, vec!["Loser"]);
+        let dpoy: Vec<(&PlayerBoxScore, f64)> = season.get_award_candidates(Award::DefensivePlayerOfTheYear, 3);
+        assert_eq!(dpoy.len(), 1);
+        assert_eq!(dpoy[0].0.name, "Ballhawk");
+        assert_eq!(dpoy[0].1, 30_f64);
+    }
+}
@@ -0,0 +1,706 @@
+use crate::game::game::Game;
+use crate::league::league::{League, LeagueError};
+
+use std::collections::BTreeSet;
+use std::fmt;
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The fewest games against common opponents needed to break a tie between
+/// teams of different divisions on their record in common games
+const MIN_COMMON_GAMES: usize = 4;
+
+/// # GameResult struct
+///
+/// A `GameResult` records the final score of a league game
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameResult {
+    pub week: usize,
+    pub home_team: String,
+    pub away_team: String,
+    pub home_score: usize,
+    pub away_score: usize
+}
+
+impl GameResult {
+    /// Initialize a new game result given the week, teams and score
+    pub fn new(week: usize, home_team: &str, away_team: &str, home_score: usize, away_score: usize) -> GameResult {
+        GameResult {
+            week,
+            home_team: String::from(home_team),
+            away_team: String::from(away_team),
+            home_score,
+            away_score
+        }
+    }
+
+    /// Record the result of a finished game
+    pub fn from_game(week: usize, game: &Game) -> GameResult {
+        GameResult::new(
+            week,
+            &game.home_team.abbreviation,
+            &game.away_team.abbreviation,
+            game.context.score.home_team_score,
+            game.context.score.away_team_score
+        )
+    }
+
+    /// Check whether a team played in the game
+    pub fn involves(&self, abbreviation: &str) -> bool {
+        self.home_team == abbreviation || self.away_team == abbreviation
+    }
+
+    /// Get a team's opponent in the game
+    pub fn get_opponent(&self, abbreviation: &str) -> Option<&str> {
+        if self.home_team == abbreviation {
+            Some(&self.away_team)
+        } else if self.away_team == abbreviation {
+            Some(&self.home_team)
+        } else {
+            None
+        }
+    }
+
+    /// Get the points a team scored and allowed in the game
+    pub fn get_points(&self, abbreviation: &str) -> Option<(usize, usize)> {
+        if self.home_team == abbreviation {
+            Some((self.home_score, self.away_score))
+        } else if self.away_team == abbreviation {
+            Some((self.away_score, self.home_score))
+        } else {
+            None
+        }
+    }
+
+    /// Get the winner of the game, or None for a tie
+    pub fn get_winner(&self) -> Option<&str> {
+        if self.home_score > self.away_score {
+            Some(&self.home_team)
+        } else if self.away_score > self.home_score {
+            Some(&self.away_team)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    /// Format a `GameResult` as away score @ home score
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result_str = format!(
+            "Week {}: {} {} @ {} {}",
+            self.week,
+            self.away_team,
+            self.away_score,
+            self.home_team,
+            self.home_score
+        );
+        f.write_str(&result_str)
+    }
+}
+
+/// # Record struct
+///
+/// A `Record` counts wins, losses and ties
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Record {
+    pub wins: usize,
+    pub losses: usize,
+    pub ties: usize
+}
+
+impl Record {
+    /// Initialize a new empty record
+    pub fn new() -> Record {
+        Record::default()
+    }
+
+    /// Add a game to the record given the points scored and allowed
+    pub fn add_game(&mut self, points_for: usize, points_against: usize) {
+        if points_for > points_against {
+            self.wins += 1;
+        } else if points_for < points_against {
+            self.losses += 1;
+        } else {
+            self.ties += 1;
+        }
+    }
+
+    /// Add another record to this one
+    pub fn add_record(&mut self, other: &Record) {
+        self.wins += other.wins;
+        self.losses += other.losses;
+        self.ties += other.ties;
+    }
+
+    /// Get the number of games played
+    pub fn get_num_games(&self) -> usize {
+        self.wins + self.losses + self.ties
+    }
+
+    /// Get the win percentage, counting ties as half a win.  A team with
+    /// no games has a win percentage of zero.
+    pub fn get_win_percentage(&self) -> f64 {
+        let num_games: usize = self.get_num_games();
+        if num_games == 0 {
+            return 0_f64;
+        }
+        (self.wins as f64 + 0.5_f64 * self.ties as f64) / num_games as f64
+    }
+}
+
+impl fmt::Display for Record {
+    /// Format a `Record` as W-L, or W-L-T if the team has tied
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let record_str = match self.ties {
+            0 => format!("{}-{}", self.wins, self.losses),
+            _ => format!("{}-{}-{}", self.wins, self.losses, self.ties)
+        };
+        f.write_str(&record_str)
+    }
+}
+
+/// # Streak enum
+///
+/// A `Streak` is the run of identical results a team is currently on
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Streak {
+    #[default]
+    None,
+    Won(usize),
+    Lost(usize),
+    Tied(usize)
+}
+
+impl Streak {
+    /// Extend the streak with the result of a game
+    pub fn add_game(&self, points_for: usize, points_against: usize) -> Streak {
+        if points_for > points_against {
+            match self {
+                Streak::Won(length) => Streak::Won(length + 1),
+                _ => Streak::Won(1)
+            }
+        } else if points_for < points_against {
+            match self {
+                Streak::Lost(length) => Streak::Lost(length + 1),
+                _ => Streak::Lost(1)
+            }
+        } else {
+            match self {
+                Streak::Tied(length) => Streak::Tied(length + 1),
+                _ => Streak::Tied(1)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Streak {
+    /// Format a `Streak` as its result and length, such as W3
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let streak_str = match self {
+            Streak::None => String::from("-"),
+            Streak::Won(length) => format!("W{}", length),
+            Streak::Lost(length) => format!("L{}", length),
+            Streak::Tied(length) => format!("T{}", length)
+        };
+        f.write_str(&streak_str)
+    }
+}
+
+/// # TeamStanding struct
+///
+/// A `TeamStanding` holds a team's records and points over a season
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeamStanding {
+    pub abbreviation: String,
+    pub record: Record,
+    pub home_record: Record,
+    pub away_record: Record,
+    pub division_record: Record,
+    pub conference_record: Record,
+    pub points_for: usize,
+    pub points_against: usize,
+    pub streak: Streak
+}
+
+impl TeamStanding {
+    /// Initialize a new empty standing for a team
+    pub fn new(abbreviation: &str) -> TeamStanding {
+        TeamStanding {
+            abbreviation: String::from(abbreviation),
+            ..Default::default()
+        }
+    }
+
+    /// Get the win percentage
+    pub fn get_win_percentage(&self) -> f64 {
+        self.record.get_win_percentage()
+    }
+
+    /// Get the points scored minus the points allowed
+    pub fn get_point_differential(&self) -> isize {
+        self.points_for as isize - self.points_against as isize
+    }
+}
+
+impl fmt::Display for TeamStanding {
+    /// Format a `TeamStanding` as a single standings table row
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let standing_str = format!(
+            "{:<5} {:>8} {:>6.3} {:>8} {:>8} {:>8} {:>8} {:>4} {:>4} {:>5} {:>4}",
+            self.abbreviation,
+            format!("{}", self.record),
+            self.get_win_percentage(),
+            format!("{}", self.home_record),
+            format!("{}", self.away_record),
+            format!("{}", self.division_record),
+            format!("{}", self.conference_record),
+            self.points_for,
+            self.points_against,
+            self.get_point_differential(),
+            format!("{}", self.streak)
+        );
+        f.write_str(&standing_str)
+    }
+}
+
+/// # Tiebreaker enum
+///
+/// A `Tiebreaker` is a single step of the NFL-style tiebreaking procedure.
+/// Teams in the same division are separated by head-to-head record,
+/// division record, record in common games, conference record, strength of
+/// victory, strength of schedule, point differential and finally a coin
+/// flip.  Teams in different divisions skip the division record and only
+/// compare common games given at least four of them.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Tiebreaker {
+    HeadToHead,
+    DivisionRecord,
+    CommonGames,
+    ConferenceRecord,
+    StrengthOfVictory,
+    StrengthOfSchedule,
+    PointDifferential,
+    CoinFlip
+}
+
+/// The tiebreakers between teams of the same division, in order
+const DIVISION_TIEBREAKERS: [Tiebreaker; 8] = [
+    Tiebreaker::HeadToHead,
+    Tiebreaker::DivisionRecord,
+    Tiebreaker::CommonGames,
+    Tiebreaker::ConferenceRecord,
+    Tiebreaker::StrengthOfVictory,
+    Tiebreaker::StrengthOfSchedule,
+    Tiebreaker::PointDifferential,
+    Tiebreaker::CoinFlip
+];
+
+/// The tiebreakers between teams of different divisions, in order
+const CONFERENCE_TIEBREAKERS: [Tiebreaker; 7] = [
+    Tiebreaker::HeadToHead,
+    Tiebreaker::ConferenceRecord,
+    Tiebreaker::CommonGames,
+    Tiebreaker::StrengthOfVictory,
+    Tiebreaker::StrengthOfSchedule,
+    Tiebreaker::PointDifferential,
+    Tiebreaker::CoinFlip
+];
+
+/// # Standings struct
+///
+/// The `Standings` struct tallies every team's standing from the results
+/// of a league's games, and ranks teams using NFL-style tiebreakers
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Standings {
+    pub teams: Vec<TeamStanding>,
+    pub results: Vec<GameResult>
+}
+
+impl Standings {
+    /// Initialize empty standings for every team in a league
+    pub fn new(league: &League) -> Standings {
+        Standings {
+            teams: league.teams.iter().map(|team| TeamStanding::new(&team.abbreviation)).collect(),
+            results: Vec::new()
+        }
+    }
+
+    /// Tally the standings of a league from the results of its games, which
+    /// should be given in the order they were played
+    pub fn from_results(league: &League, results: &[GameResult]) -> Result<Standings, LeagueError> {
+        let mut standings: Standings = Standings::new(league);
+        for result in results.iter() {
+            standings.add_result(league, result.clone())?;
+        }
+        Ok(standings)
+    }
+
+    /// Add the result of a game to the standings
+    pub fn add_result(&mut self, league: &League, result: GameResult) -> Result<(), LeagueError> {
+        for team in [&result.home_team, &result.away_team] {
+            if self.get_team(team).is_none() {
+                return Err(LeagueError::TeamNotFound(team.clone()));
+            }
+        }
+        let is_division_game: bool = league.is_division_game(&result.home_team, &result.away_team);
+        let is_conference_game: bool = league.is_conference_game(&result.home_team, &result.away_team);
+        for (team, is_home) in [(&result.home_team, true), (&result.away_team, false)] {
+            let (points_for, points_against) = result.get_points(team).unwrap();
+            let standing: &mut TeamStanding = self.teams.iter_mut().find(|standing| &standing.abbreviation == team).unwrap();
+            standing.record.add_game(points_for, points_against);
+            match is_home {
+                true => standing.home_record.add_game(points_for, points_against),
+                false => standing.away_record.add_game(points_for, points_against)
+            }
+            if is_division_game {
+                standing.division_record.add_game(points_for, points_against);
+            }
+            if is_conference_game {
+                standing.conference_record.add_game(points_for, points_against);
+            }
+            standing.points_for += points_for;
+            standing.points_against += points_against;
+            standing.streak = standing.streak.add_game(points_for, points_against);
+        }
+        self.results.push(result);
+        Ok(())
+    }
+
+    /// Get a team's standing given its abbreviation
+    pub fn get_team(&self, abbreviation: &str) -> Option<&TeamStanding> {
+        self.teams.iter().find(|standing| standing.abbreviation == abbreviation)
+    }
+
+    /// Get a team's win percentage, or zero if it is not in the standings
+    fn get_win_percentage(&self, abbreviation: &str) -> f64 {
+        self.get_team(abbreviation).map(|standing| standing.get_win_percentage()).unwrap_or(0_f64)
+    }
+
+    /// Get a team's record against a set of opponents
+    pub fn get_record_against(&self, abbreviation: &str, opponents: &[&str]) -> Record {
+        let mut record: Record = Record::new();
+        for result in self.results.iter() {
+            if let Some(opponent) = result.get_opponent(abbreviation) {
+                if opponents.contains(&opponent) {
+                    let (points_for, points_against) = result.get_points(abbreviation).unwrap();
+                    record.add_game(points_for, points_against);
+                }
+            }
+        }
+        record
+    }
+
+    /// Get every opponent a team has played
+    pub fn get_opponents(&self, abbreviation: &str) -> BTreeSet<&str> {
+        self.results.iter()
+            .filter_map(|result| result.get_opponent(abbreviation))
+            .collect()
+    }
+
+    /// Get the combined record of the opponents a team has beaten, counting
+    /// an opponent once for every win against it
+    pub fn get_strength_of_victory(&self, abbreviation: &str) -> f64 {
+        let beaten: Vec<&str> = self.results.iter()
+            .filter(|result| result.get_winner() == Some(abbreviation))
+            .filter_map(|result| result.get_opponent(abbreviation))
+            .collect();
+        self.get_combined_win_percentage(&beaten)
+    }
+
+    /// Get the combined record of the opponents a team has played, counting
+    /// an opponent once for every game against it
+    pub fn get_strength_of_schedule(&self, abbreviation: &str) -> f64 {
+        let played: Vec<&str> = self.results.iter()
+            .filter_map(|result| result.get_opponent(abbreviation))
+            .collect();
+        self.get_combined_win_percentage(&played)
+    }
+
+    /// Get the win percentage of several teams' records combined
+    fn get_combined_win_percentage(&self, teams: &[&str]) -> f64 {
+        let mut record: Record = Record::new();
+        for team in teams.iter() {
+            if let Some(standing) = self.get_team(team) {
+                record.add_record(&standing.record);
+            }
+        }
+        record.get_win_percentage()
+    }
+
+    /// Rank a set of teams by win percentage, breaking ties with NFL-style
+    /// tiebreakers.  As in the NFL, ties within a division are broken
+    /// before teams of different divisions are compared, so each
+    /// division's teams keep their order relative to one another.
+    pub fn rank_teams(&self, league: &League, teams: &[&str], rng: &mut impl Rng) -> Vec<String> {
+        // Rank each division's teams among themselves
+        let mut divisions: Vec<Vec<String>> = Vec::new();
+        let mut division_indices: Vec<Option<(usize, usize)>> = Vec::new();
+        for team in teams.iter() {
+            let division_index: Option<(usize, usize)> = league.get_division_index(team);
+            match division_indices.iter().position(|index| *index == division_index) {
+                Some(index) => divisions[index].push(String::from(*team)),
+                None => {
+                    division_indices.push(division_index);
+                    divisions.push(vec![String::from(*team)]);
+                }
+            }
+        }
+        let mut divisions: Vec<Vec<String>> = divisions.into_iter()
+            .map(|division| {
+                let division: Vec<&str> = division.iter().map(|team| team.as_str()).collect();
+                self.rank_group(&division, &DIVISION_TIEBREAKERS, rng)
+            })
+            .collect();
+        if divisions.len() == 1 {
+            return divisions.remove(0);
+        }
+
+        // Repeatedly take the best of each division's top remaining team
+        let mut ranking: Vec<String> = Vec::with_capacity(teams.len());
+        while divisions.iter().any(|division| !division.is_empty()) {
+            let candidates: Vec<&str> = divisions.iter()
+                .filter_map(|division| division.first().map(|team| team.as_str()))
+                .collect();
+            let best: String = self.rank_group(&candidates, &CONFERENCE_TIEBREAKERS, rng).remove(0);
+            for division in divisions.iter_mut() {
+                division.retain(|team| *team != best);
+            }
+            ranking.push(best);
+        }
+        ranking
+    }
+
+    /// Rank the teams of a division
+    pub fn get_division_ranking(&self, league: &League, conference_index: usize, division_index: usize, rng: &mut impl Rng) -> Vec<String> {
+        let teams: Vec<&str> = league.conferences[conference_index].divisions[division_index].teams.iter()
+            .map(|team| team.as_str())
+            .collect();
+        self.rank_teams(league, &teams, rng)
+    }
+
+    /// Rank the teams of a conference
+    pub fn get_conference_ranking(&self, league: &League, conference_index: usize, rng: &mut impl Rng) -> Vec<String> {
+        let teams: Vec<&str> = league.conferences[conference_index].get_teams();
+        self.rank_teams(league, &teams, rng)
+    }
+
+    /// Format the standings as a table for each division, with teams in
+    /// ranked order
+    pub fn format_standings(&self, league: &League, rng: &mut impl Rng) -> String {
+        let mut standings_str: String = String::new();
+        for (conference_index, conference) in league.conferences.iter().enumerate() {
+            for (division_index, division) in conference.divisions.iter().enumerate() {
+                if !standings_str.is_empty() {
+                    standings_str += "\n\n";
+                }
+                standings_str += &format!(
+                    "{:<5} {:>8} {:>6} {:>8} {:>8} {:>8} {:>8} {:>4} {:>4} {:>5} {:>4}",
+                    format!("{} {}", conference.name, division.name),
+                    "W-L-T", "PCT", "HOME", "AWAY", "DIV", "CONF", "PF", "PA", "DIFF", "STRK"
+                );
+                for team in self.get_division_ranking(league, conference_index, division_index, rng) {
+                    if let Some(standing) = self.get_team(&team) {
+                        standings_str += &format!("\n{}", standing);
+                    }
+                }
+            }
+        }
+        standings_str
+    }
+
+    /// Rank a group of teams by win percentage, breaking ties with the
+    /// given tiebreakers
+    fn rank_group(&self, teams: &[&str], tiebreakers: &[Tiebreaker], rng: &mut impl Rng) -> Vec<String> {
+        let mut remaining: Vec<&str> = teams.to_vec();
+        let mut ranking: Vec<String> = Vec::with_capacity(teams.len());
+        while !remaining.is_empty() {
+            let best_percentage: f64 = remaining.iter()
+                .map(|team| self.get_win_percentage(team))
+                .fold(f64::MIN, f64::max);
+            let tied: Vec<&str> = remaining.iter()
+                .filter(|team| self.get_win_percentage(team) == best_percentage)
+                .copied()
+                .collect();
+            let best: &str = self.break_tie(&tied, tiebreakers, rng);
+            remaining.retain(|team| *team != best);
+            ranking.push(String::from(best));
+        }
+        ranking
+    }
+
+    /// Pick the best of a group of tied teams.  Whenever a tiebreaker
+    /// eliminates some but not all of the teams, the procedure starts over
+    /// with those that remain.
+    fn break_tie<'a>(&self, teams: &[&'a str], tiebreakers: &[Tiebreaker], rng: &mut impl Rng) -> &'a str {
+        let is_division_tie: bool = tiebreakers.contains(&Tiebreaker::DivisionRecord);
+        let mut tied: Vec<&'a str> = teams.to_vec();
+        'restart: while tied.len() > 1 {
+            for tiebreaker in tiebreakers.iter() {
+                let values: Vec<Option<f64>> = tied.iter()
+                    .map(|team| self.get_tiebreaker_value(*tiebreaker, team, &tied, is_division_tie, rng))
+                    .collect();
+                if values.iter().any(|value| value.is_none()) {
+                    continue;
+                }
+                let best: f64 = values.iter().flatten().copied().fold(f64::MIN, f64::max);
+                let num_tied: usize = tied.len();
+                tied = tied.iter()
+                    .zip(values.iter())
+                    .filter(|(_, value)| **value == Some(best))
+                    .map(|(team, _)| *team)
+                    .collect();
+                if tied.len() < num_tied {
+                    continue 'restart;
+                }
+            }
+        }
+        tied[0]
+    }
+
+    /// Get a team's value for a tiebreaker among a group of tied teams,
+    /// where higher is better, or None if the tiebreaker does not apply
+    fn get_tiebreaker_value(&self, tiebreaker: Tiebreaker, team: &str, tied: &[&str], is_division_tie: bool, rng: &mut impl Rng) -> Option<f64> {
+        let standing: &TeamStanding = self.get_team(team)?;
+        match tiebreaker {
+            Tiebreaker::HeadToHead => {
+                // Only applies if every tied team has played every other
+                let others: Vec<&str> = tied.iter().filter(|other| **other != team).copied().collect();
+                let played: BTreeSet<&str> = self.get_opponents(team);
+                if others.iter().any(|other| !played.contains(other)) {
+                    return None;
+                }
+                Some(self.get_record_against(team, &others).get_win_percentage())
+            },
+            Tiebreaker::DivisionRecord => Some(standing.division_record.get_win_percentage()),
+            Tiebreaker::CommonGames => {
+                let mut common: BTreeSet<&str> = self.get_opponents(tied[0]);
+                for other in tied.iter().skip(1) {
+                    let played: BTreeSet<&str> = self.get_opponents(other);
+                    common.retain(|opponent| played.contains(opponent));
+                }
+                let common: Vec<&str> = common.into_iter().filter(|opponent| !tied.contains(opponent)).collect();
+                let record: Record = self.get_record_against(team, &common);
+                if record.get_num_games() == 0 || (!is_division_tie && record.get_num_games() < MIN_COMMON_GAMES) {
+                    return None;
+                }
+                Some(record.get_win_percentage())
+            },
+            Tiebreaker::ConferenceRecord => Some(standing.conference_record.get_win_percentage()),
+            Tiebreaker::StrengthOfVictory => Some(self.get_strength_of_victory(team)),
+            Tiebreaker::StrengthOfSchedule => Some(self.get_strength_of_schedule(team)),
+            Tiebreaker::PointDifferential => Some(standing.get_point_differential() as f64),
+            Tiebreaker::CoinFlip => Some(rng.gen::<f64>())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Generate a league of one conference with two divisions, A through D
+    /// in the first and E through H in the second
+    fn generate_league() -> League {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for (index, abbreviation) in ["A", "B", "C", "D", "E", "F", "G", "H"].iter().enumerate() {
+            let division: &str = match index < 4 {
+                true => "North",
+                false => "South"
+            };
+            let team = generate_test_team(abbreviation, abbreviation, &mut rng);
+            league.add_team(team, "Conference", division).unwrap();
+        }
+        league
+    }
+
+    /// Tally standings from (home, away, home score, away score) results
+    fn get_standings(league: &League, scores: &[(&str, &str, usize, usize)]) -> Standings {
+        let results: Vec<GameResult> = scores.iter()
+            .enumerate()
+            .map(|(week, (home, away, home_score, away_score))| GameResult::new(week + 1, home, away, *home_score, *away_score))
+            .collect();
+        Standings::from_results(league, &results).unwrap()
+    }
+
+    #[test]
+    fn test_head_to_head_breaks_division_tie() {
+        let league: League = generate_league();
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+
+        // A and B are both 1-1, but A beat B despite the worse differential
+        let standings: Standings = get_standings(&league, &[
+            ("A", "B", 10, 7),
+            ("E", "A", 40, 0),
+            ("B", "F", 40, 0)
+        ]);
+        assert_eq!(standings.rank_teams(&league, &["B", "A"], &mut rng), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_division_record_breaks_division_tie() {
+        let league: League = generate_league();
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+
+        // A and B are both 1-1 without playing each other, A winning its
+        // division game and B losing its
+        let standings: Standings = get_standings(&league, &[
+            ("A", "C", 10, 7),
+            ("E", "A", 40, 0),
+            ("D", "B", 10, 7),
+            ("B", "F", 40, 0)
+        ]);
+        assert_eq!(standings.rank_teams(&league, &["B", "A"], &mut rng), vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_common_games_need_four_games_across_divisions() {
+        let league: League = generate_league();
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+
+        // A and E both beat C, their only common opponent, so the tie
+        // falls through to point differential
+        let standings: Standings = get_standings(&league, &[
+            ("A", "C", 30, 0),
+            ("E", "C", 14, 10)
+        ]);
+        assert_eq!(standings.rank_teams(&league, &["E", "A"], &mut rng), vec!["A", "E"]);
+
+        // With four common opponents, A's better record against them wins
+        // out over E's point differential
+        let standings: Standings = get_standings(&league, &[
+            ("A", "C", 10, 7),
+            ("A", "D", 10, 7),
+            ("A", "G", 10, 7),
+            ("H", "A", 10, 7),
+            ("F", "A", 10, 7),
+            ("E", "C", 50, 0),
+            ("E", "D", 50, 0),
+            ("G", "E", 10, 7),
+            ("H", "E", 10, 7),
+            ("E", "B", 50, 0)
+        ]);
+        assert_eq!(standings.get_team("A").unwrap().record.get_win_percentage(), standings.get_team("E").unwrap().record.get_win_percentage());
+        assert_eq!(standings.rank_teams(&league, &["E", "A"], &mut rng), vec!["A", "E"]);
+    }
+
+    #[test]
+    fn test_full_tie_ranks_every_team_once() {
+        let league: League = generate_league();
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let standings: Standings = Standings::new(&league);
+        let mut ranking: Vec<String> = standings.get_conference_ranking(&league, 0, &mut rng);
+        ranking.sort();
+        assert_eq!(ranking, vec!["A", "B", "C", "D", "E", "F", "G", "H"]);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::league::draft::get_team_needs;
+use crate::league::league::{League, LeagueError};
+use crate::team::contract::Contract;
+use crate::team::player::Player;
+use crate::team::position::Position;
+use crate::team::progression::AgingCurve;
+use crate::team::team::Team;
+
+use std::fmt;
+use rand::Rng;
+use rand::distributions::Distribution;
+use statrs::distribution::Normal;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// How much of a player's value a team adds for each point of need at the
+/// player's position
+const NEED_VALUE_WEIGHT: f64 = 0.5_f64;
+
+/// The relative error in a coach's judgment of a player's value, scaled
+/// down as the coach's intelligence goes up
+const MISJUDGMENT_SPREAD: f64 = 0.3_f64;
+
+/// Get a player's trade value in dollars.  A player is worth their market
+/// salary this season, plus a quarter more for each season before they
+/// start to decline, up to four, plus the surplus of their market salary
+/// over their cap hit for each season left on their contract.  Overpaid
+/// players on long contracts can be worth less than nothing.
+pub fn get_player_value(player: &Player) -> f64 {
+    let market_salary: f64 = Contract::get_market_salary(player) as f64;
+    let curve: AgingCurve = AgingCurve::from_position(player.position);
+    let prime_years: f64 = curve.decline_age.saturating_sub(player.age).min(4) as f64;
+    let years_remaining: f64 = player.contract.as_ref().map_or(1, |contract| contract.years_remaining.max(1)) as f64;
+    let surplus: f64 = market_salary - player.get_cap_hit() as f64;
+    market_salary * (1_f64 + 0.25_f64 * prime_years) + surplus * years_remaining
+}
+
+/// # Trade struct
+///
+/// A `Trade` swaps players between two teams, each player named alongside
+/// their position.  The proposing team offers players to the receiving
+/// team in return for the players it requests.  An AI receiving team
+/// accepts a trade if it judges that the players it receives are worth
+/// more than those it gives up, valuing players at needed positions more
+/// highly, with less intelligent coaches misjudging values more.  Traded
+/// players keep their contracts, except that their remaining signing
+/// bonus proration stays on their old team's cap as dead money.  A trade
+/// which would leave either team with an invalid roster or over the
+/// salary cap is rejected without changing either team.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trade {
+    pub proposing_team: String,
+    pub receiving_team: String,
+    pub players_offered: Vec<(String, Position)>,
+    pub players_requested: Vec<(String, Position)>
+}
+
+impl Trade {
+    /// Initialize a new trade given the teams and the players each gives
+    /// up
+    pub fn new(proposing_team: &str, receiving_team: &str, players_offered: Vec<(String, Position)>, players_requested: Vec<(String, Position)>) -> Trade {
+        Trade {
+            proposing_team: String::from(proposing_team),
+            receiving_team: String::from(receiving_team),
+            players_offered,
+            players_requested
+        }
+    }
+
+    /// Get the players a team sends and receives in the trade
+    fn get_players(&self, league: &League, abbreviation: &str) -> Result<(Vec<Player>, Vec<Player>), LeagueError> {
+        let (sent, received, other_team) = if abbreviation == self.proposing_team {
+            (&self.players_offered, &self.players_requested, &self.receiving_team)
+        } else if abbreviation == self.receiving_team {
+            (&self.players_requested, &self.players_offered, &self.proposing_team)
+        } else {
+            return Err(LeagueError::TransactionError(format!("{} is not part of the trade", abbreviation)));
+        };
+        let mut players: Vec<Vec<Player>> = Vec::with_capacity(2);
+        for (team_abbreviation, names) in [(abbreviation, sent), (other_team.as_str(), received)] {
+            let roster: Vec<Player> = match league.get_team(team_abbreviation) {
+                Some(team) => team.get_roster(),
+                None => return Err(LeagueError::TeamNotFound(String::from(team_abbreviation)))
+            };
+            let mut team_players: Vec<Player> = Vec::with_capacity(names.len());
+            for (name, position) in names.iter() {
+                match roster.iter().find(|player| player.name == *name && player.position == *position) {
+                    Some(player) => team_players.push(player.clone()),
+                    None => return Err(LeagueError::TransactionError(format!("{} ({}) is not on {}", name, position, team_abbreviation)))
+                }
+            }
+            players.push(team_players);
+        }
+        let received_players: Vec<Player> = players.pop().unwrap();
+        let sent_players: Vec<Player> = players.pop().unwrap();
+        Ok((sent_players, received_players))
+    }
+
+    /// Get the net value of the trade for one of its teams, valuing the
+    /// players it receives more highly at positions of need
+    pub fn get_value(&self, league: &League, abbreviation: &str) -> Result<f64, LeagueError> {
+        let (sent, received) = self.get_players(league, abbreviation)?;
+        let team: &Team = match league.get_team(abbreviation) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(String::from(abbreviation)))
+        };
+        let needs: Vec<(Position, f64)> = get_team_needs(team);
+        let received_value: f64 = received.iter()
+            .map(|player| {
+                let need: f64 = needs.iter()
+                    .find(|(position, _)| *position == player.position)
+                    .map(|(_, need)| *need)
+                    .unwrap_or(0_f64);
+                get_player_value(player) * (1_f64 + NEED_VALUE_WEIGHT * need)
+            })
+            .sum();
+        let sent_value: f64 = sent.iter().map(get_player_value).sum();
+        Ok(received_value - sent_value)
+    }
+
+    /// Decide whether the AI receiving team accepts the trade
+    pub fn is_accepted(&self, league: &League, mut rng: &mut impl Rng) -> Result<bool, LeagueError> {
+        let team: &Team = match league.get_team(&self.receiving_team) {
+            Some(team) => team,
+            None => return Err(LeagueError::TeamNotFound(self.receiving_team.clone()))
+        };
+        let value: f64 = self.get_value(league, &self.receiving_team)?;
+        let (sent, received) = self.get_players(league, &self.receiving_team)?;
+        let total_value: f64 = sent.iter().chain(received.iter()).map(|player| get_player_value(player).abs()).sum();
+        let intelligence: f64 = team.coach.intelligence.min(10) as f64 / 10_f64;
+        let dst_misjudgment: Normal = Normal::new(0_f64, (MISJUDGMENT_SPREAD * (1_f64 - intelligence) * total_value).max(f64::MIN_POSITIVE)).unwrap();
+        Ok(value + dst_misjudgment.sample(&mut rng) > 0_f64)
+    }
+
+    /// Execute the trade, updating and validating both rosters.  If either
+    /// team would be left with an invalid roster or over the salary cap,
+    /// the trade is rejected and neither team changes.
+    pub fn execute(&self, league: &mut League) -> Result<(), LeagueError> {
+        if self.proposing_team == self.receiving_team {
+            return Err(LeagueError::TransactionError(format!("{} cannot trade with itself", self.proposing_team)));
+        }
+        self.get_players(league, &self.proposing_team)?;
+        let mut proposing_team: Team = league.get_team(&self.proposing_team).unwrap().clone();
+        let mut receiving_team: Team = league.get_team(&self.receiving_team).unwrap().clone();
+
+        // Swap the players
+        let offered_players: Vec<Player> = remove_traded_players(&mut proposing_team, &self.players_offered)?;
+        let requested_players: Vec<Player> = remove_traded_players(&mut receiving_team, &self.players_requested)?;
+        for player in offered_players {
+            receiving_team.sign_player(player);
+        }
+        for player in requested_players {
+            proposing_team.sign_player(player);
+        }
+
+        // Validate both teams before committing the trade
+        for team in [&proposing_team, &receiving_team] {
+            if let Err(err) = team.validate() {
+                return Err(LeagueError::TransactionError(err.to_string()));
+            }
+            if let Err(err) = team.validate_salary_cap(league.salary_cap) {
+                return Err(LeagueError::TransactionError(err.to_string()));
+            }
+        }
+        *league.get_team_mut(&self.proposing_team).unwrap() = proposing_team;
+        *league.get_team_mut(&self.receiving_team).unwrap() = receiving_team;
+        Ok(())
+    }
+
+    /// Propose the trade to an AI receiving team, executing it if they
+    /// accept.  Returns whether the trade was accepted.
+    pub fn propose(&self, league: &mut League, rng: &mut impl Rng) -> Result<bool, LeagueError> {
+        if !self.is_accepted(league, rng)? {
+            return Ok(false);
+        }
+        self.execute(league)?;
+        Ok(true)
+    }
+}
+
+impl fmt::Display for Trade {
+    /// Format a `Trade` as the players each team gives up
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let offered: Vec<&str> = self.players_offered.iter().map(|(name, _)| name.as_str()).collect();
+        let requested: Vec<&str> = self.players_requested.iter().map(|(name, _)| name.as_str()).collect();
+        let trade_str = format!(
+            "{} trades {} to {} for {}",
+            self.proposing_team,
+            offered.join(", "),
+            self.receiving_team,
+            requested.join(", ")
+        );
+        f.write_str(&trade_str)
+    }
+}
+
+/// Remove traded players from a team, leaving their remaining signing
+/// bonus proration on the team's cap as dead money
+fn remove_traded_players(team: &mut Team, players: &[(String, Position)]) -> Result<Vec<Player>, LeagueError> {
+    let mut traded: Vec<Player> = Vec::with_capacity(players.len());
+    for (name, position) in players.iter() {
+        let mut player: Player = match team.remove_player(name, *position) {
+            Ok(player) => player,
+            Err(err) => return Err(LeagueError::TransactionError(err.to_string()))
+        };
+        if let Some(contract) = player.contract.as_mut() {
+            team.dead_money += contract.get_remaining_proration();
+            contract.signing_bonus = 0;
+        }
+        traded.push(player);
+    }
+    Ok(traded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Generate a league of two teams
+    fn generate_league() -> League {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut league: League = League::new("Test League");
+        for abbreviation in ["A", "B"].iter() {
+            let team = generate_test_team(abbreviation, abbreviation, &mut rng);
+            league.add_team(team, "Conference", "Division").unwrap();
+        }
+        league
+    }
+
+    /// Get a team's roster as names and positions, along with its dead
+    /// money
+    fn get_team_state(league: &League, abbreviation: &str) -> (Vec<(String, Position)>, u64) {
+        let team: &Team = league.get_team(abbreviation).unwrap();
+        let roster: Vec<(String, Position)> = team.get_roster().into_iter().map(|player| (player.name, player.position)).collect();
+        (roster, team.dead_money)
+    }
+
+    /// Get the names and positions of a team's players at a position
+    fn get_players_at(league: &League, abbreviation: &str, position: Position) -> Vec<(String, Position)> {
+        league.get_team(abbreviation).unwrap().get_roster().into_iter()
+            .filter(|player| player.position == position)
+            .map(|player| (player.name, player.position))
+            .collect()
+    }
+
+    #[test]
+    fn test_execute_swaps_players() {
+        let mut league: League = generate_league();
+        let offered: Vec<(String, Position)> = get_players_at(&league, "A", Position::WideReceiver)[..1].to_vec();
+        let requested: Vec<(String, Position)> = get_players_at(&league, "B", Position::WideReceiver)[..1].to_vec();
+        Trade::new("A", "B", offered.clone(), requested.clone()).execute(&mut league).unwrap();
+        assert!(get_players_at(&league, "B", Position::WideReceiver).contains(&offered[0]));
+        assert!(get_players_at(&league, "A", Position::WideReceiver).contains(&requested[0]));
+        assert!(!get_players_at(&league, "A", Position::WideReceiver).contains(&offered[0]));
+    }
+
+    #[test]
+    fn test_execute_rolls_back_invalid_roster() {
+        let mut league: League = generate_league();
+        let before_a = get_team_state(&league, "A");
+        let before_b = get_team_state(&league, "B");
+
+        // Trading away every quarterback leaves the offense unable to play
+        let quarterbacks: Vec<(String, Position)> = get_players_at(&league, "A", Position::Quarterback);
+        let kicker: Vec<(String, Position)> = get_players_at(&league, "B", Position::Kicker)[..1].to_vec();
+        let result = Trade::new("A", "B", quarterbacks, kicker).execute(&mut league);
+        assert!(matches!(result, Err(LeagueError::TransactionError(message)) if message.contains("quarterback")));
+        assert_eq!(get_team_state(&league, "A"), before_a);
+        assert_eq!(get_team_state(&league, "B"), before_b);
+    }
+
+    #[test]
+    fn test_execute_rolls_back_cap_overage() {
+        let mut league: League = generate_league();
+
+        // Leave team A no room under the cap, then have it take on a more
+        // expensive player than it sends away
+        let cheapest = |roster: Vec<Player>| roster.into_iter().min_by_key(|player| player.get_cap_hit()).unwrap();
+        let priciest = |roster: Vec<Player>| roster.into_iter().max_by_key(|player| player.get_cap_hit()).unwrap();
+        let sent: Player = cheapest(league.get_team("A").unwrap().get_roster());
+        let received: Player = priciest(league.get_team("B").unwrap().get_roster());
+        assert!(received.get_cap_hit() > sent.get_cap_hit());
+        league.salary_cap = league.get_team("A").unwrap().get_cap_hit();
+        let before_a = get_team_state(&league, "A");
+        let before_b = get_team_state(&league, "B");
+
+        let trade: Trade = Trade::new("A", "B", vec![(sent.name, sent.position)], vec![(received.name, received.position)]);
+        let result = trade.execute(&mut league);
+        assert!(matches!(result, Err(LeagueError::TransactionError(message)) if message.contains("salary cap")));
+        assert_eq!(get_team_state(&league, "A"), before_a);
+        assert_eq!(get_team_state(&league, "B"), before_b);
+    }
+
+    #[test]
+    fn test_execute_rejects_unknown_players_and_self_trades() {
+        let mut league: League = generate_league();
+        let kicker: Vec<(String, Position)> = get_players_at(&league, "A", Position::Kicker)[..1].to_vec();
+        let missing: Vec<(String, Position)> = vec![(String::from("Nobody"), Position::Kicker)];
+        assert!(matches!(Trade::new("A", "B", missing, Vec::new()).execute(&mut league), Err(LeagueError::TransactionError(_))));
+        assert!(matches!(Trade::new("A", "A", kicker, Vec::new()).execute(&mut league), Err(LeagueError::TransactionError(_))));
+    }
+}
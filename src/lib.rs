@@ -0,0 +1,66 @@
+// Each module directory holds a file of the same name with its core type,
+// e.g. game::game::Game, which is the layout the crate has always used
+#![allow(clippy::module_inception)]
+
+pub mod game {
+    pub mod game;
+    pub mod batch;
+    pub mod boxscore;
+    pub mod coinflip;
+    pub mod drive;
+    pub mod environment;
+    pub mod epa;
+    pub mod export;
+    pub mod fatigue;
+    pub mod homefield;
+    pub mod injury;
+    pub mod log;
+    pub mod overtime;
+    pub mod play;
+    pub mod snapshot;
+    pub mod winprob;
+    pub mod context {
+        pub mod clock;
+        pub mod context;
+        pub mod possession;
+        pub mod score;
+    }
+}
+pub mod league {
+    pub mod league;
+    pub mod draft;
+    pub mod franchise;
+    pub mod freeagency;
+    pub mod playoffs;
+    pub mod ratings;
+    pub mod schedule;
+    pub mod season;
+    pub mod standings;
+    pub mod trade;
+}
+pub mod team {
+    pub mod team;
+    pub mod attributes;
+    pub mod coach;
+    pub mod contract;
+    pub mod generator;
+    pub mod personnel;
+    pub mod playcall;
+    pub mod player;
+    pub mod players;
+    pub mod position;
+    pub mod progression;
+    pub mod roster;
+    pub mod units {
+        pub mod defense {
+            pub mod defense;
+        }
+        pub mod offense {
+            pub mod offense;
+        }
+        pub mod specialteams {
+            pub mod specialteams;
+        }
+    }
+}
+
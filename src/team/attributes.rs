@@ -0,0 +1,351 @@
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # Attribute enum
+///
+/// An `Attribute` represents a single rating of a football player
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Attribute {
+    Speed,
+    Strength,
+    Agility,
+    Awareness,
+    Accuracy,
+    ArmStrength,
+    Carrying,
+    Catching,
+    RouteRunning,
+    RunBlock,
+    PassBlock,
+    Tackling,
+    PassRush,
+    Coverage,
+    KickPower,
+    KickAccuracy
+}
+
+impl Attribute {
+    /// All player attributes
+    pub const ALL: [Attribute; 16] = [
+        Attribute::Speed,
+        Attribute::Strength,
+        Attribute::Agility,
+        Attribute::Awareness,
+        Attribute::Accuracy,
+        Attribute::ArmStrength,
+        Attribute::Carrying,
+        Attribute::Catching,
+        Attribute::RouteRunning,
+        Attribute::RunBlock,
+        Attribute::PassBlock,
+        Attribute::Tackling,
+        Attribute::PassRush,
+        Attribute::Coverage,
+        Attribute::KickPower,
+        Attribute::KickAccuracy
+    ];
+}
+
+/// # AttributeWeights type
+///
+/// An `AttributeWeights` lists the attributes which make up a position's
+/// overall rating and the weight of each.  The weights of each table sum
+/// to one so that overall ratings share the 0-10 scale of the attributes.
+pub type AttributeWeights = &'static [(Attribute, f64)];
+
+pub const QUARTERBACK_WEIGHTS: AttributeWeights = &[
+    (Attribute::Accuracy, 0.35),
+    (Attribute::ArmStrength, 0.2),
+    (Attribute::Awareness, 0.3),
+    (Attribute::Agility, 0.1),
+    (Attribute::Speed, 0.05)
+];
+
+pub const RUNNINGBACK_WEIGHTS: AttributeWeights = &[
+    (Attribute::Speed, 0.25),
+    (Attribute::Agility, 0.25),
+    (Attribute::Carrying, 0.2),
+    (Attribute::Strength, 0.1),
+    (Attribute::Catching, 0.1),
+    (Attribute::Awareness, 0.05),
+    (Attribute::PassBlock, 0.05)
+];
+
+pub const FULLBACK_WEIGHTS: AttributeWeights = &[
+    (Attribute::RunBlock, 0.3),
+    (Attribute::Strength, 0.25),
+    (Attribute::Carrying, 0.15),
+    (Attribute::PassBlock, 0.1),
+    (Attribute::Catching, 0.1),
+    (Attribute::Awareness, 0.1)
+];
+
+pub const WIDE_RECEIVER_WEIGHTS: AttributeWeights = &[
+    (Attribute::Catching, 0.25),
+    (Attribute::RouteRunning, 0.25),
+    (Attribute::Speed, 0.25),
+    (Attribute::Agility, 0.15),
+    (Attribute::Awareness, 0.1)
+];
+
+pub const TIGHT_END_WEIGHTS: AttributeWeights = &[
+    (Attribute::Catching, 0.2),
+    (Attribute::RouteRunning, 0.15),
+    (Attribute::RunBlock, 0.2),
+    (Attribute::PassBlock, 0.1),
+    (Attribute::Strength, 0.15),
+    (Attribute::Speed, 0.1),
+    (Attribute::Awareness, 0.1)
+];
+
+pub const OFFENSIVE_LINE_WEIGHTS: AttributeWeights = &[
+    (Attribute::RunBlock, 0.35),
+    (Attribute::PassBlock, 0.35),
+    (Attribute::Strength, 0.2),
+    (Attribute::Awareness, 0.1)
+];
+
+pub const EDGE_RUSHER_WEIGHTS: AttributeWeights = &[
+    (Attribute::PassRush, 0.35),
+    (Attribute::Speed, 0.15),
+    (Attribute::Strength, 0.15),
+    (Attribute::Tackling, 0.2),
+    (Attribute::Awareness, 0.15)
+];
+
+pub const DEFENSIVE_TACKLE_WEIGHTS: AttributeWeights = &[
+    (Attribute::Strength, 0.3),
+    (Attribute::PassRush, 0.25),
+    (Attribute::Tackling, 0.3),
+    (Attribute::Awareness, 0.15)
+];
+
+pub const LINEBACKER_WEIGHTS: AttributeWeights = &[
+    (Attribute::Tackling, 0.3),
+    (Attribute::Awareness, 0.2),
+    (Attribute::Speed, 0.15),
+    (Attribute::Coverage, 0.15),
+    (Attribute::Strength, 0.1),
+    (Attribute::PassRush, 0.1)
+];
+
+pub const CORNERBACK_WEIGHTS: AttributeWeights = &[
+    (Attribute::Coverage, 0.4),
+    (Attribute::Speed, 0.25),
+    (Attribute::Agility, 0.15),
+    (Attribute::Awareness, 0.1),
+    (Attribute::Tackling, 0.1)
+];
+
+pub const SAFETY_WEIGHTS: AttributeWeights = &[
+    (Attribute::Coverage, 0.3),
+    (Attribute::Tackling, 0.2),
+    (Attribute::Speed, 0.2),
+    (Attribute::Awareness, 0.2),
+    (Attribute::Agility, 0.1)
+];
+
+pub const KICKER_WEIGHTS: AttributeWeights = &[
+    (Attribute::KickPower, 0.45),
+    (Attribute::KickAccuracy, 0.55)
+];
+
+pub const PUNTER_WEIGHTS: AttributeWeights = &[
+    (Attribute::KickPower, 0.55),
+    (Attribute::KickAccuracy, 0.45)
+];
+
+pub const LONG_SNAPPER_WEIGHTS: AttributeWeights = &[
+    (Attribute::Awareness, 0.4),
+    (Attribute::Strength, 0.3),
+    (Attribute::RunBlock, 0.3)
+];
+
+pub const RETURNER_WEIGHTS: AttributeWeights = &[
+    (Attribute::Speed, 0.35),
+    (Attribute::Agility, 0.3),
+    (Attribute::Carrying, 0.2),
+    (Attribute::Awareness, 0.15)
+];
+
+/// # PlayerAttributes struct
+///
+/// A `PlayerAttributes` holds a football player's ratings, each on a
+/// scale of 0 to 10
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayerAttributes {
+    pub speed: usize,
+    pub strength: usize,
+    pub agility: usize,
+    pub awareness: usize,
+    pub accuracy: usize,
+    pub arm_strength: usize,
+    pub carrying: usize,
+    pub catching: usize,
+    pub route_running: usize,
+    pub run_block: usize,
+    pub pass_block: usize,
+    pub tackling: usize,
+    pub pass_rush: usize,
+    pub coverage: usize,
+    pub kick_power: usize,
+    pub kick_accuracy: usize
+}
+
+impl PlayerAttributes {
+    /// Initialize a new PlayerAttributes with every attribute set to the
+    /// same rating
+    pub fn new(rating: usize) -> PlayerAttributes {
+        PlayerAttributes {
+            speed: rating,
+            strength: rating,
+            agility: rating,
+            awareness: rating,
+            accuracy: rating,
+            arm_strength: rating,
+            carrying: rating,
+            catching: rating,
+            route_running: rating,
+            run_block: rating,
+            pass_block: rating,
+            tackling: rating,
+            pass_rush: rating,
+            coverage: rating,
+            kick_power: rating,
+            kick_accuracy: rating
+        }
+    }
+
+    /// Initialize a new PlayerAttributes from the six general ratings
+    /// used before position-specific attributes were introduced
+    pub fn from_general(throwing: usize, catching: usize, running: usize, blocking: usize, tackling: usize, kicking: usize) -> PlayerAttributes {
+        PlayerAttributes {
+            speed: running,
+            strength: (blocking + tackling) / 2,
+            agility: running,
+            awareness: (throwing + catching + running + blocking + tackling + kicking) / 6,
+            accuracy: throwing,
+            arm_strength: throwing,
+            carrying: running,
+            catching,
+            route_running: catching,
+            run_block: blocking,
+            pass_block: blocking,
+            tackling,
+            pass_rush: (tackling + running) / 2,
+            coverage: (tackling + running) / 2,
+            kick_power: kicking,
+            kick_accuracy: kicking
+        }
+    }
+
+    /// Get the rating of a single attribute
+    pub fn get(&self, attribute: Attribute) -> usize {
+        match attribute {
+            Attribute::Speed => self.speed,
+            Attribute::Strength => self.strength,
+            Attribute::Agility => self.agility,
+            Attribute::Awareness => self.awareness,
+            Attribute::Accuracy => self.accuracy,
+            Attribute::ArmStrength => self.arm_strength,
+            Attribute::Carrying => self.carrying,
+            Attribute::Catching => self.catching,
+            Attribute::RouteRunning => self.route_running,
+            Attribute::RunBlock => self.run_block,
+            Attribute::PassBlock => self.pass_block,
+            Attribute::Tackling => self.tackling,
+            Attribute::PassRush => self.pass_rush,
+            Attribute::Coverage => self.coverage,
+            Attribute::KickPower => self.kick_power,
+            Attribute::KickAccuracy => self.kick_accuracy
+        }
+    }
+
+    /// Get a mutable reference to a single attribute
+    pub fn get_mut(&mut self, attribute: Attribute) -> &mut usize {
+        match attribute {
+            Attribute::Speed => &mut self.speed,
+            Attribute::Strength => &mut self.strength,
+            Attribute::Agility => &mut self.agility,
+            Attribute::Awareness => &mut self.awareness,
+            Attribute::Accuracy => &mut self.accuracy,
+            Attribute::ArmStrength => &mut self.arm_strength,
+            Attribute::Carrying => &mut self.carrying,
+            Attribute::Catching => &mut self.catching,
+            Attribute::RouteRunning => &mut self.route_running,
+            Attribute::RunBlock => &mut self.run_block,
+            Attribute::PassBlock => &mut self.pass_block,
+            Attribute::Tackling => &mut self.tackling,
+            Attribute::PassRush => &mut self.pass_rush,
+            Attribute::Coverage => &mut self.coverage,
+            Attribute::KickPower => &mut self.kick_power,
+            Attribute::KickAccuracy => &mut self.kick_accuracy
+        }
+    }
+
+    /// Calculate a weighted overall rating given a position's weights
+    pub fn get_weighted_overall(&self, weights: AttributeWeights) -> f64 {
+        let mut overall: f64 = 0.0_f64;
+        for (attribute, weight) in weights.iter() {
+            overall += self.get(*attribute) as f64 * weight;
+        }
+        overall
+    }
+
+    /// Calculate the average of two attributes, which the simulation uses
+    /// for skills that draw on more than one attribute
+    pub fn get_average(&self, first: Attribute, second: Attribute) -> f64 {
+        (self.get(first) + self.get(second)) as f64 / 2_f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::position::Position;
+
+    #[test]
+    fn test_position_weights_sum_to_one() {
+        for position in Position::ALL.iter() {
+            let total: f64 = position.get_weights().iter().map(|(_, weight)| weight).sum();
+            assert!((total - 1_f64).abs() < 1e-9, "{} weights sum to {}", position.format_position(), total);
+            assert!((PlayerAttributes::new(7).get_weighted_overall(position.get_weights()) - 7_f64).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_get_mut_sets_only_its_attribute() {
+        for (i, attribute) in Attribute::ALL.iter().enumerate() {
+            let mut attributes: PlayerAttributes = PlayerAttributes::new(0);
+            *attributes.get_mut(*attribute) = 9;
+            for (j, other) in Attribute::ALL.iter().enumerate() {
+                assert_eq!(attributes.get(*other), if i == j { 9 } else { 0 });
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_general_spreads_ratings() {
+        let attributes: PlayerAttributes = PlayerAttributes::from_general(8, 6, 4, 2, 10, 0);
+        assert_eq!(attributes.get(Attribute::Accuracy), 8);
+        assert_eq!(attributes.get(Attribute::RouteRunning), 6);
+        assert_eq!(attributes.get(Attribute::Speed), 4);
+        assert_eq!(attributes.get(Attribute::PassBlock), 2);
+        assert_eq!(attributes.get(Attribute::Tackling), 10);
+        assert_eq!(attributes.get(Attribute::KickPower), 0);
+        assert_eq!(attributes.get(Attribute::Strength), 6);
+        assert_eq!(attributes.get(Attribute::Awareness), 5);
+        assert_eq!(attributes.get_average(Attribute::Accuracy, Attribute::Tackling), 9_f64);
+    }
+
+    #[test]
+    fn test_player_takes_best_fitting_position() {
+        let attributes: PlayerAttributes = PlayerAttributes::from_general(1, 1, 1, 1, 1, 10);
+        let position: Position = Position::get_best_fit(&attributes);
+        assert!(position == Position::Kicker || position == Position::Punter);
+        for other in Position::ALL.iter() {
+            assert!(position.get_overall(&attributes) >= other.get_overall(&attributes));
+        }
+    }
+}
@@ -1,17 +1,26 @@
+use crate::game::context::clock::GameQuarter;
 use crate::game::context::context::GameContext;
+use crate::game::context::possession::GameDown;
+use crate::team::personnel::{OffensivePersonnel, OffensiveFormation, DefensivePersonnel};
 use crate::team::playcall::{
     OffensivePlayCall,
     OffensivePlay,
     DefensivePlayCall,
     DefensivePlay,
+    FourthDownDecision,
     PlayCaller
 };
 
 use rand::Rng;
+use rand::distributions::{Distribution, Bernoulli};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # OffensiveStyle enum
 ///
 /// An `OffensiveStyle` represents a coaching style for offense
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OffensiveStyle {
     Run,
     Balanced,
@@ -21,6 +30,8 @@ pub enum OffensiveStyle {
 /// # DefensiveStyle enum
 ///
 /// A `DefensiveStyle` represents a coaching style for defense
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DefensiveStyle {
     Coverage,
     Balanced,
@@ -30,6 +41,8 @@ pub enum DefensiveStyle {
 /// # Coach struct
 ///
 /// A `Coach` represents a football coach
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Coach {
     pub name: String,
     pub aggressiveness: usize,
@@ -54,8 +67,132 @@ impl Coach {
 }
 
 impl PlayCaller for Coach {
+    /// Generate the coach's offensive personnel grouping
+    fn generate_offensive_personnel(&self, context: &GameContext, _is_home: bool, mut rng: &mut impl Rng) -> OffensivePersonnel {
+        /*Initialize weights for 11, 12, 21, empty, and goal line personnel*/
+        let mut weights: [f64; 5] = [0.55_f64, 0.2_f64, 0.1_f64, 0.05_f64, 0.0_f64];
+
+        /*Tweak weights based on game context & coaching style*/
+        // Tweak weights based on coaching style
+        match self.offensive_style {
+            OffensiveStyle::Run => {
+                weights[0] -= 0.15_f64;
+                weights[1] += 0.1_f64;
+                weights[2] += 0.1_f64;
+            },
+            OffensiveStyle::Balanced => {},
+            OffensiveStyle::Pass => {
+                weights[0] += 0.1_f64;
+                weights[2] -= 0.05_f64;
+                weights[3] += 0.05_f64;
+            }
+        }
+
+        // Tweak empty personnel based on aggressiveness
+        weights[3] += 0.01_f64 * self.aggressiveness as f64;
+
+        // Tweak weights based on the distance to the first down & endzone
+        let yards_to_endzone: isize = context.possession.get_yards_to_endzone();
+        let yards_to_first: isize = context.possession.get_yards_to_first();
+        if yards_to_endzone <= 2 {
+            weights[4] += 1_f64;
+        } else if yards_to_endzone <= 5 {
+            weights[4] += 0.2_f64;
+        }
+        if yards_to_first <= 2 {
+            weights[1] += 0.15_f64;
+            weights[2] += 0.15_f64;
+            weights[4] += 0.1_f64;
+        } else if yards_to_first >= 8 && (context.possession.down == GameDown::Third || context.possession.down == GameDown::Fourth) {
+            weights[0] += 0.2_f64;
+            weights[3] += 0.2_f64;
+        }
+
+        /*Randomly generate a personnel grouping*/
+        for weight in weights.iter_mut() {
+            *weight = weight.max(0_f64);
+        }
+        OffensivePersonnel::generate(&weights, &mut rng)
+    }
+
+    /// Generate the coach's defensive personnel grouping in response to
+    /// the offensive personnel grouping
+    fn generate_defensive_personnel(&self, context: &GameContext, _is_home: bool, offensive_personnel: OffensivePersonnel, mut rng: &mut impl Rng) -> DefensivePersonnel {
+        /*Initialize weights for base, nickel, dime, and goal line personnel*/
+        // Start from the grouping which matches the offensive personnel
+        let matched: [f64; 4] = match offensive_personnel {
+            OffensivePersonnel::Eleven => [0.25_f64, 0.65_f64, 0.1_f64, 0_f64],
+            OffensivePersonnel::Twelve => [0.6_f64, 0.35_f64, 0.05_f64, 0_f64],
+            OffensivePersonnel::TwentyOne => [0.75_f64, 0.2_f64, 0_f64, 0.05_f64],
+            OffensivePersonnel::Empty => [0.05_f64, 0.4_f64, 0.55_f64, 0_f64],
+            OffensivePersonnel::GoalLine => [0.15_f64, 0_f64, 0_f64, 0.85_f64]
+        };
+
+        // Smarter coaches match the offensive personnel more reliably
+        let p_matched: f64 = (0.5_f64 + 0.05_f64 * self.intelligence as f64).min(1_f64);
+        let mut weights: [f64; 4] = [0_f64; 4];
+        for (i, weight) in weights.iter_mut().enumerate() {
+            *weight = p_matched * matched[i] + (1_f64 - p_matched) * 0.25_f64;
+        }
+
+        /*Tweak weights based on game context & coaching style*/
+        // Tweak weights based on coaching style
+        match self.defensive_style {
+            DefensiveStyle::Coverage => {
+                weights[1] += 0.05_f64;
+                weights[2] += 0.1_f64;
+            },
+            DefensiveStyle::Balanced => {},
+            DefensiveStyle::Blitz => {
+                weights[0] += 0.1_f64;
+            }
+        }
+
+        // Tweak weights based on the distance to the first down
+        if context.possession.get_yards_to_first() >= 8 && (context.possession.down == GameDown::Third || context.possession.down == GameDown::Fourth) {
+            weights[2] += 0.3_f64;
+        }
+
+        /*Randomly generate a personnel grouping*/
+        DefensivePersonnel::generate(&weights, &mut rng)
+    }
+
+    /// Generate the formation the coach's offensive personnel grouping
+    /// lines up in
+    fn generate_offensive_formation(&self, context: &GameContext, _is_home: bool, personnel: OffensivePersonnel, mut rng: &mut impl Rng) -> OffensiveFormation {
+        /*Initialize weights for shotgun, singleback, and I formation*/
+        let mut weights: [f64; 3] = [0.5_f64, 0.3_f64, 0.2_f64];
+
+        /*Tweak weights based on game context & coaching style*/
+        // Tweak weights based on coaching style
+        match self.offensive_style {
+            OffensiveStyle::Run => {
+                weights[0] -= 0.2_f64;
+                weights[2] += 0.2_f64;
+            },
+            OffensiveStyle::Balanced => {},
+            OffensiveStyle::Pass => {
+                weights[0] += 0.3_f64;
+                weights[2] -= 0.1_f64;
+            }
+        }
+
+        // Tweak weights based on the distance to the first down, lining up
+        // under center in short yardage and in the shotgun in long yardage
+        let yards_to_first: isize = context.possession.get_yards_to_first();
+        if yards_to_first <= 2 {
+            weights[1] += 0.2_f64;
+            weights[2] += 0.4_f64;
+        } else if yards_to_first >= 8 {
+            weights[0] += 0.5_f64;
+        }
+
+        /*Randomly generate a formation the personnel can line up in*/
+        OffensiveFormation::generate(personnel, &weights, &mut rng)
+    }
+
     /// Expected to generate the playcaller's offensive play call
-    fn generate_offensive_playcall(&self, context: &GameContext, is_home: bool, mut rng: &mut impl Rng) -> Box<dyn OffensivePlayCall> {
+    fn generate_offensive_playcall(&self, context: &GameContext, is_home: bool, personnel: OffensivePersonnel, formation: OffensiveFormation, mut rng: &mut impl Rng) -> Box<dyn OffensivePlayCall> {
         /*Initialize probabilities for pass/run, inside/outside, pass depth*/
         let mut p_run: f64 = 0.5_f64;
         let mut p_run_sum_count: usize = 1_usize;
@@ -103,19 +240,35 @@ impl PlayCaller for Coach {
             (p_run, p_run_sum_count) = inc_prob(p_run, p_run_sum_count, 0.5_f64);
         }
 
+        // Tweak run probability based on the personnel grouping
+        (p_run, p_run_sum_count) = match personnel {
+            OffensivePersonnel::Eleven => inc_prob(p_run, p_run_sum_count, 0.4_f64),
+            OffensivePersonnel::Twelve => inc_prob(p_run, p_run_sum_count, 0.6_f64),
+            OffensivePersonnel::TwentyOne => inc_prob(p_run, p_run_sum_count, 0.75_f64),
+            OffensivePersonnel::Empty => inc_prob(p_run, p_run_sum_count, 0.05_f64),
+            OffensivePersonnel::GoalLine => inc_prob(p_run, p_run_sum_count, 1_f64)
+        };
+
+        // Tweak run probability based on the formation
+        p_run = match formation {
+            OffensiveFormation::Shotgun => inc_prob(p_run, p_run_sum_count, 0.3_f64).0,
+            OffensiveFormation::Singleback => inc_prob(p_run, p_run_sum_count, 0.55_f64).0,
+            OffensiveFormation::IFormation => inc_prob(p_run, p_run_sum_count, 0.75_f64).0
+        };
+
         /*Randomly generate a play call*/
         Box::new(OffensivePlay::generate(p_run, p_inside, p_long, &mut rng))
     }
 
     /// Expected to generate the playcaller's defensive play call
-    fn generate_defensive_playcall(&self, context: &GameContext, is_home: bool, mut rng: &mut impl Rng) -> Box<dyn DefensivePlayCall> {
+    fn generate_defensive_playcall(&self, context: &GameContext, is_home: bool, personnel: DefensivePersonnel, mut rng: &mut impl Rng) -> Box<dyn DefensivePlayCall> {
         /*Initialize probabilities for blitz/coverage, man/zone, zone depth, blitz aggressiveness*/
         let mut p_blitz: f64 = 0.5_f64;
         let mut p_blitz_sum_count: usize = 1_usize;
         let mut p_all_out_blitz: f64 = 0.5_f64;
         let mut p_all_out_blitz_sum_count: usize = 1_usize;
         let mut p_zone: f64 = 0.5_f64;
-        let mut p_zone_sum_count: usize = 1_usize;
+        let p_zone_sum_count: usize = 1_usize;
         let mut p_zone_depth: f64 = 0.5_f64;
         let mut p_zone_depth_sum_count: usize = 1_usize;
 
@@ -158,7 +311,66 @@ impl PlayCaller for Coach {
             (p_blitz, p_blitz_sum_count) = inc_prob(p_blitz, p_blitz_sum_count, 0.5_f64);
         }
 
+        // Tweak zone probability based on the personnel grouping
+        p_zone = match personnel {
+            DefensivePersonnel::Base => inc_prob(p_zone, p_zone_sum_count, 0.5_f64).0,
+            DefensivePersonnel::Nickel => inc_prob(p_zone, p_zone_sum_count, 0.6_f64).0,
+            DefensivePersonnel::Dime => inc_prob(p_zone, p_zone_sum_count, 0.8_f64).0,
+            DefensivePersonnel::GoalLine => inc_prob(p_zone, p_zone_sum_count, 0.2_f64).0
+        };
+
         /*Randomly generate a play call*/
         Box::new(DefensivePlay::generate(p_blitz, p_all_out_blitz, p_zone, p_zone_depth, &mut rng))
     }
-}
\ No newline at end of file
+
+    /// Decide whether to go for it, punt, or kick a field goal on fourth down
+    fn generate_fourth_down_decision(&self, context: &GameContext, is_home: bool, mut rng: &mut impl Rng) -> FourthDownDecision {
+        /*Get the field position and score situation*/
+        let yards_to_endzone: isize = context.possession.get_yards_to_endzone();
+        let yards_to_first: isize = context.possession.get_yards_to_first();
+        let in_field_goal_range: bool = yards_to_endzone <= 37;
+        let score_diff: isize = match is_home {
+            true => context.score.home_team_score as isize - context.score.away_team_score as isize,
+            false => context.score.away_team_score as isize - context.score.home_team_score as isize
+        };
+        let late_in_game: bool = context.clock.quarter == GameQuarter::Fourth && context.clock.game_clock_seconds < 300;
+
+        /*Initialize the probability of going for it*/
+        let mut p_go: f64 = if yards_to_first <= 1 {
+            0.35_f64
+        } else if yards_to_first <= 3 {
+            0.15_f64
+        } else {
+            0.03_f64
+        };
+
+        /*Tweak probability based on game context & coaching style*/
+        // Tweak based on aggressiveness
+        p_go += 0.03_f64 * (self.aggressiveness as f64 - 5_f64);
+
+        // Tweak based on field position, backed up teams rarely go for it
+        if yards_to_endzone > 60 {
+            p_go *= 0.3_f64;
+        } else if yards_to_endzone <= 45 && !in_field_goal_range {
+            p_go += 0.1_f64;
+        }
+
+        // Tweak based on the score late in the game, a team trailing by
+        // more than a field goal must go for it
+        if late_in_game && score_diff < -3 {
+            p_go = 0.95_f64;
+        } else if late_in_game && score_diff < 0 && in_field_goal_range {
+            return FourthDownDecision::FieldGoal;
+        }
+
+        /*Randomly decide whether to go for it, otherwise kick*/
+        let dst_go: Bernoulli = Bernoulli::new(p_go.clamp(0_f64, 1_f64)).unwrap();
+        if dst_go.sample(&mut rng) {
+            FourthDownDecision::GoForIt
+        } else if in_field_goal_range {
+            FourthDownDecision::FieldGoal
+        } else {
+            FourthDownDecision::Punt
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::team::player::Player;
+use crate::team::position::Position;
+use crate::team::progression::get_projected_overall;
+
+use std::fmt;
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The league minimum salary
+pub const MIN_SALARY: u64 = 795_000;
+
+/// The salary of the most valuable player at the most valuable position
+pub const MAX_SALARY: u64 = 50_000_000;
+
+/// The most years a signing bonus is prorated over
+pub const MAX_PRORATION_YEARS: usize = 5;
+
+/// The default league salary cap
+pub const DEFAULT_SALARY_CAP: u64 = 255_400_000;
+
+/// The number of years on a rookie contract
+pub const ROOKIE_CONTRACT_YEARS: usize = 4;
+
+/// The salary of the first pick in the draft
+const MAX_ROOKIE_SALARY: u64 = 10_000_000;
+
+/// Get how much a position is worth on the market relative to a
+/// quarterback
+fn get_position_value(position: Position) -> f64 {
+    match position {
+        Position::Quarterback => 1_f64,
+        Position::DefensiveEnd => 0.6_f64,
+        Position::WideReceiver => 0.6_f64,
+        Position::OffensiveLineman => 0.45_f64,
+        Position::Cornerback => 0.45_f64,
+        Position::DefensiveTackle => 0.45_f64,
+        Position::Linebacker => 0.4_f64,
+        Position::Safety => 0.35_f64,
+        Position::TightEnd => 0.35_f64,
+        Position::Runningback => 0.3_f64,
+        Position::Fullback => 0.1_f64,
+        Position::Kicker => 0.1_f64,
+        Position::Punter => 0.1_f64,
+        Position::Returner => 0.1_f64,
+        Position::LongSnapper => 0.05_f64
+    }
+}
+
+/// Format an amount of money in millions of dollars
+pub fn format_money(amount: u64) -> String {
+    format!("${:.2}M", amount as f64 / 1_000_000_f64)
+}
+
+/// # Contract struct
+///
+/// A `Contract` pays a player a base `salary` each year plus a signing
+/// bonus.  For the salary cap the signing bonus is prorated evenly over
+/// the first five years of the contract, or all of it if shorter, so a
+/// season's cap hit is the salary plus that season's share of the bonus.
+/// `guaranteed_salary` is the base salary still guaranteed, which is paid
+/// out first.  Releasing a player accelerates their remaining bonus
+/// proration and guaranteed salary into dead money on the current cap.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Contract {
+    pub length: usize,
+    pub years_remaining: usize,
+    pub salary: u64,
+    pub signing_bonus: u64,
+    pub guaranteed_salary: u64
+}
+
+impl Contract {
+    /// Initialize a new contract given its length, annual salary, signing
+    /// bonus and guaranteed salary
+    pub fn new(length: usize, salary: u64, signing_bonus: u64, guaranteed_salary: u64) -> Contract {
+        Contract {
+            length,
+            years_remaining: length,
+            salary,
+            signing_bonus,
+            guaranteed_salary: guaranteed_salary.min(salary * length as u64)
+        }
+    }
+
+    /// Initialize a new minimum salary contract with no bonus or guarantees
+    pub fn minimum(length: usize) -> Contract {
+        Contract::new(length, MIN_SALARY, 0, 0)
+    }
+
+    /// Initialize a new rookie contract given the overall number of the
+    /// draft pick and the number of picks in the draft.  Earlier picks
+    /// earn more, with bigger bonuses and more guaranteed salary.
+    pub fn rookie(pick_number: usize, num_picks: usize) -> Contract {
+        let pick_scale: f64 = 1_f64 - (pick_number.saturating_sub(1) as f64 / num_picks.max(1) as f64);
+        let annual_value: u64 = MIN_SALARY + ((MAX_ROOKIE_SALARY - MIN_SALARY) as f64 * pick_scale.powi(3)) as u64;
+        let signing_bonus: u64 = (annual_value - MIN_SALARY) * ROOKIE_CONTRACT_YEARS as u64 / 2;
+        let salary: u64 = annual_value - signing_bonus / ROOKIE_CONTRACT_YEARS as u64;
+        let guaranteed_salary: u64 = (salary as f64 * ROOKIE_CONTRACT_YEARS as f64 * pick_scale) as u64;
+        Contract::new(ROOKIE_CONTRACT_YEARS, salary, signing_bonus, guaranteed_salary)
+    }
+
+    /// Get a player's market value per season from their projected overall
+    /// next season and the value of their position
+    pub fn get_market_salary(player: &Player) -> u64 {
+        let overall_scale: f64 = ((get_projected_overall(player) - 4_f64) / 6_f64).clamp(0_f64, 1_f64);
+        let salary_scale: f64 = get_position_value(player.position) * overall_scale.powi(3);
+        MIN_SALARY + ((MAX_SALARY - MIN_SALARY) as f64 * salary_scale) as u64
+    }
+
+    /// Initialize a new contract paying a player their market value.
+    /// Younger players sign longer deals, a quarter of the value is paid
+    /// as a signing bonus, and half of the salary is guaranteed.
+    pub fn from_market_value(player: &Player) -> Contract {
+        let length: usize = match player.age {
+            0..=26 => 5,
+            27..=28 => 4,
+            29..=30 => 3,
+            31..=32 => 2,
+            _ => 1
+        };
+        let total_value: u64 = Contract::get_market_salary(player) * length as u64;
+        let signing_bonus: u64 = total_value / 4;
+        let salary: u64 = ((total_value - signing_bonus) / length as u64).max(MIN_SALARY);
+        Contract::new(length, salary, signing_bonus, salary * length as u64 / 2)
+    }
+
+    /// Generate a market value contract already partway through its term
+    pub fn generate(player: &Player, rng: &mut impl Rng) -> Contract {
+        let mut contract: Contract = Contract::from_market_value(player);
+        for _ in 0..rng.gen_range(0..contract.length) {
+            contract.advance_year();
+        }
+        contract
+    }
+
+    /// Get the number of years the signing bonus is prorated over
+    pub fn get_proration_years(&self) -> usize {
+        self.length.clamp(1, MAX_PRORATION_YEARS)
+    }
+
+    /// Get the signing bonus proration counted against each season's cap
+    pub fn get_annual_proration(&self) -> u64 {
+        self.signing_bonus / self.get_proration_years() as u64
+    }
+
+    /// Get the number of seasons left, including the current season, with
+    /// signing bonus proration counted against the cap
+    pub fn get_proration_years_remaining(&self) -> usize {
+        let years_played: usize = self.length.saturating_sub(self.years_remaining);
+        self.get_proration_years().saturating_sub(years_played)
+    }
+
+    /// Get the signing bonus proration left to count against the cap,
+    /// including the current season.  Any remainder from dividing the
+    /// bonus is counted in the last year of proration.
+    pub fn get_remaining_proration(&self) -> u64 {
+        let proration_years_remaining: usize = self.get_proration_years_remaining();
+        if proration_years_remaining == 0 {
+            return 0;
+        }
+        let proration_years_played: usize = self.get_proration_years() - proration_years_remaining;
+        self.signing_bonus - self.get_annual_proration() * proration_years_played as u64
+    }
+
+    /// Get the contract's hit on the current season's salary cap
+    pub fn get_cap_hit(&self) -> u64 {
+        if self.is_expired() {
+            return 0;
+        }
+        let proration: u64 = match self.get_proration_years_remaining() {
+            0 | 1 => self.get_remaining_proration(),
+            _ => self.get_annual_proration()
+        };
+        self.salary + proration
+    }
+
+    /// Get the dead money counted against the current season's salary cap
+    /// if the player is released
+    pub fn get_dead_money(&self) -> u64 {
+        if self.is_expired() {
+            return 0;
+        }
+        self.get_remaining_proration() + self.guaranteed_salary
+    }
+
+    /// Get the total value of the contract
+    pub fn get_total_value(&self) -> u64 {
+        self.salary * self.length as u64 + self.signing_bonus
+    }
+
+    /// Check whether every year of the contract has been played
+    pub fn is_expired(&self) -> bool {
+        self.years_remaining == 0
+    }
+
+    /// Move the contract on by a season, paying out a year of salary
+    pub fn advance_year(&mut self) {
+        if self.is_expired() {
+            return;
+        }
+        self.years_remaining -= 1;
+        self.guaranteed_salary = self.guaranteed_salary.saturating_sub(self.salary);
+    }
+}
+
+impl fmt::Display for Contract {
+    /// Format a `Contract` as its years remaining and cap hit
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let contract_str = format!(
+            "{} of {} years left, {} cap hit, {} total",
+            self.years_remaining,
+            self.length,
+            format_money(self.get_cap_hit()),
+            format_money(self.get_total_value())
+        );
+        f.write_str(&contract_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the cap hit of every season of a contract
+    fn get_cap_hits(contract: &Contract) -> Vec<u64> {
+        let mut contract: Contract = contract.clone();
+        let mut cap_hits: Vec<u64> = Vec::new();
+        while !contract.is_expired() {
+            cap_hits.push(contract.get_cap_hit());
+            contract.advance_year();
+        }
+        cap_hits
+    }
+
+    #[test]
+    fn test_signing_bonus_is_prorated_over_the_contract() {
+        let contract: Contract = Contract::new(3, 2_000_000, 9_000_000, 0);
+        assert_eq!(get_cap_hits(&contract), vec![5_000_000, 5_000_000, 5_000_000]);
+        assert_eq!(get_cap_hits(&contract).iter().sum::<u64>(), contract.get_total_value());
+    }
+
+    #[test]
+    fn test_signing_bonus_is_prorated_over_at_most_five_years() {
+        // The remainder of dividing the bonus lands in the fifth year, and
+        // the last two years carry no proration
+        let contract: Contract = Contract::new(7, 1_000_000, 10_000_003, 0);
+        assert_eq!(contract.get_proration_years(), MAX_PRORATION_YEARS);
+        assert_eq!(get_cap_hits(&contract), vec![
+            3_000_000, 3_000_000, 3_000_000, 3_000_000, 3_000_003, 1_000_000, 1_000_000
+        ]);
+        assert_eq!(get_cap_hits(&contract).iter().sum::<u64>(), contract.get_total_value());
+    }
+
+    #[test]
+    fn test_dead_money_accelerates_proration_and_guarantees() {
+        let mut contract: Contract = Contract::new(4, 3_000_000, 8_000_000, 6_000_000);
+        assert_eq!(contract.get_dead_money(), 8_000_000 + 6_000_000);
+
+        // A season in, a year of proration and salary have been paid
+        contract.advance_year();
+        assert_eq!(contract.get_remaining_proration(), 6_000_000);
+        assert_eq!(contract.guaranteed_salary, 3_000_000);
+        assert_eq!(contract.get_dead_money(), 9_000_000);
+
+        // Once the guarantees are paid out only the proration is left
+        contract.advance_year();
+        contract.advance_year();
+        assert_eq!(contract.get_dead_money(), 2_000_000);
+        contract.advance_year();
+        assert!(contract.is_expired());
+        assert_eq!(contract.get_cap_hit(), 0);
+        assert_eq!(contract.get_dead_money(), 0);
+    }
+
+    #[test]
+    fn test_guaranteed_salary_is_capped_at_total_salary() {
+        let contract: Contract = Contract::new(2, 1_000_000, 0, 5_000_000);
+        assert_eq!(contract.guaranteed_salary, 2_000_000);
+    }
+}
@@ -0,0 +1,295 @@
+use crate::team::attributes::{Attribute, PlayerAttributes};
+use crate::team::coach::{Coach, OffensiveStyle, DefensiveStyle};
+use crate::team::contract::Contract;
+use crate::team::player::Player;
+use crate::team::position::Position;
+use crate::team::team::Team;
+
+use std::collections::BTreeSet;
+use rand::Rng;
+use rand::prelude::SliceRandom;
+use rand::distributions::Distribution;
+use statrs::distribution::Normal;
+
+const FIRST_NAMES: [&str; 80] = [
+    "Aaron", "Adrian", "Alex", "Andre", "Anthony", "Austin", "Ben", "Blake",
+    "Brandon", "Brian", "Bryce", "Caleb", "Cameron", "Carlos", "Chase", "Chris",
+    "Cole", "Connor", "Corey", "Darius", "David", "Derek", "Devin", "Dominic",
+    "Drew", "Dylan", "Eli", "Eric", "Ethan", "Evan", "Gabe", "Garrett",
+    "Grant", "Hunter", "Isaiah", "Jack", "Jalen", "Jamal", "Jared", "Jason",
+    "Javon", "Jordan", "Josh", "Julian", "Justin", "Keenan", "Kevin", "Kyle",
+    "Lamar", "Landon", "Logan", "Luke", "Malik", "Marcus", "Mason", "Matt",
+    "Micah", "Miles", "Nate", "Nick", "Noah", "Omar", "Owen", "Patrick",
+    "Quinn", "Reggie", "Ryan", "Sam", "Sean", "Shawn", "Terrell", "Trent",
+    "Trevor", "Troy", "Tyler", "Tyrone", "Victor", "Wesley", "Xavier", "Zach"
+];
+
+const LAST_NAMES: [&str; 80] = [
+    "Adams", "Alexander", "Allen", "Anderson", "Bailey", "Baker", "Banks", "Bell",
+    "Bennett", "Brooks", "Brown", "Bryant", "Butler", "Campbell", "Carter", "Clark",
+    "Coleman", "Collins", "Cook", "Cooper", "Davis", "Dixon", "Edwards", "Ellis",
+    "Evans", "Fisher", "Ford", "Foster", "Freeman", "Garcia", "Gibson", "Graham",
+    "Green", "Griffin", "Hall", "Harris", "Hayes", "Henderson", "Hill", "Howard",
+    "Hughes", "Jackson", "James", "Jenkins", "Johnson", "Jones", "Kelly", "King",
+    "Lewis", "Long", "Martin", "Mason", "Miller", "Mitchell", "Moore", "Morgan",
+    "Morris", "Murphy", "Nelson", "Owens", "Parker", "Perry", "Powell", "Price",
+    "Reed", "Richardson", "Robinson", "Rogers", "Ross", "Russell", "Scott", "Simmons",
+    "Smith", "Taylor", "Thomas", "Turner", "Walker", "Ward", "Washington", "White"
+];
+
+/// The number of times a name is redrawn when it is already taken before
+/// a suffix is added to make it unique
+const MAX_NAME_DRAWS: usize = 20;
+
+/// The suffixes added to a name which is still taken after redrawing
+const NAME_SUFFIXES: [&str; 4] = ["Jr.", "II", "III", "IV"];
+
+/// The number of players generated at each position for a full team
+pub const ROSTER_SIZES: [(Position, usize); 15] = [
+    (Position::Quarterback, 3),
+    (Position::Runningback, 3),
+    (Position::Fullback, 1),
+    (Position::WideReceiver, 6),
+    (Position::TightEnd, 3),
+    (Position::OffensiveLineman, 9),
+    (Position::DefensiveEnd, 4),
+    (Position::DefensiveTackle, 4),
+    (Position::Linebacker, 6),
+    (Position::Cornerback, 6),
+    (Position::Safety, 4),
+    (Position::Kicker, 1),
+    (Position::Punter, 1),
+    (Position::LongSnapper, 1),
+    (Position::Returner, 2)
+];
+
+/// # PlayerGenerator struct
+///
+/// A `PlayerGenerator` randomly generates players whose overall ratings
+/// are spread around a target overall.  Generation only draws from the
+/// given random number generator, so a seeded generator such as
+/// `rand::rngs::StdRng::seed_from_u64` always produces the same players.
+/// Players are identified by name throughout a team, so players generated
+/// for a team should be given names not already taken by its roster.
+pub struct PlayerGenerator {
+    pub target_overall: f64,
+    pub spread: f64
+}
+
+impl PlayerGenerator {
+    /// Initialize a new player generator given a target overall and the
+    /// standard deviation of player overalls around it
+    pub fn new(target_overall: f64, spread: f64) -> PlayerGenerator {
+        PlayerGenerator {
+            target_overall,
+            spread
+        }
+    }
+
+    /// Generate a random player name
+    pub fn generate_name(&self, rng: &mut impl Rng) -> String {
+        let first_name: &str = FIRST_NAMES.choose(rng).unwrap();
+        let last_name: &str = LAST_NAMES.choose(rng).unwrap();
+        format!("{} {}", first_name, last_name)
+    }
+
+    /// Generate a random player name not among the taken names, redrawing
+    /// on a collision and adding a suffix if no free name is drawn
+    pub fn generate_unique_name(&self, taken: &BTreeSet<String>, mut rng: &mut impl Rng) -> String {
+        let mut name: String = self.generate_name(&mut rng);
+        for _ in 0..MAX_NAME_DRAWS {
+            if !taken.contains(&name) {
+                return name;
+            }
+            name = self.generate_name(&mut rng);
+        }
+        let mut count: usize = NAME_SUFFIXES.len() + 1;
+        for suffix in NAME_SUFFIXES.iter() {
+            let suffixed: String = format!("{} {}", name, suffix);
+            if !taken.contains(&suffixed) {
+                return suffixed;
+            }
+        }
+        loop {
+            let numbered: String = format!("{} {}", name, count);
+            if !taken.contains(&numbered) {
+                return numbered;
+            }
+            count += 1;
+        }
+    }
+
+    /// Generate attributes for a player at a position.  The attributes
+    /// which make up the position's overall are drawn around the player's
+    /// own target overall, while the rest are drawn around half of it.
+    pub fn generate_attributes(&self, position: Position, mut rng: &mut impl Rng) -> PlayerAttributes {
+        // Generate the player's own target overall
+        let dst_overall: Normal = Normal::new(self.target_overall, self.spread.max(f64::MIN_POSITIVE)).unwrap();
+        let player_overall: f64 = dst_overall.sample(&mut rng).clamp(1_f64, 10_f64);
+
+        // Generate each attribute around the player's target overall
+        let weights = position.get_weights();
+        let dst_primary: Normal = Normal::new(player_overall, 1_f64).unwrap();
+        let dst_secondary: Normal = Normal::new(player_overall / 2_f64, 1_f64).unwrap();
+        let mut attributes: PlayerAttributes = PlayerAttributes::new(0);
+        for attribute in Attribute::ALL.iter() {
+            let is_primary: bool = weights.iter().any(|(weighted, _)| weighted == attribute);
+            let rating: f64 = match is_primary {
+                true => dst_primary.sample(&mut rng),
+                false => dst_secondary.sample(&mut rng)
+            };
+            *attributes.get_mut(*attribute) = rating.round().clamp(1_f64, 10_f64) as usize;
+        }
+        attributes
+    }
+
+    /// Generate a player at a position, aged anywhere from a rookie to a
+    /// veteran
+    pub fn generate_player(&self, position: Position, rng: &mut impl Rng) -> Player {
+        self.generate_unique_player(position, &BTreeSet::new(), rng)
+    }
+
+    /// Generate a player at a position whose name is not among the taken
+    /// names
+    pub fn generate_unique_player(&self, position: Position, taken: &BTreeSet<String>, mut rng: &mut impl Rng) -> Player {
+        let name: String = self.generate_unique_name(taken, &mut rng);
+        let attributes: PlayerAttributes = self.generate_attributes(position, &mut rng);
+        let mut player: Player = Player::from_attributes(&name, position, attributes);
+        player.age = rng.gen_range(22..=32);
+        player
+    }
+
+    /// Generate a rookie at a position, aged 21 to 23
+    pub fn generate_rookie(&self, position: Position, rng: &mut impl Rng) -> Player {
+        self.generate_unique_rookie(position, &BTreeSet::new(), rng)
+    }
+
+    /// Generate a rookie at a position whose name is not among the taken
+    /// names
+    pub fn generate_unique_rookie(&self, position: Position, taken: &BTreeSet<String>, mut rng: &mut impl Rng) -> Player {
+        let mut player: Player = self.generate_unique_player(position, taken, &mut rng);
+        player.age = rng.gen_range(21..=23);
+        player
+    }
+}
+
+/// # TeamGenerator struct
+///
+/// A `TeamGenerator` randomly generates coaches and full teams, filling
+/// every unit and depth chart with players from its `PlayerGenerator`
+pub struct TeamGenerator {
+    pub player_generator: PlayerGenerator
+}
+
+impl TeamGenerator {
+    /// Initialize a new team generator given a target overall and the
+    /// standard deviation of player overalls around it
+    pub fn new(target_overall: f64, spread: f64) -> TeamGenerator {
+        TeamGenerator {
+            player_generator: PlayerGenerator::new(target_overall, spread)
+        }
+    }
+
+    /// Generate a coach with random ratings and styles
+    pub fn generate_coach(&self, mut rng: &mut impl Rng) -> Coach {
+        let name: String = format!("Coach {}", LAST_NAMES.choose(&mut rng).unwrap());
+        let offensive_style: OffensiveStyle = match rng.gen_range(0..3) {
+            0 => OffensiveStyle::Run,
+            1 => OffensiveStyle::Balanced,
+            _ => OffensiveStyle::Pass
+        };
+        let defensive_style: DefensiveStyle = match rng.gen_range(0..3) {
+            0 => DefensiveStyle::Coverage,
+            1 => DefensiveStyle::Balanced,
+            _ => DefensiveStyle::Blitz
+        };
+        Coach::new(
+            &name,
+            rng.gen_range(1..=10),
+            rng.gen_range(1..=10),
+            rng.gen_range(1..=10),
+            offensive_style,
+            defensive_style
+        )
+    }
+
+    /// Generate a full team with a random coach.  Each player is signed to
+    /// a market value contract partway through its term, each depth chart
+    /// is sorted best player first, and the kickoff units are filled with
+    /// backups from the offense and defense.  Every player on the team has
+    /// a different name.
+    pub fn generate_team(&self, name: &str, abbreviation: &str, mut rng: &mut impl Rng) -> Team {
+        // Generate the coach and every position group
+        let coach: Coach = self.generate_coach(&mut rng);
+        let mut team: Team = Team::new(name, abbreviation, coach);
+        let mut names: BTreeSet<String> = BTreeSet::new();
+        for (position, count) in ROSTER_SIZES.iter() {
+            let mut players: Vec<Player> = Vec::with_capacity(*count);
+            for _ in 0..*count {
+                let player: Player = self.player_generator.generate_unique_player(*position, &names, &mut rng);
+                names.insert(player.name.clone());
+                players.push(player);
+            }
+            for player in players.iter_mut() {
+                player.contract = Some(Contract::generate(player, &mut rng));
+            }
+            players.sort_by(|a, b| b.get_overall().total_cmp(&a.get_overall()));
+            for player in players {
+                team.add_player(player);
+            }
+        }
+
+        // Fill the kickoff units with backups
+        team.fill_kickoff_units();
+        team
+    }
+}
+
+/// Generate an average team for tests
+#[cfg(test)]
+pub(crate) fn generate_test_team(name: &str, abbreviation: &str, rng: &mut impl Rng) -> Team {
+    TeamGenerator::new(5_f64, 1_f64).generate_team(name, abbreviation, rng)
+}
+
+/// Generate a home and an away team for tests from a seed
+#[cfg(test)]
+pub(crate) fn generate_test_teams(seed: u64) -> (Team, Team) {
+    let mut rng: rand_chacha::ChaCha8Rng = rand::SeedableRng::seed_from_u64(seed);
+    (generate_test_team("Home", "HOME", &mut rng), generate_test_team("Away", "AWAY", &mut rng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_generated_team_names_are_unique() {
+        let generator: TeamGenerator = TeamGenerator::new(5_f64, 1_f64);
+        for seed in 0..200_u64 {
+            let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(seed);
+            let team: Team = generator.generate_team("Team", "TM", &mut rng);
+            let names: BTreeSet<String> = team.get_roster().into_iter().map(|player| player.name).collect();
+            assert_eq!(names.len(), team.get_roster().len(), "seed {} generated duplicate names", seed);
+            assert!(team.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_unique_name_adds_suffix_when_pool_is_taken() {
+        let generator: PlayerGenerator = PlayerGenerator::new(5_f64, 1_f64);
+        let mut taken: BTreeSet<String> = BTreeSet::new();
+        for first_name in FIRST_NAMES.iter() {
+            for last_name in LAST_NAMES.iter() {
+                taken.insert(format!("{} {}", first_name, last_name));
+            }
+        }
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..10 {
+            let name: String = generator.generate_unique_name(&taken, &mut rng);
+            assert!(!taken.contains(&name));
+            taken.insert(name);
+        }
+    }
+}
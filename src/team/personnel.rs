@@ -0,0 +1,282 @@
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+
+/// # OffensivePersonnel enum
+///
+/// An `OffensivePersonnel` represents the personnel grouping sent onto the
+/// field by the offense.  The grouping is named by its number of backs
+/// followed by its number of tight ends, so 12 personnel is one back and
+/// two tight ends.  The remaining skill positions are filled by receivers.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OffensivePersonnel {
+    Eleven,
+    Twelve,
+    TwentyOne,
+    Empty,
+    GoalLine
+}
+
+impl OffensivePersonnel {
+    /// All offensive personnel groupings
+    pub const ALL: [OffensivePersonnel; 5] = [
+        OffensivePersonnel::Eleven,
+        OffensivePersonnel::Twelve,
+        OffensivePersonnel::TwentyOne,
+        OffensivePersonnel::Empty,
+        OffensivePersonnel::GoalLine
+    ];
+
+    /// Generates a new offensive personnel grouping given a weight for
+    /// each grouping, in the order of `OffensivePersonnel::ALL`
+    pub fn generate(weights: &[f64; 5], mut rng: &mut impl Rng) -> OffensivePersonnel {
+        let dst_personnel: WeightedIndex<f64> = WeightedIndex::new(weights).unwrap();
+        OffensivePersonnel::ALL[dst_personnel.sample(&mut rng)]
+    }
+
+    /// Get the number of quarterbacks in the grouping
+    pub fn num_quarterbacks(&self) -> usize {
+        1_usize
+    }
+
+    /// Get the number of runningbacks in the grouping
+    pub fn num_runningbacks(&self) -> usize {
+        match self {
+            OffensivePersonnel::Empty => 0_usize,
+            _ => 1_usize
+        }
+    }
+
+    /// Get the number of fullbacks in the grouping
+    pub fn num_fullbacks(&self) -> usize {
+        match self {
+            OffensivePersonnel::TwentyOne => 1_usize,
+            OffensivePersonnel::GoalLine => 1_usize,
+            _ => 0_usize
+        }
+    }
+
+    /// Get the number of tight ends in the grouping
+    pub fn num_tight_ends(&self) -> usize {
+        match self {
+            OffensivePersonnel::Twelve => 2_usize,
+            OffensivePersonnel::GoalLine => 3_usize,
+            _ => 1_usize
+        }
+    }
+
+    /// Get the number of wide receivers in the grouping
+    pub fn num_wide_receivers(&self) -> usize {
+        match self {
+            OffensivePersonnel::Eleven => 3_usize,
+            OffensivePersonnel::Empty => 4_usize,
+            OffensivePersonnel::GoalLine => 0_usize,
+            _ => 2_usize
+        }
+    }
+
+    /// Get the number of offensive linemen in the grouping
+    pub fn num_offensive_linemen(&self) -> usize {
+        5_usize
+    }
+
+    /// Format the personnel grouping as a string
+    pub fn format_personnel(&self) -> String {
+        let personnel_str = match self {
+            OffensivePersonnel::Eleven => "11",
+            OffensivePersonnel::Twelve => "12",
+            OffensivePersonnel::TwentyOne => "21",
+            OffensivePersonnel::Empty => "Empty",
+            OffensivePersonnel::GoalLine => "Goal Line"
+        };
+        String::from(personnel_str)
+    }
+}
+
+/// # OffensiveFormation enum
+///
+/// An `OffensiveFormation` represents how the offense lines up before the
+/// snap.  The shotgun sets the quarterback back from the line for more
+/// time to throw, at the cost of a weaker run game.  The singleback puts
+/// the quarterback under center with one back behind, and the I formation
+/// lines a fullback up in front of the back to lead the way on runs, but
+/// needs a fullback on the field.  Empty personnel can only line up in the
+/// shotgun.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OffensiveFormation {
+    Shotgun,
+    Singleback,
+    IFormation
+}
+
+impl OffensiveFormation {
+    /// All offensive formations
+    pub const ALL: [OffensiveFormation; 3] = [
+        OffensiveFormation::Shotgun,
+        OffensiveFormation::Singleback,
+        OffensiveFormation::IFormation
+    ];
+
+    /// Generates a new offensive formation for a personnel grouping given
+    /// a weight for each formation, in the order of
+    /// `OffensiveFormation::ALL`.  Formations the grouping cannot line up
+    /// in are never chosen.
+    pub fn generate(personnel: OffensivePersonnel, weights: &[f64; 3], mut rng: &mut impl Rng) -> OffensiveFormation {
+        let mut allowed_weights: [f64; 3] = [0_f64; 3];
+        for (i, weight) in allowed_weights.iter_mut().enumerate() {
+            if OffensiveFormation::ALL[i].is_allowed(personnel) {
+                *weight = weights[i].max(0_f64);
+            }
+        }
+        if allowed_weights.iter().all(|weight| *weight == 0_f64) {
+            return OffensiveFormation::Shotgun;
+        }
+        let dst_formation: WeightedIndex<f64> = WeightedIndex::new(allowed_weights).unwrap();
+        OffensiveFormation::ALL[dst_formation.sample(&mut rng)]
+    }
+
+    /// Check whether a personnel grouping can line up in the formation
+    pub fn is_allowed(&self, personnel: OffensivePersonnel) -> bool {
+        match self {
+            OffensiveFormation::Shotgun => true,
+            OffensiveFormation::Singleback => personnel.num_runningbacks() > 0,
+            OffensiveFormation::IFormation => personnel.num_runningbacks() > 0 && personnel.num_fullbacks() > 0
+        }
+    }
+
+    /// Get the edge the formation gives the run blockers
+    pub fn get_run_blocking_edge(&self) -> f64 {
+        match self {
+            OffensiveFormation::Shotgun => -0.5_f64,
+            OffensiveFormation::Singleback => 0_f64,
+            OffensiveFormation::IFormation => 0.75_f64
+        }
+    }
+
+    /// Get the edge the formation gives the pass protection
+    pub fn get_pass_blocking_edge(&self) -> f64 {
+        match self {
+            OffensiveFormation::Shotgun => 0.75_f64,
+            OffensiveFormation::Singleback => 0_f64,
+            OffensiveFormation::IFormation => -0.25_f64
+        }
+    }
+
+    /// Format the formation as a string
+    pub fn format_formation(&self) -> String {
+        let formation_str = match self {
+            OffensiveFormation::Shotgun => "Shotgun",
+            OffensiveFormation::Singleback => "Singleback",
+            OffensiveFormation::IFormation => "I-Form"
+        };
+        String::from(formation_str)
+    }
+}
+
+/// # DefensivePersonnel enum
+///
+/// A `DefensivePersonnel` represents the personnel grouping sent onto the
+/// field by the defense.  Nickel and dime replace linebackers with extra
+/// cornerbacks, while goal line trades a safety for a defensive tackle.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DefensivePersonnel {
+    Base,
+    Nickel,
+    Dime,
+    GoalLine
+}
+
+impl DefensivePersonnel {
+    /// All defensive personnel groupings
+    pub const ALL: [DefensivePersonnel; 4] = [
+        DefensivePersonnel::Base,
+        DefensivePersonnel::Nickel,
+        DefensivePersonnel::Dime,
+        DefensivePersonnel::GoalLine
+    ];
+
+    /// Generates a new defensive personnel grouping given a weight for
+    /// each grouping, in the order of `DefensivePersonnel::ALL`
+    pub fn generate(weights: &[f64; 4], mut rng: &mut impl Rng) -> DefensivePersonnel {
+        let dst_personnel: WeightedIndex<f64> = WeightedIndex::new(weights).unwrap();
+        DefensivePersonnel::ALL[dst_personnel.sample(&mut rng)]
+    }
+
+    /// Get the number of edge rushers in the grouping
+    pub fn num_edge_rushers(&self) -> usize {
+        2_usize
+    }
+
+    /// Get the number of defensive tackles in the grouping
+    pub fn num_defensive_tackles(&self) -> usize {
+        match self {
+            DefensivePersonnel::GoalLine => 3_usize,
+            _ => 2_usize
+        }
+    }
+
+    /// Get the number of linebackers in the grouping
+    pub fn num_linebackers(&self) -> usize {
+        match self {
+            DefensivePersonnel::Nickel => 2_usize,
+            DefensivePersonnel::Dime => 1_usize,
+            _ => 3_usize
+        }
+    }
+
+    /// Get the number of cornerbacks in the grouping
+    pub fn num_cornerbacks(&self) -> usize {
+        match self {
+            DefensivePersonnel::Nickel => 3_usize,
+            DefensivePersonnel::Dime => 4_usize,
+            _ => 2_usize
+        }
+    }
+
+    /// Get the number of safeties in the grouping
+    pub fn num_safeties(&self) -> usize {
+        match self {
+            DefensivePersonnel::GoalLine => 1_usize,
+            _ => 2_usize
+        }
+    }
+
+    /// Format the personnel grouping as a string
+    pub fn format_personnel(&self) -> String {
+        let personnel_str = match self {
+            DefensivePersonnel::Base => "Base 4-3",
+            DefensivePersonnel::Nickel => "Nickel",
+            DefensivePersonnel::Dime => "Dime",
+            DefensivePersonnel::GoalLine => "Goal Line"
+        };
+        String::from(personnel_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_formations_need_their_backs() {
+        for personnel in OffensivePersonnel::ALL.iter() {
+            assert!(OffensiveFormation::Shotgun.is_allowed(*personnel));
+        }
+        assert!(!OffensiveFormation::Singleback.is_allowed(OffensivePersonnel::Empty));
+        assert!(!OffensiveFormation::IFormation.is_allowed(OffensivePersonnel::Empty));
+        assert!(!OffensiveFormation::IFormation.is_allowed(OffensivePersonnel::Eleven));
+        assert!(OffensiveFormation::IFormation.is_allowed(OffensivePersonnel::TwentyOne));
+    }
+
+    #[test]
+    fn test_generate_never_picks_a_disallowed_formation() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..100 {
+            let formation: OffensiveFormation = OffensiveFormation::generate(OffensivePersonnel::Eleven, &[0.1_f64, 0.1_f64, 10_f64], &mut rng);
+            assert!(formation.is_allowed(OffensivePersonnel::Eleven));
+        }
+        let formation: OffensiveFormation = OffensiveFormation::generate(OffensivePersonnel::Empty, &[0_f64, 1_f64, 1_f64], &mut rng);
+        assert_eq!(formation, OffensiveFormation::Shotgun);
+    }
+}
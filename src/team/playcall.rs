@@ -1,12 +1,16 @@
 use crate::game::context::context::GameContext;
+use crate::team::personnel::{OffensivePersonnel, OffensiveFormation, DefensivePersonnel};
 
 use rand::Rng;
 use rand::distributions::{Distribution, Bernoulli};
 use statrs::distribution::Beta;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # PlayDepth enum
 ///
 /// The PlayDepth enum represents the depth of a pass or pass defense play
+#[derive(PartialEq)]
 pub enum PlayDepth {
     Short,
     Medium,
@@ -60,6 +64,8 @@ pub trait DefensivePlayCall {
 /// # OffensivePlay enum
 ///
 /// The OffensivePlay enum represents a play called by the offense
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OffensivePlay {
     InsideRun,
     OutsideRun,
@@ -106,6 +112,18 @@ impl OffensivePlay {
         // Initialize and return the offensive play
         OffensivePlay::new(is_run, is_inside_run, depth)
     }
+
+    /// Format the offensive play as a string
+    pub fn format_offensive_play(&self) -> String {
+        let play_str = match self {
+            OffensivePlay::InsideRun => "InsideRun",
+            OffensivePlay::OutsideRun => "OutsideRun",
+            OffensivePlay::ShortPass => "ShortPass",
+            OffensivePlay::MediumPass => "MediumPass",
+            OffensivePlay::LongPass => "LongPass"
+        };
+        String::from(play_str)
+    }
 }
 
 impl OffensivePlayCall for OffensivePlay {
@@ -232,14 +250,39 @@ impl DefensivePlayCall for DefensivePlay {
     }
 }
 
+/// # FourthDownDecision enum
+///
+/// The `FourthDownDecision` enum represents a coach's choice on fourth down
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FourthDownDecision {
+    GoForIt,
+    Punt,
+    FieldGoal
+}
+
 /// # PlayCaller trait
 ///
 /// A `PlayCaller` implementation represents someone who calls plays for
 /// a football team
 pub trait PlayCaller {
+    /// Expected to generate the playcaller's offensive personnel grouping
+    fn generate_offensive_personnel(&self, context: &GameContext, is_home: bool, rng: &mut impl Rng) -> OffensivePersonnel;
+
+    /// Expected to generate the playcaller's defensive personnel grouping
+    /// in response to the offensive personnel grouping
+    fn generate_defensive_personnel(&self, context: &GameContext, is_home: bool, offensive_personnel: OffensivePersonnel, rng: &mut impl Rng) -> DefensivePersonnel;
+
+    /// Expected to generate the formation the playcaller's offensive
+    /// personnel grouping lines up in
+    fn generate_offensive_formation(&self, context: &GameContext, is_home: bool, personnel: OffensivePersonnel, rng: &mut impl Rng) -> OffensiveFormation;
+
     /// Expected to generate the playcaller's offensive play call
-    fn generate_offensive_playcall(&self, context: &GameContext, is_home: bool, rng: &mut impl Rng) -> Box<dyn OffensivePlayCall>;
+    fn generate_offensive_playcall(&self, context: &GameContext, is_home: bool, personnel: OffensivePersonnel, formation: OffensiveFormation, rng: &mut impl Rng) -> Box<dyn OffensivePlayCall>;
 
     /// Expected to generate the playcaller's defensive play call
-    fn generate_defensive_playcall(&self, context: &GameContext, is_home: bool, rng: &mut impl Rng) -> Box<dyn DefensivePlayCall>;
+    fn generate_defensive_playcall(&self, context: &GameContext, is_home: bool, personnel: DefensivePersonnel, rng: &mut impl Rng) -> Box<dyn DefensivePlayCall>;
+
+    /// Expected to decide whether to go for it, punt, or kick a field goal
+    /// on fourth down
+    fn generate_fourth_down_decision(&self, context: &GameContext, is_home: bool, rng: &mut impl Rng) -> FourthDownDecision;
 }
\ No newline at end of file
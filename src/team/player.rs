@@ -1,50 +1,112 @@
+use crate::game::fatigue::{FATIGUE_PENALTY, is_rotating_out};
+use crate::game::injury::Injury;
+use crate::team::attributes::{Attribute, PlayerAttributes};
+use crate::team::contract::Contract;
+use crate::team::position::Position;
+
 use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// The age given to players created without one
+pub const DEFAULT_PLAYER_AGE: usize = 25;
 
 /// # Player struct
 ///
-/// A `Player` represents a football player
+/// A `Player` represents a football player.  During a game a player's
+/// `fatigue` runs from 0 when fresh to 1 when exhausted, and lowers their
+/// effective ratings.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Player {
     pub name: String,
-    pub throwing: usize,
-    pub catching: usize,
-    pub running: usize,
-    pub blocking: usize,
-    pub tackling: usize,
-    pub kicking: usize
+    pub position: Position,
+    pub attributes: PlayerAttributes,
+    #[cfg_attr(feature = "serde", serde(default = "default_player_age"))]
+    pub age: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub contract: Option<Contract>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub injury: Option<Injury>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fatigue: f64
 }
 
 impl Player {
-    /// Initialize a new player given its attributes
+    /// Initialize a new player given its general ratings.  The general
+    /// ratings are spread across the position-specific attributes, and the
+    /// player is assigned whichever position suits them best.
     pub fn new(name: &str, throwing: usize, catching: usize, running: usize, blocking: usize, tackling: usize, kicking: usize) -> Player {
+        let attributes: PlayerAttributes = PlayerAttributes::from_general(throwing, catching, running, blocking, tackling, kicking);
+        let position: Position = Position::get_best_fit(&attributes);
+        Player::from_attributes(name, position, attributes)
+    }
+
+    /// Initialize a new player given its position and attributes
+    pub fn from_attributes(name: &str, position: Position, attributes: PlayerAttributes) -> Player {
         Player {
             name: String::from(name),
-            throwing: throwing,
-            catching: catching,
-            running: running,
-            blocking: blocking,
-            tackling: tackling,
-            kicking: kicking
+            position,
+            attributes,
+            age: DEFAULT_PLAYER_AGE,
+            contract: None,
+            injury: None,
+            fatigue: 0_f64
         }
     }
 
-    /// Calculate the player's overall rating
+    /// Calculate the player's overall rating at a given position
+    pub fn get_position_overall(&self, position: Position) -> f64 {
+        position.get_overall(&self.attributes)
+    }
+
+    /// Calculate the player's overall rating at their own position
     pub fn get_overall(&self) -> f64 {
-        (
-            self.throwing as f64 +
-            self.catching as f64 +
-            self.running as f64 +
-            self.blocking as f64 +
-            self.tackling as f64 +
-            self.kicking as f64
-        ) / 6 as f64
+        self.get_position_overall(self.position)
+    }
+
+    /// Check whether the player is injured
+    pub fn is_injured(&self) -> bool {
+        self.injury.is_some()
+    }
+
+    /// Check whether the player is tired enough to rotate out for a backup
+    pub fn is_rotating_out(&self) -> bool {
+        is_rotating_out(self.position, self.fatigue)
+    }
+
+    /// Get the player's effective rating for an attribute, lowered by
+    /// their fatigue
+    pub fn get_rating(&self, attribute: Attribute) -> f64 {
+        self.attributes.get(attribute) as f64 * (1_f64 - FATIGUE_PENALTY * self.fatigue)
+    }
+
+    /// Get the average of the player's effective ratings for two attributes
+    pub fn get_rating_average(&self, first: Attribute, second: Attribute) -> f64 {
+        (self.get_rating(first) + self.get_rating(second)) / 2_f64
+    }
+
+    /// Get the player's hit on the current season's salary cap
+    pub fn get_cap_hit(&self) -> u64 {
+        self.contract.as_ref().map_or(0, |contract| contract.get_cap_hit())
+    }
+
+    /// Get the dead money left on the salary cap if the player is released
+    pub fn get_dead_money(&self) -> u64 {
+        self.contract.as_ref().map_or(0, |contract| contract.get_dead_money())
     }
 }
 
+/// Default the age of players loaded from roster files without one
+#[cfg(feature = "serde")]
+fn default_player_age() -> usize {
+    DEFAULT_PLAYER_AGE
+}
+
 impl fmt::Display for Player {
     /// Format a `Player` as a string
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let player_str = format!("{} ({:.2} Overall)", self.name, self.get_overall());
+        let player_str = format!("{} ({}, {:.2} Overall)", self.name, self.position, self.get_overall());
         f.write_str(&player_str)
     }
-}
\ No newline at end of file
+}
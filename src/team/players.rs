@@ -14,6 +14,11 @@ pub trait Players {
 impl Players for Vec<Player> {
     /// Generates the overall rating of the collection of players
     fn get_overall(&self) -> f64 {
+        // An empty collection has no overall rating
+        if self.is_empty() {
+            return 0.0_f64;
+        }
+
         // Initialize a sum of overall ratings
         let mut sum_overall: f64 = 0.0_f64;
 
@@ -31,4 +36,60 @@ impl Players for Vec<Player> {
         // Return the len of the vec
         self.len()
     }
-}
\ No newline at end of file
+}
+/// Select players from a set of depth charts to fill a lineup.
+///
+/// Each depth chart in `depth_charts` is asked for the corresponding number
+/// of players in `needs`, taking healthy players in depth chart order.
+/// Players tired enough to rotate out are rested while a fresher backup on
+/// the same depth chart can take their place.  If a depth chart runs out
+/// of healthy players, the shortfall is filled from the depth charts listed
+/// for it in `fallbacks`, in order, without reusing any player.  Injured
+/// players only play if no healthy player is left.
+pub fn select_players<'a>(depth_charts: &[&'a Vec<Player>], needs: &[usize], fallbacks: &[&[usize]]) -> Vec<Vec<&'a Player>> {
+    // Initialize the selected players and which players have been used
+    let mut selected: Vec<Vec<&'a Player>> = depth_charts.iter().map(|_| Vec::new()).collect();
+    let mut used: Vec<Vec<bool>> = depth_charts.iter().map(|depth_chart| vec![false; depth_chart.len()]).collect();
+
+    // Fill each position from its own depth chart first, fresh players
+    // before tired ones
+    for (i, depth_chart) in depth_charts.iter().enumerate() {
+        for take_tired in [false, true] {
+            for (index, player) in depth_chart.iter().enumerate() {
+                if selected[i].len() >= needs[i] {
+                    break;
+                }
+                if !used[i][index] && !player.is_injured() && player.is_rotating_out() == take_tired {
+                    selected[i].push(player);
+                    used[i][index] = true;
+                }
+            }
+        }
+    }
+
+    // Fill any shortfall from the fallback depth charts
+    for i in 0..depth_charts.len() {
+        for &j in fallbacks[i].iter() {
+            for (index, player) in depth_charts[j].iter().enumerate() {
+                if selected[i].len() >= needs[i] {
+                    break;
+                }
+                if !used[j][index] && !player.is_injured() {
+                    selected[i].push(player);
+                    used[j][index] = true;
+                }
+            }
+        }
+    }
+
+    // Play injured players only if there is nobody else
+    for (i, depth_chart) in depth_charts.iter().enumerate() {
+        for player in depth_chart.iter().filter(|player| player.is_injured()) {
+            if selected[i].len() >= needs[i] {
+                break;
+            }
+            selected[i].push(player);
+        }
+    }
+    selected
+}
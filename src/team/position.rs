@@ -0,0 +1,136 @@
+use crate::team::attributes::{
+    PlayerAttributes,
+    AttributeWeights,
+    QUARTERBACK_WEIGHTS,
+    RUNNINGBACK_WEIGHTS,
+    FULLBACK_WEIGHTS,
+    WIDE_RECEIVER_WEIGHTS,
+    TIGHT_END_WEIGHTS,
+    OFFENSIVE_LINE_WEIGHTS,
+    EDGE_RUSHER_WEIGHTS,
+    DEFENSIVE_TACKLE_WEIGHTS,
+    LINEBACKER_WEIGHTS,
+    CORNERBACK_WEIGHTS,
+    SAFETY_WEIGHTS,
+    KICKER_WEIGHTS,
+    PUNTER_WEIGHTS,
+    LONG_SNAPPER_WEIGHTS,
+    RETURNER_WEIGHTS
+};
+
+use std::fmt;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+/// # Position enum
+///
+/// A `Position` represents the position a football player plays
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Position {
+    Quarterback,
+    Runningback,
+    Fullback,
+    WideReceiver,
+    TightEnd,
+    OffensiveLineman,
+    DefensiveEnd,
+    DefensiveTackle,
+    Linebacker,
+    Cornerback,
+    Safety,
+    Kicker,
+    Punter,
+    LongSnapper,
+    Returner
+}
+
+impl Position {
+    /// All positions
+    pub const ALL: [Position; 15] = [
+        Position::Quarterback,
+        Position::Runningback,
+        Position::Fullback,
+        Position::WideReceiver,
+        Position::TightEnd,
+        Position::OffensiveLineman,
+        Position::DefensiveEnd,
+        Position::DefensiveTackle,
+        Position::Linebacker,
+        Position::Cornerback,
+        Position::Safety,
+        Position::Kicker,
+        Position::Punter,
+        Position::LongSnapper,
+        Position::Returner
+    ];
+
+    /// Get the attribute weights which make up the position's overall
+    pub fn get_weights(&self) -> AttributeWeights {
+        match self {
+            Position::Quarterback => QUARTERBACK_WEIGHTS,
+            Position::Runningback => RUNNINGBACK_WEIGHTS,
+            Position::Fullback => FULLBACK_WEIGHTS,
+            Position::WideReceiver => WIDE_RECEIVER_WEIGHTS,
+            Position::TightEnd => TIGHT_END_WEIGHTS,
+            Position::OffensiveLineman => OFFENSIVE_LINE_WEIGHTS,
+            Position::DefensiveEnd => EDGE_RUSHER_WEIGHTS,
+            Position::DefensiveTackle => DEFENSIVE_TACKLE_WEIGHTS,
+            Position::Linebacker => LINEBACKER_WEIGHTS,
+            Position::Cornerback => CORNERBACK_WEIGHTS,
+            Position::Safety => SAFETY_WEIGHTS,
+            Position::Kicker => KICKER_WEIGHTS,
+            Position::Punter => PUNTER_WEIGHTS,
+            Position::LongSnapper => LONG_SNAPPER_WEIGHTS,
+            Position::Returner => RETURNER_WEIGHTS
+        }
+    }
+
+    /// Calculate the overall rating of a set of attributes at the position
+    pub fn get_overall(&self, attributes: &PlayerAttributes) -> f64 {
+        attributes.get_weighted_overall(self.get_weights())
+    }
+
+    /// Get the position at which a set of attributes rates highest
+    pub fn get_best_fit(attributes: &PlayerAttributes) -> Position {
+        let mut best_position: Position = Position::Quarterback;
+        let mut best_overall: f64 = f64::MIN;
+        for position in Position::ALL.iter() {
+            let overall: f64 = position.get_overall(attributes);
+            if overall > best_overall {
+                best_position = *position;
+                best_overall = overall;
+            }
+        }
+        best_position
+    }
+
+    /// Format the position as its abbreviation
+    pub fn format_position(&self) -> String {
+        let position_str = match self {
+            Position::Quarterback => "QB",
+            Position::Runningback => "RB",
+            Position::Fullback => "FB",
+            Position::WideReceiver => "WR",
+            Position::TightEnd => "TE",
+            Position::OffensiveLineman => "OL",
+            Position::DefensiveEnd => "DE",
+            Position::DefensiveTackle => "DT",
+            Position::Linebacker => "LB",
+            Position::Cornerback => "CB",
+            Position::Safety => "S",
+            Position::Kicker => "K",
+            Position::Punter => "P",
+            Position::LongSnapper => "LS",
+            Position::Returner => "KR/PR"
+        };
+        String::from(position_str)
+    }
+}
+
+impl fmt::Display for Position {
+    /// Format a `Position` as a string
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_position())
+    }
+}
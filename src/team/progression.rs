@@ -0,0 +1,202 @@
+use crate::team::attributes::Attribute;
+use crate::team::player::Player;
+use crate::team::position::Position;
+
+use rand::Rng;
+use rand::distributions::{Distribution, Bernoulli};
+use statrs::distribution::Normal;
+
+/// The age by which every player has retired
+pub const MAX_PLAYER_AGE: usize = 40;
+
+/// # AgingCurve struct
+///
+/// An `AgingCurve` describes how players at a position develop with age.
+/// Young players improve by up to `growth` rating points per season,
+/// tapering off as they near `peak_age`.  Ratings then hold steady until
+/// `decline_age`, after which they fall by `decline` points per season,
+/// faster with every year past it.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AgingCurve {
+    pub peak_age: usize,
+    pub decline_age: usize,
+    pub growth: f64,
+    pub decline: f64
+}
+
+impl AgingCurve {
+    /// Initialize a new aging curve
+    pub fn new(peak_age: usize, decline_age: usize, growth: f64, decline: f64) -> AgingCurve {
+        AgingCurve {
+            peak_age,
+            decline_age,
+            growth,
+            decline
+        }
+    }
+
+    /// Get the aging curve for a position.  Running backs and defensive
+    /// backs peak early and fall off quickly, quarterbacks and linemen
+    /// last longer, and specialists play well into their thirties.
+    pub fn from_position(position: Position) -> AgingCurve {
+        match position {
+            Position::Quarterback => AgingCurve::new(28, 33, 0.5, 0.4),
+            Position::Runningback => AgingCurve::new(24, 28, 0.6, 0.7),
+            Position::Fullback => AgingCurve::new(25, 29, 0.4, 0.5),
+            Position::WideReceiver => AgingCurve::new(26, 30, 0.5, 0.5),
+            Position::TightEnd => AgingCurve::new(26, 30, 0.5, 0.5),
+            Position::OffensiveLineman => AgingCurve::new(27, 32, 0.4, 0.4),
+            Position::DefensiveEnd => AgingCurve::new(26, 30, 0.5, 0.5),
+            Position::DefensiveTackle => AgingCurve::new(26, 31, 0.5, 0.4),
+            Position::Linebacker => AgingCurve::new(25, 29, 0.5, 0.5),
+            Position::Cornerback => AgingCurve::new(25, 29, 0.5, 0.6),
+            Position::Safety => AgingCurve::new(26, 30, 0.5, 0.5),
+            Position::Kicker => AgingCurve::new(28, 36, 0.3, 0.3),
+            Position::Punter => AgingCurve::new(28, 36, 0.3, 0.3),
+            Position::LongSnapper => AgingCurve::new(27, 35, 0.3, 0.3),
+            Position::Returner => AgingCurve::new(24, 28, 0.5, 0.7)
+        }
+    }
+
+    /// Get the expected change in a rating over a season for a player of
+    /// a given age
+    pub fn get_expected_change(&self, age: usize) -> f64 {
+        if age < self.peak_age {
+            let years_to_peak: f64 = (self.peak_age - age) as f64;
+            self.growth * (years_to_peak / 3_f64).min(1_f64)
+        } else if age < self.decline_age {
+            0_f64
+        } else {
+            let years_past_decline: f64 = (age - self.decline_age) as f64;
+            -self.decline * (1_f64 + years_past_decline / 3_f64)
+        }
+    }
+
+    /// Get the probability that a player of a given age and overall retires
+    /// at the end of a season.  Players rarely retire before they start to
+    /// decline, and good players hang on longer than their peers.
+    pub fn get_retirement_probability(&self, age: usize, overall: f64) -> f64 {
+        if age >= MAX_PLAYER_AGE {
+            return 1_f64;
+        }
+        if age < self.decline_age {
+            return 0.01_f64;
+        }
+        let years_past_decline: f64 = (age - self.decline_age + 1) as f64;
+        (0.1_f64 * years_past_decline - 0.03_f64 * (overall - 5_f64)).clamp(0.02_f64, 1_f64)
+    }
+}
+
+/// Get how strongly an attribute follows the aging curve while improving
+/// and while declining.  Speed and agility come early and go early, while
+/// awareness and technique keep improving and hold up with age.
+fn get_attribute_aging(attribute: Attribute) -> (f64, f64) {
+    match attribute {
+        Attribute::Speed | Attribute::Agility => (0.5_f64, 1.5_f64),
+        Attribute::Awareness | Attribute::Accuracy | Attribute::RouteRunning | Attribute::KickAccuracy => (1.5_f64, 0.5_f64),
+        _ => (1_f64, 1_f64)
+    }
+}
+
+/// Get a player's expected overall next season, after a year of
+/// progression or regression
+pub fn get_projected_overall(player: &Player) -> f64 {
+    let curve: AgingCurve = AgingCurve::from_position(player.position);
+    (player.get_overall() + curve.get_expected_change(player.age)).clamp(0_f64, 10_f64)
+}
+
+/// Age a player by a season, moving their ratings along the aging curve
+/// for their position.  Each player develops at their own random pace
+/// each season, and only the attributes which make up the position's
+/// overall improve, while every attribute declines with age.
+pub fn progress_player(player: &mut Player, mut rng: &mut impl Rng) {
+    let curve: AgingCurve = AgingCurve::from_position(player.position);
+    let expected_change: f64 = curve.get_expected_change(player.age);
+    let dst_development: Normal = Normal::new(1_f64, 0.5_f64).unwrap();
+    let development: f64 = dst_development.sample(&mut rng).clamp(0_f64, 2_f64);
+    let dst_noise: Normal = Normal::new(0_f64, 0.25_f64).unwrap();
+    let weights = player.position.get_weights();
+    for attribute in Attribute::ALL.iter() {
+        // Get the change in the attribute this season
+        let (growth_factor, decline_factor) = get_attribute_aging(*attribute);
+        let is_primary: bool = weights.iter().any(|(weighted, _)| weighted == attribute);
+        let change: f64 = if expected_change > 0_f64 {
+            match is_primary {
+                true => expected_change * growth_factor * development,
+                false => 0_f64
+            }
+        } else {
+            expected_change * decline_factor
+        } + dst_noise.sample(&mut rng);
+
+        // Round the change up or down at random so that fractional
+        // changes add up over the seasons
+        let whole_change: f64 = change.floor();
+        let dst_round_up: Bernoulli = Bernoulli::new(change - whole_change).unwrap();
+        let rounded_change: isize = whole_change as isize + dst_round_up.sample(&mut rng) as isize;
+        let rating: &mut usize = player.attributes.get_mut(*attribute);
+        *rating = (*rating as isize + rounded_change).clamp(1, 10) as usize;
+    }
+    player.age += 1;
+}
+
+/// Decide at random whether a player retires at the end of a season
+pub fn is_retiring(player: &Player, mut rng: &mut impl Rng) -> bool {
+    let curve: AgingCurve = AgingCurve::from_position(player.position);
+    let p_retire: f64 = curve.get_retirement_probability(player.age, player.get_overall());
+    let dst_retire: Bernoulli = Bernoulli::new(p_retire).unwrap();
+    dst_retire.sample(&mut rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::attributes::PlayerAttributes;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Get a player's average overall after a season of progression over
+    /// many random seasons
+    fn get_average_progressed_overall(position: Position, age: usize) -> f64 {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut total_overall: f64 = 0_f64;
+        for _ in 0..500 {
+            let mut player: Player = Player::from_attributes("Player", position, PlayerAttributes::new(5));
+            player.age = age;
+            progress_player(&mut player, &mut rng);
+            assert_eq!(player.age, age + 1);
+            for attribute in Attribute::ALL.iter() {
+                assert!((1..=10).contains(&player.attributes.get(*attribute)));
+            }
+            total_overall += player.get_overall();
+        }
+        total_overall / 500_f64
+    }
+
+    #[test]
+    fn test_aging_curve_grows_holds_and_declines() {
+        let curve: AgingCurve = AgingCurve::from_position(Position::Runningback);
+        assert_eq!(curve.get_expected_change(21), curve.growth);
+        assert!(curve.get_expected_change(23) > 0_f64 && curve.get_expected_change(23) < curve.growth);
+        assert_eq!(curve.get_expected_change(26), 0_f64);
+        assert_eq!(curve.get_expected_change(28), -curve.decline);
+        assert!(curve.get_expected_change(31) < curve.get_expected_change(29));
+    }
+
+    #[test]
+    fn test_young_players_improve_and_old_players_decline() {
+        assert!(get_average_progressed_overall(Position::Cornerback, 21) > 5.2_f64);
+        assert!(get_average_progressed_overall(Position::Cornerback, 32) < 4.5_f64);
+        let peak: f64 = get_average_progressed_overall(Position::Quarterback, 30);
+        assert!((peak - 5_f64).abs() < 0.2_f64);
+    }
+
+    #[test]
+    fn test_retirement_probability() {
+        let curve: AgingCurve = AgingCurve::from_position(Position::Quarterback);
+        assert_eq!(curve.get_retirement_probability(25, 5_f64), 0.01_f64);
+        assert_eq!(curve.get_retirement_probability(MAX_PLAYER_AGE, 10_f64), 1_f64);
+        assert!(curve.get_retirement_probability(36, 8_f64) < curve.get_retirement_probability(36, 4_f64));
+        assert!(curve.get_retirement_probability(38, 5_f64) > curve.get_retirement_probability(34, 5_f64));
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(feature = "serde")]
+use crate::team::team::Team;
+
+use std::fmt;
+#[cfg(feature = "serde")]
+use std::fs;
+#[cfg(feature = "serde")]
+use std::path::Path;
+
+/// Load a team from a JSON roster file, then validate it
+#[cfg(feature = "serde")]
+pub fn load_team_json(path: impl AsRef<Path>) -> Result<Team, RosterError> {
+    let contents: String = read_roster_file(path.as_ref())?;
+    team_from_json(&contents)
+}
+
+/// Load a team from a TOML roster file, then validate it
+#[cfg(feature = "serde")]
+pub fn load_team_toml(path: impl AsRef<Path>) -> Result<Team, RosterError> {
+    let contents: String = read_roster_file(path.as_ref())?;
+    team_from_toml(&contents)
+}
+
+/// Parse a team from a JSON string, then validate it
+#[cfg(feature = "serde")]
+pub fn team_from_json(contents: &str) -> Result<Team, RosterError> {
+    let team: Team = match serde_json::from_str(contents) {
+        Ok(team) => team,
+        Err(err) => return Err(RosterError::ParseError(err.to_string()))
+    };
+    team.validate()?;
+    Ok(team)
+}
+
+/// Parse a team from a TOML string, then validate it
+#[cfg(feature = "serde")]
+pub fn team_from_toml(contents: &str) -> Result<Team, RosterError> {
+    let team: Team = match toml::from_str(contents) {
+        Ok(team) => team,
+        Err(err) => return Err(RosterError::ParseError(err.to_string()))
+    };
+    team.validate()?;
+    Ok(team)
+}
+
+/// Save a team to a JSON roster file
+#[cfg(feature = "serde")]
+pub fn save_team_json(team: &Team, path: impl AsRef<Path>) -> Result<(), RosterError> {
+    let contents: String = match serde_json::to_string_pretty(team) {
+        Ok(contents) => contents,
+        Err(err) => return Err(RosterError::ParseError(err.to_string()))
+    };
+    write_roster_file(path.as_ref(), &contents)
+}
+
+/// Save a team to a TOML roster file
+#[cfg(feature = "serde")]
+pub fn save_team_toml(team: &Team, path: impl AsRef<Path>) -> Result<(), RosterError> {
+    let contents: String = match toml::to_string_pretty(team) {
+        Ok(contents) => contents,
+        Err(err) => return Err(RosterError::ParseError(err.to_string()))
+    };
+    write_roster_file(path.as_ref(), &contents)
+}
+
+/// Read the contents of a roster file
+#[cfg(feature = "serde")]
+fn read_roster_file(path: &Path) -> Result<String, RosterError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents),
+        Err(err) => Err(RosterError::IoError(format!("{}: {}", path.display(), err)))
+    }
+}
+
+/// Write the contents of a roster file
+#[cfg(feature = "serde")]
+fn write_roster_file(path: &Path, contents: &str) -> Result<(), RosterError> {
+    match fs::write(path, contents) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(RosterError::IoError(format!("{}: {}", path.display(), err)))
+    }
+}
+
+/// # RosterError enum
+///
+/// The `RosterError` enum represents the errors that can be generated
+/// while loading, saving, or validating a roster.  Validation errors list
+/// every problem found so they can all be fixed in one pass.
+#[derive(Debug)]
+pub enum RosterError {
+    IoError(String),
+    ParseError(String),
+    ValidationError(Vec<String>),
+    PlayerNotFound(String)
+}
+
+impl fmt::Display for RosterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            RosterError::IoError(err) => format!("Roster IO Error: {}", err),
+            RosterError::ParseError(err) => format!("Roster Parse Error: {}", err),
+            RosterError::ValidationError(errs) => format!("Roster Validation Error: {}", errs.join("; ")),
+            RosterError::PlayerNotFound(err) => format!("Player Not Found: {}", err)
+        };
+        f.write_str(&description)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    /// Generate a team which fails validation for two reasons
+    fn generate_invalid_team() -> Team {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut team: Team = generate_test_team("Team", "TM", &mut rng);
+        team.offense.quarterbacks.clear();
+        team.defense.cornerbacks[0].attributes.coverage = 11;
+        team
+    }
+
+    #[test]
+    fn test_valid_team_round_trips() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let team: Team = generate_test_team("Team", "TM", &mut rng);
+        let from_json: Team = team_from_json(&serde_json::to_string(&team).unwrap()).unwrap();
+        let from_toml: Team = team_from_toml(&toml::to_string(&team).unwrap()).unwrap();
+        assert_eq!(from_json.get_roster().len(), team.get_roster().len());
+        assert_eq!(from_toml.get_roster().len(), team.get_roster().len());
+    }
+
+    #[test]
+    fn test_malformed_files_are_parse_errors() {
+        assert!(matches!(team_from_json("{\"name\": \"Team\""), Err(RosterError::ParseError(_))));
+        assert!(matches!(team_from_json("{\"name\": \"Team\"}"), Err(RosterError::ParseError(_))));
+        assert!(matches!(team_from_toml("name = "), Err(RosterError::ParseError(_))));
+        assert!(matches!(team_from_toml("name = \"Team\""), Err(RosterError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_invalid_teams_are_validation_errors() {
+        let team: Team = generate_invalid_team();
+        for result in [
+            team_from_json(&serde_json::to_string(&team).unwrap()),
+            team_from_toml(&toml::to_string(&team).unwrap())
+        ] {
+            match result {
+                Err(RosterError::ValidationError(errors)) => {
+                    assert_eq!(errors.len(), 2);
+                    assert!(errors.iter().any(|error| error.contains("quarterback")));
+                    assert!(errors.iter().any(|error| error.contains("Coverage 11")));
+                },
+                _ => panic!("invalid team was not rejected")
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_file_is_io_error() {
+        assert!(matches!(load_team_json("does/not/exist.json"), Err(RosterError::IoError(_))));
+        assert!(matches!(load_team_toml("does/not/exist.toml"), Err(RosterError::IoError(_))));
+    }
+}
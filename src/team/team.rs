@@ -1,24 +1,37 @@
 use crate::team::coach::Coach;
+use crate::team::contract::format_money;
+use crate::team::player::Player;
+use crate::team::position::Position;
 use crate::team::players::Players;
+use crate::team::roster::RosterError;
+use crate::team::attributes::Attribute;
 use crate::team::units::offense::offense::Offense;
 use crate::team::units::defense::defense::Defense;
 use crate::team::units::specialteams::specialteams::SpecialTeams;
 use crate::game::coinflip::CoinFlipDecision;
+use crate::game::fatigue::{add_snap_fatigue, recover_fatigue};
+use crate::game::injury::{Injury, PlayerInjury};
 
 use std::fmt;
 use rand::Rng;
 use rand::distributions::{Distribution, Bernoulli};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 /// # Team struct
 ///
 /// A `Team` represents a team of football players
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Team {
     pub coach: Coach,
     pub offense: Offense,
     pub defense: Defense,
     pub special_teams: SpecialTeams,
     pub name: String,
-    pub abbreviation: String
+    pub abbreviation: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dead_money: u64
 }
 
 impl Team {
@@ -31,31 +44,369 @@ impl Team {
             defense: Defense::new(),
             special_teams: SpecialTeams::new(),
             name: String::from(name),
-            abbreviation: String::from(abbreviation)
+            abbreviation: String::from(abbreviation),
+            dead_money: 0
         }
     }
 
-    /// Calculate the team's overall rating
-    pub fn get_overall(&self) -> f64 {
-        // Calculate the sum of player overalls on the offense
-        let offense_num_players: usize = self.offense.get_num_players();
-        let offense_overall: f64 = self.offense.get_overall();
-        let offense_total_overall: f64 = offense_overall * (offense_num_players as f64);
+    /// Add a player to the bottom of the depth chart for their position.
+    /// Returners are added as both kick and punt returners, and long
+    /// snappers join the special teams linemen.
+    pub fn add_player(&mut self, player: Player) {
+        match player.position {
+            Position::Quarterback => self.offense.quarterbacks.push(player),
+            Position::Runningback => self.offense.runningbacks.push(player),
+            Position::Fullback => self.offense.fullbacks.push(player),
+            Position::WideReceiver => self.offense.wide_receivers.push(player),
+            Position::TightEnd => self.offense.tight_ends.push(player),
+            Position::OffensiveLineman => self.offense.offensive_line.push(player),
+            Position::DefensiveEnd => self.defense.edge_rushers.push(player),
+            Position::DefensiveTackle => self.defense.defensive_tackles.push(player),
+            Position::Linebacker => self.defense.linebackers.push(player),
+            Position::Cornerback => self.defense.cornerbacks.push(player),
+            Position::Safety => self.defense.safeties.push(player),
+            Position::Kicker => self.special_teams.kickers.push(player),
+            Position::Punter => self.special_teams.punters.push(player),
+            Position::LongSnapper => self.special_teams.linemen.push(player),
+            Position::Returner => {
+                self.special_teams.kick_returners.push(player.clone());
+                self.special_teams.punt_returners.push(player);
+            }
+        };
+    }
+
+    /// Fill the kickoff units with backups from the offense and defense,
+    /// replacing any players already on them other than long snappers
+    pub fn fill_kickoff_units(&mut self) {
+        self.special_teams.linemen.retain(|player| player.position == Position::LongSnapper);
+        self.special_teams.defenders.clear();
+        self.special_teams.extras.clear();
+        self.special_teams.linemen.extend(self.offense.offensive_line.iter().skip(5).cloned());
+        self.special_teams.linemen.extend(self.offense.tight_ends.iter().skip(1).cloned());
+        self.special_teams.defenders.extend(self.defense.linebackers.iter().skip(3).cloned());
+        self.special_teams.defenders.extend(self.defense.safeties.iter().skip(2).cloned());
+        self.special_teams.extras.extend(self.defense.cornerbacks.iter().skip(3).cloned());
+        self.special_teams.extras.extend(self.offense.runningbacks.iter().skip(1).cloned());
+    }
+
+    /// Get every player on the team once.  Players on the kickoff units
+    /// and punt returners are copies of players on other depth charts, so
+    /// only long snappers are taken from the special teams linemen and
+    /// only returners from the kick returners.
+    pub fn get_roster(&self) -> Vec<Player> {
+        let mut roster: Vec<Player> = Vec::new();
+        for depth_chart in [
+            &self.offense.quarterbacks,
+            &self.offense.runningbacks,
+            &self.offense.fullbacks,
+            &self.offense.wide_receivers,
+            &self.offense.tight_ends,
+            &self.offense.offensive_line,
+            &self.defense.edge_rushers,
+            &self.defense.defensive_tackles,
+            &self.defense.linebackers,
+            &self.defense.safeties,
+            &self.defense.cornerbacks,
+            &self.special_teams.kickers,
+            &self.special_teams.punters
+        ] {
+            roster.extend(depth_chart.iter().cloned());
+        }
+        roster.extend(self.special_teams.linemen.iter().filter(|player| player.position == Position::LongSnapper).cloned());
+        roster.extend(self.special_teams.kick_returners.iter().filter(|player| player.position == Position::Returner).cloned());
+        roster
+    }
+
+    /// Replace every depth chart with a new roster.  Each depth chart is
+    /// sorted best player first, and the kickoff units are refilled with
+    /// backups.
+    pub fn set_roster(&mut self, mut roster: Vec<Player>) {
+        self.offense = Offense::new();
+        self.defense = Defense::new();
+        self.special_teams = SpecialTeams::new();
+        roster.sort_by(|a, b| b.get_overall().total_cmp(&a.get_overall()));
+        for player in roster {
+            self.add_player(player);
+        }
+        self.fill_kickoff_units();
+    }
+
+    /// Sign a player to the team, re-sorting the depth charts around them
+    pub fn sign_player(&mut self, player: Player) {
+        let mut roster: Vec<Player> = self.get_roster();
+        roster.push(player);
+        self.set_roster(roster);
+    }
+
+    /// Remove the first player with a given name at a position from the
+    /// roster, re-sorting the depth charts without them
+    pub fn remove_player(&mut self, name: &str, position: Position) -> Result<Player, RosterError> {
+        let mut roster: Vec<Player> = self.get_roster();
+        let index: usize = match roster.iter().position(|player| player.name == name && player.position == position) {
+            Some(index) => index,
+            None => return Err(RosterError::PlayerNotFound(format!("{} ({}) is not on {}", name, position, self.name)))
+        };
+        let player: Player = roster.remove(index);
+        self.set_roster(roster);
+        Ok(player)
+    }
+
+    /// Release the first player with a given name at a position.  Their
+    /// remaining signing bonus proration and guaranteed salary count
+    /// against the team's cap as dead money, and they leave without a
+    /// contract.
+    pub fn release_player(&mut self, name: &str, position: Position) -> Result<Player, RosterError> {
+        let mut player: Player = self.remove_player(name, position)?;
+        self.dead_money += player.get_dead_money();
+        player.contract = None;
+        Ok(player)
+    }
+
+    /// Get the team's hit on the current season's salary cap, including
+    /// dead money from released players
+    pub fn get_cap_hit(&self) -> u64 {
+        self.get_roster().iter().map(|player| player.get_cap_hit()).sum::<u64>() + self.dead_money
+    }
+
+    /// Get the team's room under a salary cap, negative if over the cap
+    pub fn get_cap_space(&self, salary_cap: u64) -> i64 {
+        salary_cap as i64 - self.get_cap_hit() as i64
+    }
+
+    /// Validate that the team's cap hit is within a salary cap
+    pub fn validate_salary_cap(&self, salary_cap: u64) -> Result<(), RosterError> {
+        let cap_hit: u64 = self.get_cap_hit();
+        if cap_hit > salary_cap {
+            return Err(RosterError::ValidationError(vec![format!(
+                "{} has a cap hit of {}, over the salary cap of {}",
+                self.name,
+                format_money(cap_hit),
+                format_money(salary_cap)
+            )]));
+        }
+        Ok(())
+    }
+
+    /// Get every depth chart on the team alongside a label naming it
+    pub fn get_depth_charts(&self) -> Vec<(&str, &Vec<Player>)> {
+        vec![
+            ("quarterbacks", &self.offense.quarterbacks),
+            ("runningbacks", &self.offense.runningbacks),
+            ("fullbacks", &self.offense.fullbacks),
+            ("wide receivers", &self.offense.wide_receivers),
+            ("tight ends", &self.offense.tight_ends),
+            ("offensive line", &self.offense.offensive_line),
+            ("edge rushers", &self.defense.edge_rushers),
+            ("defensive tackles", &self.defense.defensive_tackles),
+            ("linebackers", &self.defense.linebackers),
+            ("safeties", &self.defense.safeties),
+            ("cornerbacks", &self.defense.cornerbacks),
+            ("kickers", &self.special_teams.kickers),
+            ("kick returners", &self.special_teams.kick_returners),
+            ("punters", &self.special_teams.punters),
+            ("punt returners", &self.special_teams.punt_returners),
+            ("special teams linemen", &self.special_teams.linemen),
+            ("special teams defenders", &self.special_teams.defenders),
+            ("special teams extras", &self.special_teams.extras)
+        ]
+    }
+
+    /// Get every depth chart on the team, mutably
+    fn get_depth_charts_mut(&mut self) -> [&mut Vec<Player>; 18] {
+        [
+            &mut self.offense.quarterbacks,
+            &mut self.offense.runningbacks,
+            &mut self.offense.fullbacks,
+            &mut self.offense.wide_receivers,
+            &mut self.offense.tight_ends,
+            &mut self.offense.offensive_line,
+            &mut self.defense.edge_rushers,
+            &mut self.defense.defensive_tackles,
+            &mut self.defense.linebackers,
+            &mut self.defense.safeties,
+            &mut self.defense.cornerbacks,
+            &mut self.special_teams.kickers,
+            &mut self.special_teams.kick_returners,
+            &mut self.special_teams.punters,
+            &mut self.special_teams.punt_returners,
+            &mut self.special_teams.linemen,
+            &mut self.special_teams.defenders,
+            &mut self.special_teams.extras
+        ]
+    }
+
+    /// Set a player's injury on every depth chart they appear on, or heal
+    /// them if the injury is `None`.  Returns whether the player was found.
+    pub fn set_injury(&mut self, name: &str, position: Position, injury: Option<Injury>) -> bool {
+        let mut found: bool = false;
+        for depth_chart in self.get_depth_charts_mut() {
+            for player in depth_chart.iter_mut().filter(|player| player.name == name && player.position == position) {
+                player.injury = injury;
+                found = true;
+            }
+        }
+        found
+    }
+
+    /// Count a game played towards the recovery of every injured player,
+    /// returning those who missed it to the lineup once healed
+    pub fn recover_injuries(&mut self) {
+        for depth_chart in self.get_depth_charts_mut() {
+            for player in depth_chart.iter_mut() {
+                if let Some(injury) = player.injury.as_mut() {
+                    injury.recover_game();
+                    if injury.is_healed() {
+                        player.injury = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record the injuries suffered by the team's players in a game.  A
+    /// game's worth of recovery is counted first for the players who were
+    /// already injured, and players only hurt for the game they were
+    /// injured in are not kept out.
+    pub fn add_game_injuries(&mut self, injuries: &[PlayerInjury]) {
+        self.recover_injuries();
+        for player_injury in injuries.iter() {
+            if player_injury.team == self.abbreviation && !player_injury.injury.is_healed() {
+                self.set_injury(&player_injury.player, player_injury.position, Some(player_injury.injury));
+            }
+        }
+    }
+
+    /// Update every player's fatigue after a play given the name and
+    /// position of each player on the field.  The players on the field tire
+    /// with the intensity of the play, and everyone else recovers on the
+    /// sideline.
+    pub fn update_fatigue(&mut self, on_field: &[(String, Position)], intensity: f64) {
+        for depth_chart in self.get_depth_charts_mut() {
+            for player in depth_chart.iter_mut() {
+                let is_on_field: bool = on_field.iter().any(|(name, position)| *name == player.name && *position == player.position);
+                player.fatigue = match is_on_field {
+                    true => add_snap_fatigue(player.fatigue, player.position, intensity),
+                    false => recover_fatigue(player.fatigue, 1)
+                };
+            }
+        }
+    }
+
+    /// Let every player recover for a number of plays on the sideline
+    pub fn recover_fatigue(&mut self, num_plays: usize) {
+        for depth_chart in self.get_depth_charts_mut() {
+            for player in depth_chart.iter_mut() {
+                player.fatigue = recover_fatigue(player.fatigue, num_plays);
+            }
+        }
+    }
 
-        // Calculate the sum of player overalls on the defense
-        let defense_num_players: usize = self.defense.get_num_players();
-        let defense_overall: f64 = self.defense.get_overall();
-        let defense_total_overall: f64 = defense_overall * (defense_num_players as f64);
+    /// Reset every player to fully rested
+    pub fn reset_fatigue(&mut self) {
+        for depth_chart in self.get_depth_charts_mut() {
+            for player in depth_chart.iter_mut() {
+                player.fatigue = 0_f64;
+            }
+        }
+    }
+
+    /// Get the team's injured players
+    pub fn get_injured_players(&self) -> Vec<Player> {
+        self.get_roster().into_iter().filter(|player| player.is_injured()).collect()
+    }
+
+    /// Validate the team, checking that every rating is on the 0-10 scale,
+    /// that no two players share a name and position, and that every unit
+    /// has enough players to take the field
+    pub fn validate(&self) -> Result<(), RosterError> {
+        let mut errors: Vec<String> = Vec::new();
+
+        // Validate the team and coach
+        if self.name.is_empty() {
+            errors.push(String::from("team name must not be empty"));
+        }
+        if self.abbreviation.is_empty() || self.abbreviation.len() > 4 {
+            errors.push(format!("team abbreviation '{}' must be 1-4 characters", self.abbreviation));
+        }
+        if self.coach.name.is_empty() {
+            errors.push(String::from("coach name must not be empty"));
+        }
+        for (rating_name, rating) in [
+            ("aggressiveness", self.coach.aggressiveness),
+            ("clock management", self.coach.clock_management),
+            ("intelligence", self.coach.intelligence)
+        ] {
+            if rating > 10 {
+                errors.push(format!("coach {} has {} {}, ratings must be 0-10", self.coach.name, rating_name, rating));
+            }
+        }
+
+        // Validate each player's ratings
+        for (depth_chart_name, depth_chart) in self.get_depth_charts() {
+            for player in depth_chart.iter() {
+                if player.name.is_empty() {
+                    errors.push(format!("a player in the {} has an empty name", depth_chart_name));
+                }
+                for attribute in Attribute::ALL.iter() {
+                    let rating: usize = player.attributes.get(*attribute);
+                    if rating > 10 {
+                        errors.push(format!("{} in the {} has {:?} {}, ratings must be 0-10", player.name, depth_chart_name, attribute, rating));
+                    }
+                }
+            }
+        }
+
+        // Validate that no two players share a name and position, since
+        // players are identified by them
+        let roster: Vec<Player> = self.get_roster();
+        for (index, player) in roster.iter().enumerate() {
+            if roster[..index].iter().any(|other| other.name == player.name && other.position == player.position) {
+                errors.push(format!("more than one {} is named {}", player.position, player.name));
+            }
+        }
+
+        // Validate that each unit can take the field
+        if self.offense.quarterbacks.is_empty() {
+            errors.push(String::from("offense must have at least one quarterback"));
+        }
+        if self.offense.get_num_players() < 11 {
+            errors.push(format!("offense must have at least 11 players, found {}", self.offense.get_num_players()));
+        }
+        if self.defense.get_num_players() < 11 {
+            errors.push(format!("defense must have at least 11 players, found {}", self.defense.get_num_players()));
+        }
+        if self.special_teams.kickers.is_empty() {
+            errors.push(String::from("special teams must have at least one kicker"));
+        }
+        if self.special_teams.punters.is_empty() {
+            errors.push(String::from("special teams must have at least one punter"));
+        }
+        if self.special_teams.kick_returners.is_empty() {
+            errors.push(String::from("special teams must have at least one kick returner"));
+        }
+        if self.special_teams.defenders.is_empty() && self.special_teams.extras.is_empty() {
+            errors.push(String::from("special teams must have at least one kickoff defender"));
+        }
 
-        // Calculate the sum of player overalls on the special teams
-        let special_teams_num_players: usize = self.special_teams.get_num_players();
-        let special_teams_overall: f64 = self.special_teams.get_overall();
-        let special_teams_total_overall: f64 = special_teams_overall * (special_teams_num_players as f64);
+        // Return every error found
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(RosterError::ValidationError(errors))
+        }
+    }
 
-        // Calculate and return the overall
-        let num_players: usize = offense_num_players + defense_num_players + special_teams_num_players;
-        let defense_total_overall: f64 = offense_total_overall + defense_total_overall + special_teams_total_overall;
-        defense_total_overall / (num_players as f64)
+    /// Calculate the team's overall rating, the average overall of the
+    /// players on its roster
+    pub fn get_overall(&self) -> f64 {
+        // Average the overall of every player on the roster, counting each
+        // player once however many depth charts they appear on
+        let roster: Vec<Player> = self.get_roster();
+        if roster.is_empty() {
+            return 0.0_f64;
+        }
+        let total_overall: f64 = roster.iter().map(|player| player.get_overall()).sum();
+        total_overall / (roster.len() as f64)
     }
 
     /// Choose whether to guess heads or tails going into the
@@ -85,4 +436,104 @@ impl fmt::Display for Team {
         let team_str = format!("{} ({:.2} Overall)", self.name, self.get_overall());
         f.write_str(&team_str)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::injury::InjurySeverity;
+    use crate::team::generator::generate_test_team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_validate_rejects_duplicate_name_and_position() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut team: Team = generate_test_team("Team", "TM", &mut rng);
+        assert!(team.validate().is_ok());
+        let duplicate: Player = team.offense.wide_receivers[0].clone();
+        team.sign_player(duplicate);
+        match team.validate() {
+            Err(RosterError::ValidationError(errors)) => assert!(errors.iter().any(|error| error.contains("more than one"))),
+            _ => panic!("duplicate player was not rejected")
+        }
+    }
+
+    #[test]
+    fn test_overall_counts_each_player_once() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut team: Team = generate_test_team("Team", "TM", &mut rng);
+        let roster: Vec<Player> = team.get_roster();
+        let average: f64 = roster.iter().map(|player| player.get_overall()).sum::<f64>() / roster.len() as f64;
+        assert!((team.get_overall() - average).abs() < 1e-9);
+
+        // Emptying the kickoff units, which hold copies of backups, leaves
+        // the overall unchanged
+        team.special_teams.defenders.clear();
+        team.special_teams.extras.clear();
+        assert!((team.get_overall() - average).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_release_replaces_cap_hit_with_dead_money() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut team: Team = generate_test_team("Team", "TM", &mut rng);
+        let player: Player = team.get_roster().into_iter().max_by_key(|player| player.get_dead_money()).unwrap();
+        assert!(player.get_dead_money() > 0);
+        let cap_hit: u64 = team.get_cap_hit();
+        let released: Player = team.release_player(&player.name, player.position).unwrap();
+        assert!(released.contract.is_none());
+        assert_eq!(team.dead_money, player.get_dead_money());
+        assert_eq!(team.get_cap_hit(), cap_hit - player.get_cap_hit() + player.get_dead_money());
+    }
+
+    #[test]
+    fn test_add_game_injuries_counts_recovery() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut team: Team = generate_test_team("Team", "TM", &mut rng);
+        let receiver: Player = team.offense.wide_receivers[0].clone();
+        let tight_end: Player = team.offense.tight_ends[0].clone();
+        let injury = |player: &Player, team: &str, games_out: usize| PlayerInjury {
+            player: player.name.clone(),
+            team: String::from(team),
+            position: player.position,
+            injury: Injury::new(InjurySeverity::Moderate, games_out)
+        };
+
+        // Injuries only hurting a player for the game they happened in, or
+        // suffered by another team's players, are not kept
+        team.add_game_injuries(&[
+            injury(&receiver, "TM", 2),
+            injury(&tight_end, "TM", 0),
+            injury(&team.offense.runningbacks[0].clone(), "XX", 3)
+        ]);
+        let injured: Vec<Player> = team.get_injured_players();
+        assert_eq!(injured.len(), 1);
+        assert_eq!(injured[0].name, receiver.name);
+
+        // The receiver misses the next two games, recovering a game at a
+        // time, and is back for the one after
+        team.add_game_injuries(&[]);
+        assert_eq!(team.get_injured_players()[0].injury.unwrap().games_remaining, 1);
+        team.add_game_injuries(&[]);
+        assert!(team.get_injured_players().is_empty());
+    }
+
+    #[test]
+    fn test_update_fatigue_tires_players_on_the_field() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let mut team: Team = generate_test_team("Team", "TM", &mut rng);
+        let quarterback: Player = team.offense.quarterbacks[0].clone();
+        let on_field: Vec<(String, Position)> = vec![(quarterback.name.clone(), quarterback.position)];
+        team.update_fatigue(&on_field, 1_f64);
+        let tired: f64 = team.offense.quarterbacks[0].fatigue;
+        assert!(tired > 0_f64);
+        assert_eq!(team.get_roster().iter().filter(|player| player.fatigue > 0_f64).count(), 1);
+
+        // Players recover on the sideline, and reset fully rested
+        team.update_fatigue(&[], 1_f64);
+        assert!(team.offense.quarterbacks[0].fatigue < tired);
+        team.update_fatigue(&on_field, 1_f64);
+        team.reset_fatigue();
+        assert!(team.get_roster().iter().all(|player| player.fatigue == 0_f64));
+    }
+}
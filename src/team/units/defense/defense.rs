@@ -1,12 +1,29 @@
+use crate::team::attributes::Attribute;
 use crate::team::player::Player;
-use crate::team::players::Players;
+use crate::team::players::{Players, select_players};
+use crate::team::personnel::DefensivePersonnel;
+use crate::team::units::offense::offense::TargetGroup;
 
+use rand::Rng;
+use rand::prelude::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Defense {
-    edge_rushers: Vec<Player>,
-    defensive_tackles: Vec<Player>,
-    linebackers: Vec<Player>,
-    safeties: Vec<Player>,
-    cornerbacks: Vec<Player>
+    pub edge_rushers: Vec<Player>,
+    pub defensive_tackles: Vec<Player>,
+    pub linebackers: Vec<Player>,
+    pub safeties: Vec<Player>,
+    pub cornerbacks: Vec<Player>
+}
+
+impl Default for Defense {
+    /// An empty defense
+    fn default() -> Self {
+        Defense::new()
+    }
 }
 
 impl Defense {
@@ -20,6 +37,43 @@ impl Defense {
             cornerbacks: Vec::new()
         }
     }
+
+    /// Get the 11 players on the field for a personnel grouping.  Players
+    /// are taken in depth chart order, and if a position group runs short
+    /// the remaining spots are filled from similar position groups.
+    pub fn get_lineup(&self, personnel: DefensivePersonnel) -> DefensiveLineup<'_> {
+        let mut selected = select_players(
+            &[
+                &self.edge_rushers,
+                &self.defensive_tackles,
+                &self.linebackers,
+                &self.safeties,
+                &self.cornerbacks
+            ],
+            &[
+                personnel.num_edge_rushers(),
+                personnel.num_defensive_tackles(),
+                personnel.num_linebackers(),
+                personnel.num_safeties(),
+                personnel.num_cornerbacks()
+            ],
+            &[
+                &[1, 2],
+                &[0, 2],
+                &[3, 0],
+                &[4, 2],
+                &[3, 2]
+            ]
+        ).into_iter();
+        DefensiveLineup {
+            personnel,
+            edge_rushers: selected.next().unwrap(),
+            defensive_tackles: selected.next().unwrap(),
+            linebackers: selected.next().unwrap(),
+            safeties: selected.next().unwrap(),
+            cornerbacks: selected.next().unwrap()
+        }
+    }
 }
 
 impl Players for Defense {
@@ -34,6 +88,9 @@ impl Players for Defense {
 
     /// Get the overall of the defensive unit
     fn get_overall(&self) -> f64 {
+        if self.get_num_players() == 0 {
+            return 0.0_f64;
+        }
         let mut sum_overall: f64 = 0.0_f64;
         for player in self.edge_rushers.iter() {
             sum_overall += player.get_overall();
@@ -52,4 +109,114 @@ impl Players for Defense {
         }
         sum_overall / (self.get_num_players() as f64)
     }
-}
\ No newline at end of file
+}
+
+/// # DefensiveLineup struct
+///
+/// A `DefensiveLineup` represents the players a defense has on the field
+/// for a single play, as determined by its personnel grouping
+pub struct DefensiveLineup<'a> {
+    pub personnel: DefensivePersonnel,
+    pub edge_rushers: Vec<&'a Player>,
+    pub defensive_tackles: Vec<&'a Player>,
+    pub linebackers: Vec<&'a Player>,
+    pub safeties: Vec<&'a Player>,
+    pub cornerbacks: Vec<&'a Player>
+}
+
+impl<'a> DefensiveLineup<'a> {
+    /// Get every player in the lineup
+    pub fn get_players(&self) -> Vec<&'a Player> {
+        self.edge_rushers.iter()
+            .chain(self.defensive_tackles.iter())
+            .chain(self.linebackers.iter())
+            .chain(self.safeties.iter())
+            .chain(self.cornerbacks.iter())
+            .copied()
+            .collect()
+    }
+
+    /// Get the number of defenders in the box.  A blitz brings extra
+    /// defenders down into the box from the secondary.
+    pub fn get_num_in_box(&self, blitz: bool, all_out_blitz: bool) -> usize {
+        let mut num_in_box: usize = self.edge_rushers.len() + self.defensive_tackles.len() + self.linebackers.len();
+        if all_out_blitz {
+            num_in_box += 2;
+        } else if blitz {
+            num_in_box += 1;
+        }
+        num_in_box
+    }
+
+    /// Get the average run defense of the defenders in the box, which
+    /// draws on both their tackling and their strength
+    pub fn get_run_defense(&self) -> f64 {
+        let mut total_run_defense: f64 = 0.0_f64;
+        let mut num_defenders: usize = 0_usize;
+        for player in self.edge_rushers.iter().chain(self.defensive_tackles.iter()).chain(self.linebackers.iter()) {
+            total_run_defense += player.get_rating_average(Attribute::Tackling, Attribute::Strength);
+            num_defenders += 1;
+        }
+        total_run_defense / num_defenders.max(1) as f64
+    }
+
+    /// Get the average pass rush of the rushers.  A light blitz sends a
+    /// linebacker, and an all-out blitz sends a linebacker and a safety.
+    pub fn get_pass_rush(&self, blitz: bool, all_out_blitz: bool) -> f64 {
+        let mut rushers: Vec<&'a Player> = Vec::new();
+        rushers.extend(self.edge_rushers.iter());
+        rushers.extend(self.defensive_tackles.iter());
+        if blitz || all_out_blitz {
+            rushers.extend(self.linebackers.iter().take(1));
+        }
+        if all_out_blitz {
+            rushers.extend(self.safeties.iter().take(1));
+        }
+        let mut total_rush: f64 = 0_f64;
+        for player in rushers.iter() {
+            total_rush += player.get_rating(Attribute::PassRush);
+        }
+        total_rush / rushers.len().max(1) as f64
+    }
+
+    /// Get the number of defensive backs on the field
+    pub fn get_num_defensive_backs(&self) -> usize {
+        self.cornerbacks.len() + self.safeties.len()
+    }
+
+    /// Get the defender covering a pass target.  Cornerbacks cover the
+    /// wide receivers, while safeties and linebackers cover tight ends
+    /// and backs.  Returns None if there is nobody in coverage.
+    pub fn get_coverage_defender(&self, target_group: TargetGroup, rng: &mut impl Rng) -> Option<&'a Player> {
+        let primary: &Vec<&'a Player> = match target_group {
+            TargetGroup::WideReceiver => &self.cornerbacks,
+            TargetGroup::TightEnd => &self.safeties,
+            TargetGroup::Back => &self.linebackers
+        };
+        if let Some(defender) = primary.choose(rng) {
+            return Some(defender);
+        }
+        self.safeties.iter().chain(self.linebackers.iter()).chain(self.cornerbacks.iter()).next().copied()
+    }
+
+    /// Get a random tackler from the box, or None if the defense has
+    /// nobody on the field
+    pub fn get_random_tackler(&self, rng: &mut impl Rng) -> Option<&'a Player> {
+        let mut box_players: Vec<&'a Player> = Vec::new();
+        box_players.extend(self.edge_rushers.iter());
+        box_players.extend(self.defensive_tackles.iter());
+        box_players.extend(self.linebackers.iter());
+        match box_players.choose(rng) {
+            Some(tackler) => Some(tackler),
+            None => self.get_random_defensive_back(rng)
+        }
+    }
+
+    /// Get a random defensive back, or None if there are none on the field
+    pub fn get_random_defensive_back(&self, rng: &mut impl Rng) -> Option<&'a Player> {
+        let mut defensive_backs: Vec<&'a Player> = Vec::new();
+        defensive_backs.extend(self.cornerbacks.iter());
+        defensive_backs.extend(self.safeties.iter());
+        defensive_backs.choose(rng).copied()
+    }
+}
@@ -1,13 +1,29 @@
+use crate::team::attributes::Attribute;
 use crate::team::player::Player;
-use crate::team::players::Players;
+use crate::team::players::{Players, select_players};
+use crate::team::personnel::{OffensivePersonnel, OffensiveFormation};
 
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Offense {
-    quarterbacks: Vec<Player>,
-    runningbacks: Vec<Player>,
-    fullbacks: Vec<Player>,
-    wide_receivers: Vec<Player>,
-    tight_ends: Vec<Player>,
-    offensive_line: Vec<Player>
+    pub quarterbacks: Vec<Player>,
+    pub runningbacks: Vec<Player>,
+    pub fullbacks: Vec<Player>,
+    pub wide_receivers: Vec<Player>,
+    pub tight_ends: Vec<Player>,
+    pub offensive_line: Vec<Player>
+}
+
+impl Default for Offense {
+    /// An empty offense
+    fn default() -> Self {
+        Offense::new()
+    }
 }
 
 impl Offense {
@@ -22,6 +38,49 @@ impl Offense {
             offensive_line: Vec::new()
         }
     }
+
+    /// Get the 11 players on the field for a personnel grouping lined up
+    /// in a formation.  Players are taken in depth chart order, and if a
+    /// position group runs short the remaining spots are filled from
+    /// similar position groups.
+    pub fn get_lineup(&self, personnel: OffensivePersonnel, formation: OffensiveFormation) -> OffensiveLineup<'_> {
+        let mut selected = select_players(
+            &[
+                &self.quarterbacks,
+                &self.runningbacks,
+                &self.fullbacks,
+                &self.wide_receivers,
+                &self.tight_ends,
+                &self.offensive_line
+            ],
+            &[
+                personnel.num_quarterbacks(),
+                personnel.num_runningbacks(),
+                personnel.num_fullbacks(),
+                personnel.num_wide_receivers(),
+                personnel.num_tight_ends(),
+                personnel.num_offensive_linemen()
+            ],
+            &[
+                &[],
+                &[2, 3],
+                &[1, 4],
+                &[4, 1],
+                &[2, 5],
+                &[4]
+            ]
+        ).into_iter();
+        OffensiveLineup {
+            personnel,
+            formation,
+            quarterbacks: selected.next().unwrap(),
+            runningbacks: selected.next().unwrap(),
+            fullbacks: selected.next().unwrap(),
+            wide_receivers: selected.next().unwrap(),
+            tight_ends: selected.next().unwrap(),
+            offensive_line: selected.next().unwrap()
+        }
+    }
 }
 
 impl Players for Offense {
@@ -37,6 +96,9 @@ impl Players for Offense {
 
     /// Get the overall of the defensive unit
     fn get_overall(&self) -> f64 {
+        if self.get_num_players() == 0 {
+            return 0.0_f64;
+        }
         let mut sum_overall: f64 = 0.0_f64;
         for player in self.quarterbacks.iter() {
             sum_overall += player.get_overall();
@@ -58,4 +120,175 @@ impl Players for Offense {
         }
         sum_overall / (self.get_num_players() as f64)
     }
-}
\ No newline at end of file
+}
+
+/// # TargetGroup enum
+///
+/// A `TargetGroup` represents the position group of a pass target, which
+/// determines who on the defense is responsible for covering them
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TargetGroup {
+    WideReceiver,
+    TightEnd,
+    Back
+}
+
+/// # OffensiveLineup struct
+///
+/// An `OffensiveLineup` represents the players an offense has on the field
+/// for a single play, as determined by its personnel grouping, and the
+/// formation they line up in
+pub struct OffensiveLineup<'a> {
+    pub personnel: OffensivePersonnel,
+    pub formation: OffensiveFormation,
+    pub quarterbacks: Vec<&'a Player>,
+    pub runningbacks: Vec<&'a Player>,
+    pub fullbacks: Vec<&'a Player>,
+    pub wide_receivers: Vec<&'a Player>,
+    pub tight_ends: Vec<&'a Player>,
+    pub offensive_line: Vec<&'a Player>
+}
+
+impl<'a> OffensiveLineup<'a> {
+    /// Get the quarterback, or None if there is no quarterback on the field
+    pub fn get_quarterback(&self) -> Option<&'a Player> {
+        self.quarterbacks.first().copied()
+    }
+
+    /// Get the ball carrier on a run play.  Inside runs occasionally go to
+    /// the fullback, and without any backs the quarterback keeps it.
+    pub fn get_ball_carrier(&self, inside: bool, rng: &mut impl Rng) -> Option<&'a Player> {
+        if inside && !self.fullbacks.is_empty() && rng.gen_bool(0.2_f64) {
+            return Some(self.fullbacks[0]);
+        }
+        match self.runningbacks.first() {
+            Some(runningback) => Some(runningback),
+            None => match self.fullbacks.first() {
+                Some(fullback) => Some(fullback),
+                None => self.get_quarterback()
+            }
+        }
+    }
+
+    /// Get the number of run blockers, that is the offensive linemen,
+    /// tight ends and fullbacks
+    pub fn get_num_run_blockers(&self) -> usize {
+        self.offensive_line.len() + self.tight_ends.len() + self.fullbacks.len()
+    }
+
+    /// Get every player in the lineup
+    pub fn get_players(&self) -> Vec<&'a Player> {
+        self.quarterbacks.iter()
+            .chain(self.runningbacks.iter())
+            .chain(self.fullbacks.iter())
+            .chain(self.wide_receivers.iter())
+            .chain(self.tight_ends.iter())
+            .chain(self.offensive_line.iter())
+            .copied()
+            .collect()
+    }
+
+    /// Get the average run blocking of the run blockers, plus the edge
+    /// the formation gives them
+    pub fn get_run_blocking(&self) -> f64 {
+        let mut total_blocking: f64 = 0_f64;
+        for player in self.offensive_line.iter().chain(self.tight_ends.iter()).chain(self.fullbacks.iter()) {
+            total_blocking += player.get_rating(Attribute::RunBlock);
+        }
+        total_blocking / self.get_num_run_blockers().max(1) as f64 + self.formation.get_run_blocking_edge()
+    }
+
+    /// Get the average pass blocking of the pass protectors, plus the edge
+    /// the formation gives them.  Against a blitz the backs stay in to help
+    /// the offensive line.
+    pub fn get_pass_blocking(&self, blitz: bool) -> f64 {
+        let mut total_blocking: f64 = 0_f64;
+        let mut num_blockers: usize = 0_usize;
+        for player in self.offensive_line.iter() {
+            total_blocking += player.get_rating(Attribute::PassBlock);
+            num_blockers += 1;
+        }
+        if blitz {
+            for player in self.runningbacks.iter().chain(self.fullbacks.iter()) {
+                total_blocking += player.get_rating(Attribute::PassBlock);
+                num_blockers += 1;
+            }
+        }
+        total_blocking / num_blockers.max(1) as f64 + self.formation.get_pass_blocking_edge()
+    }
+
+    /// Get a random pass target, weighting wide receivers above tight
+    /// ends and tight ends above backs, or None if there is nobody to
+    /// throw to
+    pub fn get_random_target(&self, mut rng: &mut impl Rng) -> Option<(&'a Player, TargetGroup)> {
+        let mut targets: Vec<(&'a Player, TargetGroup)> = Vec::new();
+        let mut weights: Vec<f64> = Vec::new();
+        for player in self.wide_receivers.iter() {
+            targets.push((player, TargetGroup::WideReceiver));
+            weights.push(3_f64);
+        }
+        for player in self.tight_ends.iter() {
+            targets.push((player, TargetGroup::TightEnd));
+            weights.push(2_f64);
+        }
+        for player in self.runningbacks.iter().chain(self.fullbacks.iter()) {
+            targets.push((player, TargetGroup::Back));
+            weights.push(1_f64);
+        }
+        if targets.is_empty() {
+            return None;
+        }
+        let dst_target: WeightedIndex<f64> = WeightedIndex::new(&weights).unwrap();
+        Some(targets[dst_target.sample(&mut rng)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::team::generator::generate_test_team;
+    use crate::team::team::Team;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_lineup_matches_personnel() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let team: Team = generate_test_team("Team", "TM", &mut rng);
+        for personnel in OffensivePersonnel::ALL.iter() {
+            let lineup: OffensiveLineup = team.offense.get_lineup(*personnel, OffensiveFormation::Shotgun);
+            assert_eq!(lineup.get_players().len(), 11);
+            assert_eq!(lineup.runningbacks.len(), personnel.num_runningbacks());
+            assert_eq!(lineup.fullbacks.len(), personnel.num_fullbacks());
+            assert_eq!(lineup.tight_ends.len(), personnel.num_tight_ends());
+            assert_eq!(lineup.wide_receivers.len(), personnel.num_wide_receivers());
+        }
+
+        // Empty personnel hands the ball to the quarterback on runs, and
+        // the starters are taken in depth chart order
+        let empty: OffensiveLineup = team.offense.get_lineup(OffensivePersonnel::Empty, OffensiveFormation::Shotgun);
+        assert_eq!(empty.get_ball_carrier(false, &mut rng).unwrap().name, team.offense.quarterbacks[0].name);
+        let goal_line: OffensiveLineup = team.offense.get_lineup(OffensivePersonnel::GoalLine, OffensiveFormation::IFormation);
+        assert_eq!(goal_line.tight_ends[0].name, team.offense.tight_ends[0].name);
+    }
+
+    #[test]
+    fn test_formation_shifts_blocking() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let team: Team = generate_test_team("Team", "TM", &mut rng);
+        let shotgun: OffensiveLineup = team.offense.get_lineup(OffensivePersonnel::TwentyOne, OffensiveFormation::Shotgun);
+        let i_formation: OffensiveLineup = team.offense.get_lineup(OffensivePersonnel::TwentyOne, OffensiveFormation::IFormation);
+        assert!(i_formation.get_run_blocking() > shotgun.get_run_blocking());
+        assert!(shotgun.get_pass_blocking(false) > i_formation.get_pass_blocking(false));
+    }
+
+    #[test]
+    fn test_empty_lineup_has_no_quarterback_or_target() {
+        let mut rng: ChaCha8Rng = ChaCha8Rng::seed_from_u64(0);
+        let offense: Offense = Offense::new();
+        let lineup: OffensiveLineup = offense.get_lineup(OffensivePersonnel::Eleven, OffensiveFormation::Shotgun);
+        assert!(lineup.get_quarterback().is_none());
+        assert!(lineup.get_ball_carrier(true, &mut rng).is_none());
+        assert!(lineup.get_random_target(&mut rng).is_none());
+    }
+}
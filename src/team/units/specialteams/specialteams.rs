@@ -1,9 +1,14 @@
+use crate::team::attributes::Attribute;
 use crate::team::player::Player;
 use crate::team::players::Players;
 
 use rand::Rng;
 use rand::prelude::SliceRandom;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SpecialTeams {
     pub kickers: Vec<Player>,
     pub kick_returners: Vec<Player>,
@@ -14,6 +19,13 @@ pub struct SpecialTeams {
     pub extras: Vec<Player>
 }
 
+impl Default for SpecialTeams {
+    /// Empty special teams units
+    fn default() -> Self {
+        SpecialTeams::new()
+    }
+}
+
 impl SpecialTeams {
     /// Initialize an empty SpecialTeams
     pub fn new() -> SpecialTeams {
@@ -28,58 +40,75 @@ impl SpecialTeams {
         }
     }
 
-    /// Get the kicker
+    /// Get the kicker, the first healthy kicker on the depth chart
     pub fn get_kicker(&self) -> &Player {
-        // Return the first kicker we find in the kickers list
-        for kicker in self.kickers.iter() {
-            return &kicker;
+        match get_first_healthy(&self.kickers) {
+            Some(kicker) => kicker,
+            None => panic!("Could not find any kicker")
         }
-
-        // If none then panic
-        panic!("Could not find any kicker");
     }
 
-    /// Get the kick returner
+    /// Get the kick returner, the first healthy kick returner on the depth
+    /// chart
     pub fn get_kick_returner(&self) -> &Player {
-        // Return the first kick returner we find in the kick returners list
-        for kick_returner in self.kick_returners.iter() {
-            return &kick_returner;
+        match get_first_healthy(&self.kick_returners) {
+            Some(kick_returner) => kick_returner,
+            None => panic!("Could not find any kick returner")
+        }
+    }
+
+    /// Get the punter, the first healthy punter on the depth chart
+    pub fn get_punter(&self) -> &Player {
+        match get_first_healthy(&self.punters) {
+            Some(punter) => punter,
+            None => panic!("Could not find any punter")
         }
+    }
 
-        // If none then panic
-        panic!("Could not find any kick returner");
+    /// Get the punt returner, the first healthy punt returner on the depth
+    /// chart
+    pub fn get_punt_returner(&self) -> &Player {
+        match get_first_healthy(&self.punt_returners) {
+            Some(punt_returner) => punt_returner,
+            None => panic!("Could not find any punt returner")
+        }
     }
 
-    /// Get kickoff defenders blocking
-    pub fn get_kickoff_defenders_blocking(&self) -> usize {
-        let mut total_blocking: usize = 0_usize;
+    /// Get kickoff defenders blocking, that is their strength when
+    /// shedding blocks
+    pub fn get_kickoff_defenders_blocking(&self) -> f64 {
+        let mut total_blocking: f64 = 0_f64;
         for player in self.defenders.iter() {
-            total_blocking += player.blocking;
+            total_blocking += player.get_rating(Attribute::Strength);
         }
         for player in self.extras.iter() {
-            total_blocking += player.blocking;
+            total_blocking += player.get_rating(Attribute::Strength);
         }
         let num_defenders = self.defenders.get_num_players();
         let num_extras = self.extras.get_num_players();
-        (total_blocking as f64 / (num_defenders + num_extras) as f64) as usize
+        total_blocking / (num_defenders + num_extras) as f64
     }
 
     /// Get kickoff blockers blocking
-    pub fn get_kickoff_blockers_blocking(&self) -> usize {
-        let mut total_blocking: usize = 0_usize;
+    pub fn get_kickoff_blockers_blocking(&self) -> f64 {
+        let mut total_blocking: f64 = 0_f64;
         for player in self.linemen.iter() {
-            total_blocking += player.blocking;
+            total_blocking += player.get_rating(Attribute::RunBlock);
         }
         for player in self.extras.iter() {
-            total_blocking += player.blocking;
+            total_blocking += player.get_rating(Attribute::RunBlock);
         }
         let num_linemen = self.linemen.get_num_players();
         let num_extras = self.extras.get_num_players();
-        (total_blocking as f64 / (num_linemen + num_extras) as f64) as usize
+        total_blocking / (num_linemen + num_extras) as f64
     }
 
-    /// Get random kickoff defender
+    /// Get random kickoff defender, healthy if possible
     pub fn get_random_kickoff_defender(&self, mut rng: &mut impl Rng) -> &Player {
+        let healthy: Vec<&Player> = self.defenders.iter().chain(self.extras.iter()).filter(|player| !player.is_injured()).collect();
+        if let Some(defender) = healthy.choose(&mut rng) {
+            return defender;
+        }
         let total_players = self.defenders.get_num_players() + self.extras.get_num_players();
         let rand_num = rng.gen_range(0..total_players);
         if rand_num < self.defenders.len() {
@@ -104,6 +133,9 @@ impl Players for SpecialTeams {
 
     /// Get the overall of the special teams unit
     fn get_overall(&self) -> f64 {
+        if self.get_num_players() == 0 {
+            return 0.0_f64;
+        }
         let mut sum_overall: f64 = 0.0_f64;
         for player in self.kickers.iter() {
             sum_overall += player.get_overall();
@@ -128,4 +160,10 @@ impl Players for SpecialTeams {
         }
         sum_overall / (self.get_num_players() as f64)
     }
-}
\ No newline at end of file
+}
+
+/// Get the first healthy player on a depth chart, or the first player if
+/// every one of them is injured
+fn get_first_healthy(depth_chart: &[Player]) -> Option<&Player> {
+    depth_chart.iter().find(|player| !player.is_injured()).or(depth_chart.first())
+}